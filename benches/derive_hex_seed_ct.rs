@@ -0,0 +1,47 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+// Decoding an English seed whose words are at the start of the wordset and one whose words are at its end, with
+// derive_hex_seed and with derive_hex_seed_ct, which takes the same time for both. Run with
+// `cargo bench --bench derive_hex_seed_ct`.
+
+use libmonero::keys::{derive_hex_seed, derive_hex_seed_ct};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ITERATIONS: u32 = 200;
+
+/// Runs given closure `ITERATIONS` times and returns the mean time of a run
+fn bench(f: impl Fn()) -> Duration {
+    f();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn main() {
+    let seed = |words: &str| -> Vec<String> { words.split(' ').map(|s| s.to_string()).collect() };
+    // "abbey" is the first word of the English wordset, "zoom" one of its last
+    let first_words = seed("abbey abducts ability abbey abducts ability abbey abducts ability abbey abducts ability abbey abducts ability abbey abducts ability abbey abducts ability abbey abducts ability abbey");
+    let last_words = seed("zoom zombie zones zoom zombie zones zoom zombie zones zoom zombie zones zoom zombie zones zoom zombie zones zoom zombie zones zoom zombie zones zoom");
+
+    for (name, words) in [("first words", &first_words), ("last words", &last_words)] {
+        let fast = bench(|| {
+            let _ = black_box(derive_hex_seed(black_box(words.clone())));
+        });
+        let constant = bench(|| {
+            let _ = black_box(derive_hex_seed_ct(black_box(words.clone())));
+        });
+        println!("derive_hex_seed, {}:    {:?}", name, fast);
+        println!("derive_hex_seed_ct, {}: {:?}", name, constant);
+    }
+}
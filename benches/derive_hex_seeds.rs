@@ -0,0 +1,50 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+// Decoding 1000 seeds of mixed languages with derive_hex_seeds_batch, which looks words up in tables built once,
+// against calling derive_hex_seed for each seed. Run with `cargo bench --bench derive_hex_seeds`.
+
+use libmonero::crypt::cn_fast_hash;
+use libmonero::keys::{derive_hex_seed, derive_hex_seeds_batch, spend_key_to_mnemonic};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const SEEDS: u32 = 1000;
+const LANGUAGES: [&str; 8] = ["en", "eo", "fr", "it", "ja", "lj", "pt", "ru"];
+
+/// Runs given closure once and returns how long it took
+fn bench(f: impl FnOnce()) -> Duration {
+    let start = Instant::now();
+    f();
+    start.elapsed()
+}
+
+fn main() {
+    let seeds: Vec<Vec<String>> = (0..SEEDS)
+        .map(|i| {
+            let hex_seed = hex::encode(cn_fast_hash(&i.to_le_bytes()));
+            spend_key_to_mnemonic(&hex_seed, LANGUAGES[i as usize % LANGUAGES.len()]).unwrap()
+        })
+        .collect();
+    // Build the tables before timing, as a long-running migration service would have
+    derive_hex_seeds_batch(vec![seeds[0].clone()]);
+
+    let batch_input = seeds.clone();
+    let batched = bench(|| {
+        black_box(derive_hex_seeds_batch(black_box(batch_input)));
+    });
+    let one_by_one = bench(|| {
+        for seed in black_box(seeds) {
+            let _ = black_box(derive_hex_seed(seed));
+        }
+    });
+    println!("derive_hex_seeds_batch, {} seeds: {:?}", SEEDS, batched);
+    println!("derive_hex_seed x {} seeds:      {:?}", SEEDS, one_by_one);
+}
@@ -0,0 +1,56 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+// Scanning a 16-output transaction with scan_outputs, which computes the key derivation once, against checking each
+// output with is_output_ours. Run with `cargo bench --bench scan_outputs`.
+
+use libmonero::crypt::cn_fast_hash;
+use libmonero::keys::{derive_pub_key, is_output_ours, scan_outputs};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const TX_PUB_KEY: &str = "10fccee43c0612968c2db809b140abbf1c799fe6158305be1c023509f3ae9a94";
+const PRIVATE_VIEW_KEY: &str = "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908";
+const PUBLIC_SPEND_KEY: &str = "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95";
+const ITERATIONS: u32 = 200;
+
+/// Runs given closure `ITERATIONS` times and returns the mean time of a run
+fn bench(f: impl Fn()) -> Duration {
+    f();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn main() {
+    // The first two outputs belong to the wallet, the other 14 are pseudorandom points
+    let mut outputs: Vec<(String, u64)> = vec![
+        ("30660d6645e727e6e6981a2956bc5590e7d2943c8de1f20f6a7fd3f57316e43f".to_string(), 0),
+        ("6d0167a993c7bd6d0b48dc8fd6b603c95e82105b6383da45490c602f58268762".to_string(), 1),
+    ];
+    for index in 2..16u64 {
+        let mut key = cn_fast_hash(&index.to_le_bytes());
+        key[31] &= 0x0f;
+        outputs.push((derive_pub_key(hex::encode(key)).unwrap(), index));
+    }
+
+    let batched = bench(|| {
+        black_box(scan_outputs(TX_PUB_KEY, PRIVATE_VIEW_KEY, PUBLIC_SPEND_KEY, black_box(&outputs)));
+    });
+    let one_by_one = bench(|| {
+        for (output_pub_key, index) in black_box(&outputs) {
+            black_box(is_output_ours(TX_PUB_KEY, PRIVATE_VIEW_KEY, PUBLIC_SPEND_KEY, output_pub_key, *index));
+        }
+    });
+    println!("scan_outputs, 16 outputs:       {:?}", batched);
+    println!("is_output_ours x 16 outputs:    {:?}", one_by_one);
+}
@@ -0,0 +1,42 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+// Deriving a lookahead table of 50000 subaddress spend keys with derive_subaddress_range_parallel, on every core,
+// against derive_subaddress_range on one. Run with `cargo bench --features rayon --bench subaddress_range`.
+
+use libmonero::keys::{derive_subaddress_range, derive_subaddress_range_parallel};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const SUBADDRESSES: u32 = 50000;
+const PRIVATE_VIEW_KEY: &str = "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908";
+const PUBLIC_SPEND_KEY: &str = "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95";
+
+/// Runs given closure once and returns how long it took
+fn bench(f: impl FnOnce()) -> Duration {
+    let start = Instant::now();
+    f();
+    start.elapsed()
+}
+
+fn main() {
+    let mut serial_keys = Vec::new();
+    let mut parallel_keys = Vec::new();
+    let serial = bench(|| {
+        serial_keys = black_box(derive_subaddress_range(PRIVATE_VIEW_KEY, PUBLIC_SPEND_KEY, 0, 0..SUBADDRESSES).unwrap());
+    });
+    let parallel = bench(|| {
+        parallel_keys = black_box(derive_subaddress_range_parallel(PRIVATE_VIEW_KEY, PUBLIC_SPEND_KEY, 0, 0..SUBADDRESSES).unwrap());
+    });
+    assert_eq!(serial_keys, parallel_keys);
+    println!("derive_subaddress_range, {} subaddresses:          {:?}", SUBADDRESSES, serial);
+    println!("derive_subaddress_range_parallel, {} subaddresses: {:?} (rayon threads: {})", SUBADDRESSES, parallel, rayon::current_num_threads());
+}
+
@@ -0,0 +1,46 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+//! Creates a new wallet: generates a seed, derives its keys and prints the mnemonic and the primary address
+//!
+//! Usage: `cargo run --example create_wallet [mainnet|testnet|stagenet] [language]`
+
+use libmonero::keys::{derive_address, derive_all_keys, generate_seed_in, Language, Network};
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let network = match args.next().as_deref() {
+        None | Some("mainnet") => Network::Mainnet,
+        Some("testnet") => Network::Testnet,
+        Some("stagenet") => Network::Stagenet,
+        Some(other) => {
+            eprintln!("Unknown network '{}', use mainnet, testnet or stagenet", other);
+            std::process::exit(1);
+        }
+    };
+    let language = args.next().unwrap_or_else(|| "en".to_string());
+
+    let mnemonic = match language.parse::<Language>().and_then(|language| generate_seed_in(language, "original")) {
+        Ok(mnemonic) => mnemonic,
+        Err(error) => {
+            eprintln!("Cannot generate a seed in language '{}': {}", language, error);
+            std::process::exit(1);
+        }
+    };
+    let keys = derive_all_keys(mnemonic.clone()).expect("generated seed is valid");
+    let address = derive_address(keys.pub_spend.clone(), keys.pub_view.clone(), network as u8)
+        .expect("derived keys are valid");
+
+    println!("Network:           {:?}", network);
+    println!("Mnemonic:          {}", mnemonic.join(" "));
+    println!("Private spend key: {}", keys.priv_spend);
+    println!("Private view key:  {}", keys.priv_view);
+    println!("Primary address:   {}", address);
+}
@@ -0,0 +1,19 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use libmonero::keys::decode_address;
+
+// Any string must give a Result, never a panic
+fuzz_target!(|data: &str| {
+    let _ = decode_address(data);
+});
@@ -0,0 +1,22 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use libmonero::keys::{derive_hex_seed, derive_priv_keys};
+
+// Any word list must give a Result, never a panic
+fuzz_target!(|data: &str| {
+    let words: Vec<String> = data.split_whitespace().map(String::from).collect();
+    if let Ok(hex_seed) = derive_hex_seed(words) {
+        let _ = derive_priv_keys(hex_seed);
+    }
+});
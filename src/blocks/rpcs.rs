@@ -286,4 +286,52 @@ pub fn get_transaction_from_hash(hash: String, node: DaemonNode) -> Result<RawTx
             pseudo_outs: json_final["rctsig_prunable"]["pseudoOuts"].as_array().unwrap().to_vec().iter().map(|x| x.as_str().unwrap_or("").to_string()).collect(),
         }
     })
-}
\ No newline at end of file
+}
+
+/// Validates the given address with the `validate_address` method of a wallet RPC server
+/// Returns whether the server considers the address valid on any network
+/// Returns `KeyError::RpcUnreachable` if the server could not be reached, so callers can retry, and `KeyError::Rpc` if
+/// it answered with an error or a malformed reply
+///
+/// The request is made with `ureq`, the blocking HTTP client the other RPC calls of this module use, rather than
+/// `reqwest`, which would add a second HTTP stack and an async runtime for one call.
+///
+/// Needs the `rpc` feature.
+///
+/// Example:
+/// ```no_run
+/// use libmonero::blocks::validate_address_rpc;
+///
+/// let valid = validate_address_rpc("4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J", "http://127.0.0.1:18082/json_rpc").unwrap();
+/// assert!(valid);
+/// ```
+#[cfg(feature = "rpc")]
+pub fn validate_address_rpc(address: &str, rpc_url: &str) -> Result<bool, crate::keys::KeyError> {
+    use crate::keys::KeyError;
+
+    let response = ureq::post(rpc_url)
+        .set("Content-Type", "application/json")
+        .send_json(ureq::json!({
+            "jsonrpc": "2.0",
+            "id": "0",
+            "method": "validate_address",
+            "params": {
+                "address": address,
+                "any_net_type": true,
+                "allow_openalias": false
+            }
+        }))
+        .map_err(|e| match e {
+            ureq::Error::Transport(transport) => KeyError::RpcUnreachable(format!("Could not reach the RPC server: {}", transport)),
+            status => KeyError::Rpc(format!("Error while validating the address with RPC server: {}", status)),
+        })?;
+    let response: serde_json::Value = response
+        .into_json()
+        .map_err(|e| KeyError::Rpc(format!("Invalid response from RPC server: {}", e)))?;
+    if let Some(message) = response["error"]["message"].as_str() {
+        return Err(KeyError::Rpc(format!("RPC server returned an error: {}", message)));
+    }
+    response["result"]["valid"]
+        .as_bool()
+        .ok_or_else(|| KeyError::Rpc("Invalid response from RPC server: missing result".to_string()))
+}
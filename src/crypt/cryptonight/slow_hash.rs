@@ -49,7 +49,8 @@ pub fn cn_slow_hash_v0(input: &[u8]) -> String {
     // process is repeated until the scratchpad is fully initialized.
 
     // Step 1A: Initialize the scratchpad with empty data
-    let mut scratchpad = [0u8; SCRATCHPAD_SIZE];
+    // On the heap, 2 MiB would overflow the stack of most threads
+    let mut scratchpad = vec![0u8; SCRATCHPAD_SIZE];
 
     // Step 1B: Use Keccak256Full to hash the input
     let mut keccak_hash = [0u8; 200];
@@ -110,7 +111,7 @@ pub fn cn_slow_hash_v0(input: &[u8]) -> String {
     // bytes, and finally the two 8-byte halves of the result are swapped.
 
     // Step 2A: Turn [u8; 200] into [[u64; 2]; 131072] for easier access
-    let mut sp_u64_2 = [[0u64; 2]; 131072];
+    let mut sp_u64_2 = vec![[0u64; 2]; 131072];
     for (i, sp_u64_2_chunk) in sp_u64_2.iter_mut().enumerate() {
         let u64_slice = unsafe {
             std::slice::from_raw_parts(scratchpad[i * 16..(i + 1) * 16].as_ptr() as *const u64, 2)
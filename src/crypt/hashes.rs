@@ -0,0 +1,69 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+use super::scalar::reduce_scalar;
+use sha3::{Digest, Keccak256};
+
+/// Hashes given data with Keccak-256, Monero's fast hash
+///
+/// Monero uses the original Keccak padding, not the one of NIST SHA3-256, so the two give different hashes.
+///
+/// Example:
+/// ```
+/// use libmonero::crypt::cn_fast_hash;
+///
+/// let hash: [u8; 32] = cn_fast_hash(b"");
+/// assert_eq!(hex::encode(hash), "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470");
+/// ```
+pub fn cn_fast_hash(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+/// Hashes given data with Keccak256 and reduces the result to a valid Ed25519 scalar, known as `Hs` in Monero
+///
+/// Example:
+/// ```
+/// use libmonero::crypt::hash_to_scalar;
+///
+/// let scalar: [u8; 32] = hash_to_scalar(b"This is a test");
+/// assert_eq!(hex::encode(scalar), "517275094ece3c79286727dd85245c35e7c016f1a124dfc784f3cca4d938b10f");
+/// ```
+pub fn hash_to_scalar(data: &[u8]) -> [u8; 32] {
+    reduce_scalar(cn_fast_hash(data))
+}
+
+/// Hashes a domain tag followed by given inputs with Keccak-256, `Keccak(domain || inputs[0] || inputs[1] || ...)`
+///
+/// Derivations of different features hash the same kind of inputs (e.g. a private view key and indexes), the domain tag
+/// first keeps their outputs apart. Every feature hashing with its own domain should go through this function, so the
+/// tag cannot be forgotten and the inputs are in the same order when deriving and when verifying. Reduce the result
+/// with [`reduce_scalar`] for a scalar (`Hs`). Domain tags of the crate:
+/// - `"SubAddr\0"` : subaddress secrets, with the private view key, major and minor index (4-byte little endian)
+/// - `"SubAddrLabel\0"` : subaddress label keys, with the same inputs
+/// - `"view_tag"` : view tags, with the key derivation and the output index (varint), only the first byte is used
+///
+/// Payment ID encryption keys append their tag (`0x8d`) to the key derivation and child keys have no tag, both as
+/// Monero's wallet and earlier versions of the crate derive them.
+///
+/// Example:
+/// ```
+/// use libmonero::crypt::{cn_fast_hash, keccak_prf};
+///
+/// let hash: [u8; 32] = keccak_prf(b"view_tag", &[&[0u8; 32], &[7]]);
+/// assert_eq!(hash, cn_fast_hash(&[&b"view_tag"[..], &[0u8; 32], &[7]].concat()));
+/// ```
+pub fn keccak_prf(domain: &[u8], inputs: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Keccak256::new();
+    hasher.update(domain);
+    for input in inputs {
+        hasher.update(input);
+    }
+    hasher.finalize().into()
+}
@@ -9,6 +9,13 @@
  */
 
 pub(crate) mod ed25519;
+pub(crate) mod hashes;
+pub(crate) mod point;
+pub(crate) mod scalar;
+
+pub use hashes::*;
+pub use point::*;
+pub use scalar::*;
 
 /// CryptoNight related functions
-pub mod cryptonight;
\ No newline at end of file
+pub mod cryptonight;
@@ -0,0 +1,31 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+use curve25519_dalek::EdwardsPoint;
+
+/// Multiplies given point by the cofactor 8, clearing its small-order (torsion) component like Monero's `ge_mul8`
+///
+/// Key derivations are computed as `8 * a * R`, so a transaction public key with a torsion component gives the same
+/// derivation as the key without it.
+///
+/// Example:
+/// ```
+/// use curve25519_dalek::{edwards::CompressedEdwardsY, Scalar};
+/// use libmonero::crypt::mul8;
+///
+/// let tx_pub_key: [u8; 32] = hex::decode("10fccee43c0612968c2db809b140abbf1c799fe6158305be1c023509f3ae9a94").unwrap().try_into().unwrap();
+/// let private_view_key: [u8; 32] = hex::decode("0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908").unwrap().try_into().unwrap();
+/// let shared_point = Scalar::from_bytes_mod_order(private_view_key) * CompressedEdwardsY(tx_pub_key).decompress().unwrap();
+/// let derivation = mul8(&shared_point);
+/// assert_eq!(hex::encode(derivation.compress().to_bytes()), "7e78087373ccb7ee318352660e0daf3a24ebaa6af7b0f836b1f56f9e2e3dfa3e");
+/// ```
+pub fn mul8(point: &EdwardsPoint) -> EdwardsPoint {
+    point.mul_by_cofactor()
+}
@@ -0,0 +1,39 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+use super::ed25519::sc_reduce32;
+
+/// Reduces given 32-byte little-endian integer modulo the order of the Ed25519 group, returning a valid scalar
+///
+/// Example:
+/// ```
+/// use libmonero::crypt::reduce_scalar;
+///
+/// let scalar: [u8; 32] = reduce_scalar([0xff; 32]);
+/// assert_eq!(hex::encode(scalar), "1c95988d7431ecd670cf7d73f45befc6feffffffffffffffffffffffffffff0f");
+/// ```
+pub fn reduce_scalar(bytes: [u8; 32]) -> [u8; 32] {
+    let mut scalar = bytes;
+    sc_reduce32(&mut scalar);
+    scalar
+}
+
+/// Checks if given 32-byte little-endian integer is not a canonical (already reduced) scalar
+///
+/// Example:
+/// ```
+/// use libmonero::crypt::needed_reduction;
+///
+/// assert!(needed_reduction(&[0xff; 32]));
+/// assert!(!needed_reduction(&[0x01; 32]));
+/// ```
+pub fn needed_reduction(bytes: &[u8; 32]) -> bool {
+    reduce_scalar(*bytes) != *bytes
+}
@@ -0,0 +1,533 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+//! # Address
+//!
+//! This module is for everything related to addresses, such as deriving them from public keys.
+
+use super::errors::KeyError;
+use super::keys::{decode_hex32, derive_all_keys, derive_mymonero_priv_keys, derive_pub_key, validate_mnemonic, with_rng};
+use crate::crypt::cn_fast_hash;
+use base58_monero::base58::BASE58_CHARS;
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use std::collections::HashMap;
+
+/// Networks of Monero, their values are the same as the `network` argument of [`derive_address`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Network {
+    Mainnet = 0,
+    Testnet = 1,
+    Stagenet = 2,
+}
+
+/// Types of Monero addresses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AddressType {
+    /// Main address of a wallet, with its public spend and view keys
+    Standard,
+    /// Main address with a payment ID
+    Integrated,
+    /// Subaddress of a wallet, with public keys of its own that differ from the wallet's ones and do not reveal its
+    /// account and index
+    Subaddress,
+}
+
+/// Prefixes of every Monero address type on every network
+pub const MONERO_PREFIXES: [(Network, AddressType, u64); 9] = [
+    (Network::Mainnet, AddressType::Standard, 0x12),
+    (Network::Mainnet, AddressType::Integrated, 0x13),
+    (Network::Mainnet, AddressType::Subaddress, 0x2a),
+    (Network::Testnet, AddressType::Standard, 0x35),
+    (Network::Testnet, AddressType::Integrated, 0x36),
+    (Network::Testnet, AddressType::Subaddress, 0x3f),
+    (Network::Stagenet, AddressType::Standard, 0x18),
+    (Network::Stagenet, AddressType::Integrated, 0x19),
+    (Network::Stagenet, AddressType::Subaddress, 0x24),
+];
+
+/// Returns the Monero prefix of given network and address type
+pub(crate) fn monero_prefix(network: Network, address_type: AddressType) -> u64 {
+    MONERO_PREFIXES
+        .iter()
+        .find(|(n, t, _)| *n == network && *t == address_type)
+        .map(|(_, _, prefix)| *prefix)
+        .unwrap()
+}
+
+/// Coins sharing Monero's address format, they only differ in their network prefixes
+///
+/// Coins:
+/// - `Monero` : Monero (default), see [`MONERO_PREFIXES`]
+/// - `Aeon` : Aeon (mainnet only)
+/// - `Custom` : Any other compatible coin, given its mainnet and testnet prefixes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Coin {
+    #[default]
+    Monero,
+    Aeon,
+    Custom { mainnet: u64, testnet: u64 },
+}
+
+/// Coin functions etc.
+impl Coin {
+    /// Returns the standard address prefix of the coin for given network, if the coin has one
+    ///
+    /// Example:
+    /// ```
+    /// use libmonero::keys::Coin;
+    ///
+    /// assert_eq!(Coin::Monero.address_prefix(0), Some(0x12));
+    /// assert_eq!(Coin::Aeon.address_prefix(1), None);
+    /// ```
+    pub fn address_prefix(&self, network: u8) -> Option<u64> {
+        match (self, network) {
+            (Coin::Monero, 0) => Some(monero_prefix(Network::Mainnet, AddressType::Standard)),
+            (Coin::Monero, 1) => Some(monero_prefix(Network::Testnet, AddressType::Standard)),
+            (Coin::Monero, 2) => Some(monero_prefix(Network::Stagenet, AddressType::Standard)),
+            (Coin::Aeon, 0) => Some(0xb2), // Aeon mainnet
+            (Coin::Custom { mainnet, .. }, 0) => Some(*mainnet),
+            (Coin::Custom { testnet, .. }, 1) => Some(*testnet),
+            _ => None,
+        }
+    }
+}
+
+/// Encodes given number as a varint, the way Monero encodes address prefixes
+pub(crate) fn encode_varint(mut number: u64) -> Vec<u8> {
+    let mut bytes: Vec<u8> = Vec::new();
+    while number >= 0x80 {
+        bytes.push((number as u8 & 0x7f) | 0x80);
+        number >>= 7;
+    }
+    bytes.push(number as u8);
+    bytes
+}
+
+/// Decodes a varint from the start of given bytes, returns the number and how many bytes it took
+fn decode_varint(bytes: &[u8]) -> Option<(u64, usize)> {
+    let mut number: u64 = 0;
+    for (i, byte) in bytes.iter().enumerate().take(10) {
+        number |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Some((number, i + 1));
+        }
+    }
+    None
+}
+
+/// Derives main public address from given public spend key, public view key and network
+///
+/// Both keys must be valid Ed25519 points and differ from each other, or [`KeyError::InvalidPoint`] or
+/// [`KeyError::DuplicateKeys`] is returned.
+///
+/// Networks:
+/// - `0` : Monero Mainnet
+/// - `1` : Monero Testnet
+/// - `2` : Monero Stagenet
+///
+/// Example:
+/// ```
+/// use libmonero::keys::derive_address;
+///
+/// let public_spend_key: String = "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95".to_string();
+/// let public_view_key: String = "157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47".to_string();
+/// let public_address: String = derive_address(public_spend_key, public_view_key, 0).unwrap();
+/// assert_eq!(public_address, "4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J".to_string());
+/// ```
+pub fn derive_address(public_spend_key: String, public_view_key: String, network: u8) -> Result<String, KeyError> {
+    derive_address_for_coin(public_spend_key, public_view_key, network, Coin::Monero)
+}
+
+/// Derives main public address of given coin from given public spend key, public view key and network
+///
+/// Only the prefix depends on the coin, the checksum is computed the same way for all of them.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{derive_address_for_coin, Coin};
+///
+/// let public_spend_key: String = "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95".to_string();
+/// let public_view_key: String = "157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47".to_string();
+/// let public_address: String = derive_address_for_coin(public_spend_key, public_view_key, 0, Coin::Aeon).unwrap();
+/// assert!(public_address.starts_with("Wm"));
+/// ```
+pub fn derive_address_for_coin(public_spend_key: String, public_view_key: String, network: u8, coin: Coin) -> Result<String, KeyError> {
+    let prefix = coin.address_prefix(network).ok_or(KeyError::InvalidNetwork)?;
+    let pub_sk_bytes = decode_hex32(&public_spend_key)?;
+    let pub_vk_bytes = decode_hex32(&public_view_key)?;
+    encode_address(prefix, &pub_sk_bytes, &pub_vk_bytes)
+}
+
+/// Derives main public address from given public spend key and public view key as bytes, on given network
+///
+/// Like [`derive_address`] without hex decoding, for callers deriving keys as bytes, e.g. with
+/// [`derive_pub_key_bytes`](super::derive_pub_key_bytes). The keys are checked the same way.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{derive_address_bytes, Network};
+///
+/// let public_spend_key: [u8; 32] = hex::decode("e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95").unwrap().try_into().unwrap();
+/// let public_view_key: [u8; 32] = hex::decode("157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47").unwrap().try_into().unwrap();
+/// let public_address: String = derive_address_bytes(public_spend_key, public_view_key, Network::Mainnet).unwrap();
+/// assert_eq!(public_address, "4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J".to_string());
+/// ```
+pub fn derive_address_bytes(public_spend_key: [u8; 32], public_view_key: [u8; 32], network: Network) -> Result<String, KeyError> {
+    encode_address(monero_prefix(network, AddressType::Standard), &public_spend_key, &public_view_key)
+}
+
+/// Derives the integrated address of given public spend key and public view key with given 8-byte payment ID, on given
+/// network
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{derive_integrated_address, Network};
+///
+/// let public_spend_key: &str = "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95";
+/// let public_view_key: &str = "157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47";
+/// let payment_id: [u8; 8] = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef];
+/// let integrated_address: String = derive_integrated_address(public_spend_key, public_view_key, Network::Mainnet, payment_id).unwrap();
+/// assert_eq!(integrated_address, "4L6ikG8AkHVNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U6CsqG2trjYYkU4Bb6wW".to_string());
+/// ```
+pub fn derive_integrated_address(public_spend_key: &str, public_view_key: &str, network: Network, payment_id: [u8; 8]) -> Result<String, KeyError> {
+    let pub_sk_bytes = decode_hex32(public_spend_key)?;
+    let pub_vk_bytes = decode_hex32(public_view_key)?;
+    encode_address_with_payment_id(monero_prefix(network, AddressType::Integrated), &pub_sk_bytes, &pub_vk_bytes, &payment_id)
+}
+
+/// Derives an integrated address like [`derive_integrated_address`], giving the address with the payment ID it embeds
+///
+/// Without a payment ID a random one is generated, so a payment processor can store the ID of every address it mints
+/// without decoding it again. Random IDs are 64 bits, collisions become likely past a few billion addresses per wallet.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{decode_address, derive_integrated_address_full, Network};
+///
+/// let public_spend_key: &str = "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95";
+/// let public_view_key: &str = "157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47";
+/// let (integrated_address, payment_id) = derive_integrated_address_full(public_spend_key, public_view_key, Network::Mainnet, None).unwrap();
+/// assert_eq!(decode_address(&integrated_address).unwrap().payment_id, Some(hex::encode(payment_id)));
+/// ```
+pub fn derive_integrated_address_full(public_spend_key: &str, public_view_key: &str, network: Network, payment_id: Option<[u8; 8]>) -> Result<(String, [u8; 8]), KeyError> {
+    let payment_id = payment_id.unwrap_or_else(|| {
+        with_rng(|rng| {
+            let mut payment_id = [0u8; 8];
+            rng.fill_bytes(&mut payment_id);
+            payment_id
+        })
+    });
+    let integrated_address = derive_integrated_address(public_spend_key, public_view_key, network, payment_id)?;
+    Ok((integrated_address, payment_id))
+}
+
+/// Checks that given public keys are valid Ed25519 points and not the same key, e.g. the spend key passed twice
+fn check_public_keys(public_spend_key: &[u8; 32], public_view_key: &[u8; 32]) -> Result<(), KeyError> {
+    for key in [public_spend_key, public_view_key] {
+        CompressedEdwardsY(*key).decompress().ok_or(KeyError::InvalidPoint)?;
+    }
+    if public_spend_key == public_view_key {
+        return Err(KeyError::DuplicateKeys);
+    }
+    Ok(())
+}
+
+/// Encodes an address from given prefix and public keys, appending its checksum
+pub(crate) fn encode_address(prefix: u64, public_spend_key: &[u8; 32], public_view_key: &[u8; 32]) -> Result<String, KeyError> {
+    encode_address_with_payment_id(prefix, public_spend_key, public_view_key, &[])
+}
+
+/// Encodes an address from given prefix, public keys and payment ID (empty but for integrated addresses), appending its
+/// checksum
+fn encode_address_with_payment_id(prefix: u64, public_spend_key: &[u8; 32], public_view_key: &[u8; 32], payment_id: &[u8]) -> Result<String, KeyError> {
+    check_public_keys(public_spend_key, public_view_key)?;
+    let network_bytes = encode_varint(prefix);
+    let mut data = [&network_bytes[..], &public_spend_key[..], &public_view_key[..], payment_id].concat();
+    let hash = cn_fast_hash(&data);
+    data.append(&mut hash[..4].to_vec());
+
+    debug!("built address with prefix {:#x}", prefix);
+    encode_base58(&data)
+}
+
+/// Derives main public addresses of every Monero network from given public spend key and public view key
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{derive_all_network_addresses, Network};
+///
+/// let public_spend_key: &str = "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95";
+/// let public_view_key: &str = "157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47";
+/// let addresses = derive_all_network_addresses(public_spend_key, public_view_key).unwrap();
+/// assert_eq!(addresses[&Network::Mainnet], "4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J".to_string());
+/// assert_eq!(addresses[&Network::Testnet], "A1wbDhxwRP5NGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U6938xeWT".to_string());
+/// assert_eq!(addresses[&Network::Stagenet], "5Ac5pJDdnd5NGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U6926FwD4".to_string());
+/// ```
+pub fn derive_all_network_addresses(public_spend_key: &str, public_view_key: &str) -> Result<HashMap<Network, String>, KeyError> {
+    let pub_sk_bytes = decode_hex32(public_spend_key)?;
+    let pub_vk_bytes = decode_hex32(public_view_key)?;
+    MONERO_PREFIXES
+        .iter()
+        .filter(|(_, address_type, _)| *address_type == AddressType::Standard)
+        .map(|(network, _, prefix)| Ok((*network, encode_address(*prefix, &pub_sk_bytes, &pub_vk_bytes)?)))
+        .collect()
+}
+
+/// Derives main public address from given private spend key, private view key and network
+///
+/// Unlike deriving from a seed, the private view key is taken as is instead of being derived from the private spend key.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::address_from_priv_keys;
+///
+/// let private_spend_key: &str = "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08";
+/// let private_view_key: &str = "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908";
+/// let public_address: String = address_from_priv_keys(private_spend_key, private_view_key, 0).unwrap();
+/// assert_eq!(public_address, "4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J".to_string());
+/// ```
+pub fn address_from_priv_keys(private_spend_key: &str, private_view_key: &str, network: u8) -> Result<String, KeyError> {
+    if Coin::Monero.address_prefix(network).is_none() {
+        return Err(KeyError::InvalidNetwork);
+    }
+    let public_spend_key = derive_pub_key(private_spend_key.to_string())?;
+    let public_view_key = derive_pub_key(private_view_key.to_string())?;
+    derive_address(public_spend_key, public_view_key, network)
+}
+
+/// Derives main public address from given MyMonero (32-character) hex seed and network, as the MyMonero wallet shows it
+///
+/// MyMonero derives the private view key from the hex seed rather than from the private spend key, so the same hex
+/// seed gives another address when it is restored as an original seed.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::mymonero_hex_to_address;
+///
+/// let public_address: String = mymonero_hex_to_address("fddb58a226ba0a61cc48e2a393739378", 0).unwrap();
+/// assert_eq!(public_address, "45PUwJmgsiVeKshFDNaPWshmHYyQyvTb63UxjRoK8hGw6seL4uX68t2EuZa77D5Z1c3Z8Xta3PCW6MX3JbSUJsu8K7oWjFs".to_string());
+/// ```
+pub fn mymonero_hex_to_address(hex_seed: &str, network: u8) -> Result<String, KeyError> {
+    let priv_keys = derive_mymonero_priv_keys(hex_seed.to_string())?;
+    address_from_priv_keys(&priv_keys[0], &priv_keys[1], network)
+}
+
+/// Checks if given mnemonic seed restores the expected main address on given network, e.g. to confirm a backup
+///
+/// Returns an error if the mnemonic seed itself is invalid (including a wrong checksum word) and `Ok(false)` if it is valid but restores another
+/// address. The addresses are compared in constant time.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::mnemonic_matches_address;
+///
+/// let mnemonic: Vec<String> = vec!["tissue", "raking", "haunted", "huts", "afraid", "volcano", "howls", "liar", "egotistic", "befit", "rounded", "older", "bluntly", "imbalance", "pivot", "exotic", "tuxedo", "amaze", "mostly", "lukewarm", "macro", "vocal", "hounded", "biplane", "rounded"].iter().map(|s| s.to_string()).collect();
+/// let address: &str = "4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J";
+/// assert_eq!(mnemonic_matches_address(&mnemonic, address, 0), Ok(true));
+/// assert_eq!(mnemonic_matches_address(&mnemonic, address, 2), Ok(false));
+/// ```
+pub fn mnemonic_matches_address(mnemonic: &[String], expected_address: &str, network: u8) -> Result<bool, KeyError> {
+    validate_mnemonic(mnemonic)?;
+    let keys = derive_all_keys(mnemonic.to_vec())?;
+    let address = derive_address(keys.pub_spend, keys.pub_view, network)?;
+    if address.len() != expected_address.len() {
+        return Ok(false);
+    }
+    let difference = address
+        .bytes()
+        .zip(expected_address.bytes())
+        .fold(0u8, |difference, (a, b)| difference | (a ^ b));
+    Ok(difference == 0)
+}
+
+/// Encodes given bytes in base58, an encoder error is given as [`KeyError::Base58`] instead of a panic
+fn encode_base58(data: &[u8]) -> Result<String, KeyError> {
+    base58_monero::encode(data).map_err(|error| KeyError::Base58(format!("{} for {} bytes", error, data.len())))
+}
+
+/// Decodes given base58 string, the error tells where it is malformed if it can
+fn decode_base58(encoded: &str) -> Result<Vec<u8>, KeyError> {
+    // Symbols are checked first, the position of a mistyped one is more helpful than a block size error
+    let invalid_symbol = encoded
+        .chars()
+        .enumerate()
+        .find(|(_, c)| !c.is_ascii() || !BASE58_CHARS.contains(&(*c as u8)));
+    if let Some((position, c)) = invalid_symbol {
+        return Err(KeyError::Base58(format!("Invalid symbol '{}' at position {}", c, position)));
+    }
+    base58_monero::decode(encoded)
+        .map_err(|error| KeyError::Base58(format!("{} for length {}", error, encoded.len())))
+}
+
+/// Checks the prefix, length and checksum of given decoded address, returns its network, type and prefix length
+fn check_address_bytes(data: &[u8]) -> Result<(Network, AddressType, usize), KeyError> {
+    // Find out network and type from the prefix
+    let (prefix, prefix_len) = decode_varint(data).ok_or(KeyError::InvalidAddress)?;
+    let (network, address_type, _) = MONERO_PREFIXES
+        .iter()
+        .find(|(_, _, p)| *p == prefix)
+        .ok_or(KeyError::UnknownPrefix(data[0]))?;
+    let payment_id_len = match address_type {
+        AddressType::Integrated => 8,
+        _ => 0,
+    };
+    if data.len() != prefix_len + 64 + payment_id_len + 4 {
+        return Err(KeyError::InvalidAddress);
+    }
+    // Verify the checksum
+    let (payload, checksum) = data.split_at(data.len() - 4);
+    if cn_fast_hash(payload)[..4] != *checksum {
+        return Err(KeyError::InvalidChecksum);
+    }
+    Ok((*network, *address_type, prefix_len))
+}
+
+/// Decodes given Monero address into its raw bytes: prefix, public spend key, public view key, payment ID (integrated
+/// addresses only) and checksum, verifying the checksum
+///
+/// Standard addresses and subaddresses are 69 bytes, integrated addresses 77 bytes. See [`bytes_to_address`] for
+/// encoding them back.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::address_to_bytes;
+///
+/// let bytes: Vec<u8> = address_to_bytes("4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J").unwrap();
+/// assert_eq!(bytes.len(), 69);
+/// assert_eq!(bytes[0], 0x12);
+/// assert_eq!(hex::encode(&bytes[1..33]), "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95");
+/// ```
+pub fn address_to_bytes(address: &str) -> Result<Vec<u8>, KeyError> {
+    let data = decode_base58(address)?;
+    check_address_bytes(&data)?;
+    Ok(data)
+}
+
+/// Encodes raw address bytes made by [`address_to_bytes`] back into a Monero address, verifying their checksum
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{address_to_bytes, bytes_to_address};
+///
+/// let address: &str = "4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J";
+/// assert_eq!(bytes_to_address(&address_to_bytes(address).unwrap()).unwrap(), address.to_string());
+/// ```
+pub fn bytes_to_address(bytes: &[u8]) -> Result<String, KeyError> {
+    check_address_bytes(bytes)?;
+    encode_base58(bytes)
+}
+
+/// Normalizes given Monero address to its canonical base58 form, so copies of the same address compare equal
+///
+/// Whitespace and invisible characters (zero-width spaces, byte order marks) picked up while copying are dropped
+/// anywhere in the address before it is decoded and encoded again. Base58 is case-sensitive, so a change of case
+/// cannot be undone and gives an error like any other corruption. Canonical addresses are returned as they are.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::normalize_address;
+///
+/// let address: &str = " 4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J\n";
+/// assert_eq!(normalize_address(address).unwrap(), "4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J".to_string());
+/// ```
+pub fn normalize_address(address: &str) -> Result<String, KeyError> {
+    let cleaned: String = address
+        .chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '\u{200b}'..='\u{200d}' | '\u{2060}' | '\u{feff}'))
+        .collect();
+    bytes_to_address(&address_to_bytes(&cleaned)?)
+}
+
+/// DecodedAddress struct contains everything encoded in a Monero address
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedAddress {
+    pub network: Network,
+    pub address_type: AddressType,
+    /// Public spend key of the address, the subaddress's own one for subaddresses
+    pub public_spend_key: String,
+    /// Public view key of the address, the subaddress's own one for subaddresses
+    pub public_view_key: String,
+    /// Only present in integrated addresses
+    pub payment_id: Option<String>,
+}
+
+/// Decodes given Monero address into its network, type, public keys and payment ID, verifying its checksum
+///
+/// Truncated or mistyped input that is not valid base58 gives [`KeyError::Base58`], telling where it is malformed.
+/// The decoded bytes are encoded again and must give the address back, else [`KeyError::NonCanonicalEncoding`] is
+/// given, so the address string can be used as an identifier of the wallet. The base58 decoder already rejects blocks
+/// overflowing their byte size, this check holds whatever the decoder accepts. Addresses with a prefix of no Monero
+/// network and address type, e.g. addresses of other coins, give [`KeyError::UnknownPrefix`] with the first byte of
+/// their prefix.
+///
+/// The public keys of a subaddress are the subaddress-specific ones it embeds, not the keys of the wallet's main
+/// address. Outputs sent to a subaddress are scanned with its public spend key, e.g. with
+/// [`scan_outputs`](super::scan_outputs).
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{decode_address, AddressType, Network};
+///
+/// let decoded = decode_address("4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J").unwrap();
+/// assert_eq!(decoded.network, Network::Mainnet);
+/// assert_eq!(decoded.address_type, AddressType::Standard);
+/// assert_eq!(decoded.public_spend_key, "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95".to_string());
+/// assert_eq!(decoded.public_view_key, "157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47".to_string());
+///
+/// // Subaddress (0, 1) of the same wallet
+/// let decoded = decode_address("86iB6SuaTL46ns1jzTGqeqaEpjCuNXpPzNVsVugBaFndMUGLdU3xXRbS35t7YGRhryCDc64kdVLJZYB4PMsnXSRGSpnNvUz").unwrap();
+/// assert_eq!(decoded.address_type, AddressType::Subaddress);
+/// assert_eq!(decoded.public_spend_key, "7057bcd5980c41229ed992e0510a6ac6b80e1fb6fe59118086da5c076298767a".to_string());
+/// ```
+pub fn decode_address(address: &str) -> Result<DecodedAddress, KeyError> {
+    let data = decode_base58(address)?;
+    let (network, address_type, prefix_len) = check_address_bytes(&data)?;
+    // Only one string may decode to an address, else two strings stored as identifiers could be the same wallet
+    if encode_base58(&data).ok().as_deref() != Some(address) {
+        return Err(KeyError::NonCanonicalEncoding);
+    }
+    let keys = &data[prefix_len..data.len() - 4];
+    Ok(DecodedAddress {
+        network,
+        address_type,
+        public_spend_key: hex::encode(&keys[..32]),
+        public_view_key: hex::encode(&keys[32..64]),
+        payment_id: match address_type {
+            AddressType::Integrated => Some(hex::encode(&keys[64..])),
+            _ => None,
+        },
+    })
+}
+
+/// Returns the public spend key encoded in given Monero address, see [`decode_address`] for the errors
+///
+/// Example:
+/// ```
+/// use libmonero::keys::address_public_spend_key;
+///
+/// let public_spend_key: String = address_public_spend_key("4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J").unwrap();
+/// assert_eq!(public_spend_key, "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95".to_string());
+/// ```
+pub fn address_public_spend_key(address: &str) -> Result<String, KeyError> {
+    Ok(decode_address(address)?.public_spend_key)
+}
+
+/// Returns the public view key encoded in given Monero address, see [`decode_address`] for the errors
+///
+/// Example:
+/// ```
+/// use libmonero::keys::address_public_view_key;
+///
+/// let public_view_key: String = address_public_view_key("4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J").unwrap();
+/// assert_eq!(public_view_key, "157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47".to_string());
+/// ```
+pub fn address_public_view_key(address: &str) -> Result<String, KeyError> {
+    Ok(decode_address(address)?.public_view_key)
+}
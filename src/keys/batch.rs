@@ -0,0 +1,144 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+//! # Batch
+//!
+//! This module is for decoding many seeds at once, e.g. when migrating wallets, with lookup tables built only once.
+
+use super::errors::KeyError;
+use super::keys::{missing_word_error, word_prefix, words_to_hex_seed, SEED_WORD_COUNTS};
+use crate::mnemonics::original::wordsets::{WordsetOriginal, WORDSETSORIGINAL};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use unicode_normalization::UnicodeNormalization;
+
+/// Lookup tables of an original (1626-word) wordset
+struct WordsetTable {
+    wordset: &'static WordsetOriginal,
+    /// Index of each word
+    words: HashMap<&'static str, usize>,
+    /// Index of each word prefix, which is the whole word for wordsets without a prefix length
+    prefixes: HashMap<&'static str, usize>,
+}
+
+/// Tables of every original wordset, built on first use and shared by all batches
+static TABLES: OnceLock<Vec<WordsetTable>> = OnceLock::new();
+/// Non-ASCII words of every original wordset by their NFKD normalization, built on first use
+static NORMALIZED_WORDS: OnceLock<HashMap<String, &'static str>> = OnceLock::new();
+
+/// Returns the tables of every original wordset, in the order of the wordsets
+fn tables() -> &'static [WordsetTable] {
+    TABLES.get_or_init(|| {
+        WORDSETSORIGINAL
+            .iter()
+            .map(|wordset| {
+                let mut table = WordsetTable {
+                    wordset,
+                    words: HashMap::with_capacity(wordset.words.len()),
+                    prefixes: HashMap::with_capacity(wordset.words.len()),
+                };
+                // The first word wins, like a linear search through the wordset
+                for (index, &word) in wordset.words.iter().enumerate() {
+                    table.words.entry(word).or_insert(index);
+                    table.prefixes.entry(word_prefix(word, wordset.prefix_len)).or_insert(index);
+                }
+                table
+            })
+            .collect()
+    })
+}
+
+/// Replaces the non-ASCII words of given seed with the wordset word they are equal to after NFKD normalization, like
+/// `normalize_seed_words` but with a table
+fn normalize_with_table(mnemonic_seed: Vec<String>) -> Vec<String> {
+    let normalized_words = NORMALIZED_WORDS.get_or_init(|| {
+        let mut normalized_words = HashMap::new();
+        for word in WORDSETSORIGINAL.iter().flat_map(|wordset| wordset.words.iter()) {
+            if !word.is_ascii() {
+                normalized_words.entry(word.nfkd().collect()).or_insert(*word);
+            }
+        }
+        normalized_words
+    });
+    mnemonic_seed
+        .into_iter()
+        .map(|word| {
+            if word.is_ascii() {
+                return word;
+            }
+            let normalized: String = word.nfkd().collect();
+            normalized_words.get(&normalized).map_or(word, |known| known.to_string())
+        })
+        .collect()
+}
+
+/// Decodes one seed of a batch like `derive_hex_seed`, with the wordset tables
+fn derive_hex_seed_with_tables(mnemonic_seed: Vec<String>, tables: &[WordsetTable]) -> Result<String, KeyError> {
+    if !SEED_WORD_COUNTS.contains(&mnemonic_seed.len()) {
+        return Err(KeyError::WrongWordCount {
+            got: mnemonic_seed.len(),
+            expected: &SEED_WORD_COUNTS,
+        });
+    }
+    let mut mnemonic_seed = normalize_with_table(mnemonic_seed);
+    // The wordset containing the most of the words, and all of them
+    let mut table: Option<&WordsetTable> = None;
+    let mut most_matches: usize = 0;
+    for candidate in tables {
+        let matches = mnemonic_seed
+            .iter()
+            .filter(|word| candidate.words.contains_key(word.as_str()))
+            .count();
+        if matches > most_matches {
+            table = Some(candidate);
+            most_matches = matches;
+        }
+    }
+    let table = table.ok_or(KeyError::UnknownWordset)?;
+    if mnemonic_seed
+        .iter()
+        .any(|word| !table.words.contains_key(word.as_str()))
+    {
+        return Err(missing_word_error(&mnemonic_seed, table.wordset));
+    }
+    // Remove checksum word, if there is one
+    if table.wordset.prefix_len > 0 && mnemonic_seed.len() % 3 == 1 {
+        mnemonic_seed.pop();
+    }
+    words_to_hex_seed(&mnemonic_seed, table.wordset, |word| {
+        table
+            .prefixes
+            .get(word_prefix(word, table.wordset.prefix_len))
+            .copied()
+    })
+}
+
+/// Derives the hex seeds of many mnemonic seeds, giving the result of each seed like [`derive_hex_seed`](super::derive_hex_seed)
+///
+/// The lookup tables of the wordsets are built on the first call and reused by every following one, so wordset
+/// detection and word lookup cost a hash lookup per word instead of a search through the wordsets. A bad seed only
+/// fails its own result.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{derive_hex_seeds_batch, KeyError};
+///
+/// let mnemonic: Vec<String> = vec!["tissue", "raking", "haunted", "huts", "afraid", "volcano", "howls", "liar", "egotistic", "befit", "rounded", "older", "bluntly", "imbalance", "pivot", "exotic", "tuxedo", "amaze", "mostly", "lukewarm", "macro", "vocal", "hounded", "biplane", "rounded"].iter().map(|s| s.to_string()).collect();
+/// let hex_seeds: Vec<Result<String, KeyError>> = derive_hex_seeds_batch(vec![mnemonic.clone(), mnemonic[..20].to_vec()]);
+/// assert_eq!(hex_seeds[0], Ok("f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8".to_string()));
+/// assert!(hex_seeds[1].is_err());
+/// ```
+pub fn derive_hex_seeds_batch(seeds: Vec<Vec<String>>) -> Vec<Result<String, KeyError>> {
+    let tables = tables();
+    seeds
+        .into_iter()
+        .map(|mnemonic_seed| derive_hex_seed_with_tables(mnemonic_seed, tables))
+        .collect()
+}
@@ -0,0 +1,109 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+//! # BIP39
+//!
+//! This module is for deriving Monero keys from BIP39 mnemonics, see <https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki>.
+
+use super::errors::KeyError;
+use super::keys::{derive_priv_keys, derive_pub_key, Keys};
+use super::polyseed::{polyseed_find_word, polyseed_word_error};
+use crate::crypt::cn_fast_hash;
+use crate::mnemonics::polyseed::wordsets::WORDSETSPOLYSEED;
+use pbkdf2::pbkdf2_hmac;
+use sha2::{Digest, Sha256, Sha512};
+use unicode_normalization::UnicodeNormalization;
+
+/// Word counts of BIP39 mnemonics, 128 to 256 bits of entropy
+pub const BIP39_WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+/// Number of iterations of the PBKDF2-HMAC-SHA512 seed derivation
+const BIP39_ITERATIONS: u32 = 2048;
+
+/// Checks the checksum of a BIP39 mnemonic given as word indexes, the last `count / 3` bits are the first bits of the
+/// SHA-256 hash of the entropy
+fn bip39_checksum_valid(indexes: &[usize]) -> bool {
+    let bits: Vec<bool> = indexes
+        .iter()
+        .flat_map(|&index| (0..11).rev().map(move |bit| (index >> bit) & 1 == 1))
+        .collect();
+    let checksum_bits = indexes.len() / 3;
+    let (entropy_bits, checksum) = bits.split_at(bits.len() - checksum_bits);
+    let entropy: Vec<u8> = entropy_bits
+        .chunks(8)
+        .map(|byte| byte.iter().fold(0u8, |acc, &bit| (acc << 1) | bit as u8))
+        .collect();
+    let hash = Sha256::digest(&entropy);
+    checksum
+        .iter()
+        .enumerate()
+        .all(|(i, &bit)| ((hash[i / 8] >> (7 - i % 8)) & 1 == 1) == bit)
+}
+
+/// Derives all four Monero keys from given BIP39 mnemonic and passphrase
+///
+/// > NOTE: This is a convention of this crate, not a standard. The 64-byte BIP39 seed (PBKDF2-HMAC-SHA512 of the
+/// > mnemonic, salted with `"mnemonic"` and the passphrase) is hashed with Keccak-256 into the hex seed of an original
+/// > seed, which gives the keys. Other wallets deriving Monero keys from BIP39 (e.g. hardware wallets following BIP44)
+/// > give other keys for the same mnemonic, and no Monero wallet can restore these keys from the BIP39 mnemonic.
+///
+/// The mnemonic may be in any BIP39 language, its words are checked against the wordlists and its checksum is checked.
+/// The seed derivation uses the words as given, so abbreviated words give other keys, like they would in other BIP39
+/// wallets.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{bip39_to_monero_keys, Keys};
+///
+/// let keys: Keys = bip39_to_monero_keys("abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about", "TREZOR").unwrap();
+/// assert_eq!(keys.priv_spend, "226d12549eca035ea0c882d6ba71b6387e67d11ba21b7499cb1dba2842684b03");
+/// assert_eq!(keys.pub_spend, "1e233b071a62f215be47b94ad81b07df09fcd7deb60add95ebbc030e356a58a5");
+/// ```
+pub fn bip39_to_monero_keys(bip39_mnemonic: &str, passphrase: &str) -> Result<Keys, KeyError> {
+    let words: Vec<String> = bip39_mnemonic.split_whitespace().map(|word| word.to_string()).collect();
+    if !BIP39_WORD_COUNTS.contains(&words.len()) {
+        return Err(KeyError::WrongWordCount {
+            got: words.len(),
+            expected: &BIP39_WORD_COUNTS,
+        });
+    }
+    // Wordlists share words, so every wordlist containing all of the words is tried
+    let mut found_wordset = false;
+    let mut checksum_valid = false;
+    for wordset in WORDSETSPOLYSEED.iter() {
+        let indexes: Option<Vec<usize>> = words.iter().map(|word| polyseed_find_word(wordset, word)).collect();
+        if let Some(indexes) = indexes {
+            found_wordset = true;
+            if bip39_checksum_valid(&indexes) {
+                checksum_valid = true;
+                break;
+            }
+        }
+    }
+    if !found_wordset {
+        return Err(polyseed_word_error(&words));
+    }
+    if !checksum_valid {
+        return Err(KeyError::InvalidSeedChecksum);
+    }
+
+    let sentence: String = words.join(" ").nfkd().collect();
+    let salt: String = format!("mnemonic{}", passphrase).nfkd().collect();
+    let mut seed = [0u8; 64];
+    pbkdf2_hmac::<Sha512>(sentence.as_bytes(), salt.as_bytes(), BIP39_ITERATIONS, &mut seed);
+    let priv_keys = derive_priv_keys(hex::encode(cn_fast_hash(&seed)))?;
+    let pub_spend = derive_pub_key(priv_keys[0].clone())?;
+    let pub_view = derive_pub_key(priv_keys[1].clone())?;
+    Ok(Keys {
+        priv_spend: priv_keys[0].clone(),
+        pub_spend,
+        priv_view: priv_keys[1].clone(),
+        pub_view,
+    })
+}
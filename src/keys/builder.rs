@@ -0,0 +1,144 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+//! # Seed builder
+//!
+//! This module is for generating seeds with more options than `generate_seed` takes, set one by one on a builder.
+
+use super::errors::KeyError;
+use super::keys::{seed_entropy_bits, with_rng};
+use super::polyseed::{now_timestamp, POLYSEED_WORD_COUNT};
+use super::scheme::{seed_scheme, PolyseedSeed};
+use rand::RngCore;
+
+/// SeedBuilder generates a mnemonic seed with the options set on it
+///
+/// Options left unset default to an English original seed, drawn from a cryptographically secure random number
+/// generator. Birthday, features and word count only apply to polyseed seeds, setting them for another seed type is an
+/// error.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{KeyError, SeedBuilder};
+///
+/// let mnemonic: Vec<String> = SeedBuilder::new().language("en").seed_type("polyseed").features(0).build().unwrap();
+/// assert_eq!(mnemonic.len(), 16);
+/// let mnemonic: Vec<String> = SeedBuilder::new().language("fr").strength(128).build().unwrap();
+/// assert_eq!(mnemonic.len(), 13);
+/// assert_eq!(SeedBuilder::new().birthday(1700000000).build(), Err(KeyError::InvalidSeedOption("birthday")));
+/// assert_eq!(SeedBuilder::new().seed_type("polyseed").word_count(12).build(), Err(KeyError::WrongWordCount { got: 12, expected: &[16] }));
+/// ```
+#[derive(Default)]
+pub struct SeedBuilder<'a> {
+    language: Option<&'a str>,
+    seed_type: Option<&'a str>,
+    strength: Option<u16>,
+    birthday: Option<u64>,
+    features: Option<u8>,
+    word_count: Option<usize>,
+    rng: Option<&'a mut dyn RngCore>,
+}
+
+impl<'a> SeedBuilder<'a> {
+    /// Creates a builder with no option set
+    pub fn new() -> SeedBuilder<'a> {
+        SeedBuilder::default()
+    }
+
+    /// Sets the language of the seed, see [`generate_seed`](super::generate_seed) for the languages of each seed type
+    pub fn language(mut self, language: &'a str) -> SeedBuilder<'a> {
+        self.language = Some(language);
+        self
+    }
+
+    /// Sets the seed type, `original`, `mymonero` or `polyseed`
+    pub fn seed_type(mut self, seed_type: &'a str) -> SeedBuilder<'a> {
+        self.seed_type = Some(seed_type);
+        self
+    }
+
+    /// Sets the strength of the seed in bits, see [`seed_entropy_bits`](super::seed_entropy_bits)
+    ///
+    /// Without a seed type, the strength picks it: 128 bits for `mymonero`, 150 for `polyseed` and 256 for `original`.
+    pub fn strength(mut self, bits: u16) -> SeedBuilder<'a> {
+        self.strength = Some(bits);
+        self
+    }
+
+    /// Sets the birthday (unix timestamp) of a polyseed seed, now if unset
+    pub fn birthday(mut self, timestamp: u64) -> SeedBuilder<'a> {
+        self.birthday = Some(timestamp);
+        self
+    }
+
+    /// Sets the user features (3 bits, 0 to 7) of a polyseed seed, none if unset
+    pub fn features(mut self, features: u8) -> SeedBuilder<'a> {
+        self.features = Some(features);
+        self
+    }
+
+    /// Sets the number of words of a polyseed seed, 16 if unset
+    ///
+    /// Only the word counts of [`POLYSEED_WORD_COUNTS`](super::POLYSEED_WORD_COUNTS) are accepted, others give
+    /// [`KeyError::WrongWordCount`].
+    pub fn word_count(mut self, word_count: usize) -> SeedBuilder<'a> {
+        self.word_count = Some(word_count);
+        self
+    }
+
+    /// Sets the random number generator to draw the seed from
+    ///
+    /// > WARNING: The generator must be cryptographically secure, anyone who can reproduce its output gets the seed
+    pub fn rng(mut self, rng: &'a mut dyn RngCore) -> SeedBuilder<'a> {
+        self.rng = Some(rng);
+        self
+    }
+
+    /// Generates the seed with the options set
+    pub fn build(self) -> Result<Vec<String>, KeyError> {
+        let seed_type = match (self.seed_type, self.strength) {
+            (Some(seed_type), _) => seed_type,
+            (None, None) | (None, Some(256)) => "original",
+            (None, Some(128)) => "mymonero",
+            (None, Some(150)) => "polyseed",
+            (None, Some(bits)) => return Err(KeyError::UnsupportedStrength(bits)),
+        };
+        let scheme = seed_scheme(seed_type)?;
+        if let Some(bits) = self.strength {
+            if seed_entropy_bits(seed_type)? != u32::from(bits) {
+                return Err(KeyError::UnsupportedStrength(bits));
+            }
+        }
+        if seed_type != "polyseed" {
+            if self.birthday.is_some() {
+                return Err(KeyError::InvalidSeedOption("birthday"));
+            }
+            if self.features.is_some() {
+                return Err(KeyError::InvalidSeedOption("features"));
+            }
+            if self.word_count.is_some() {
+                return Err(KeyError::InvalidSeedOption("word_count"));
+            }
+        }
+        let language = self.language.unwrap_or("en");
+        let generate = |rng: &mut dyn RngCore| match seed_type {
+            "polyseed" => {
+                let timestamp = self.birthday.unwrap_or_else(now_timestamp);
+                let word_count = self.word_count.unwrap_or(POLYSEED_WORD_COUNT);
+                PolyseedSeed.generate_with(language, word_count, timestamp, self.features.unwrap_or(0), rng)
+            }
+            _ => scheme.generate(language, rng),
+        };
+        match self.rng {
+            Some(rng) => generate(rng),
+            None => with_rng(generate),
+        }
+    }
+}
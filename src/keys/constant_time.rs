@@ -0,0 +1,147 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+//! # Constant time
+//!
+//! This module is for decoding seeds without timing leaks about which words they contain.
+
+use super::errors::KeyError;
+use super::keys::{missing_word_error, normalize_seed_words, word_prefix, words_to_hex_seed, SEED_WORD_COUNTS};
+use crate::mnemonics::original::wordsets::WORDSETSORIGINAL;
+use std::hint::black_box;
+use std::sync::OnceLock;
+
+/// Number of bytes words are padded to for comparison, every word of the original wordsets fits
+const PADDED_LEN: usize = 32;
+
+/// A word padded with zeros to `PADDED_LEN` bytes, with its real length
+#[derive(Clone, Copy)]
+struct PaddedWord {
+    bytes: [u8; PADDED_LEN],
+    len: usize,
+}
+
+impl PaddedWord {
+    /// Pads given word, longer words are cut but keep their length so they match no word of the wordsets
+    fn new(word: &str) -> PaddedWord {
+        let mut bytes = [0u8; PADDED_LEN];
+        let len = word.len().min(PADDED_LEN);
+        bytes[..len].copy_from_slice(&word.as_bytes()[..len]);
+        PaddedWord { bytes, len: word.len() }
+    }
+}
+
+/// Padded words and word prefixes of an original wordset
+struct PaddedWordset {
+    words: Vec<PaddedWord>,
+    prefixes: Vec<PaddedWord>,
+}
+
+/// Padded copies of every original wordset, built on first use
+static PADDED_WORDSETS: OnceLock<Vec<PaddedWordset>> = OnceLock::new();
+
+/// Returns the padded copies of every original wordset, in the order of the wordsets
+fn padded_wordsets() -> &'static [PaddedWordset] {
+    PADDED_WORDSETS.get_or_init(|| {
+        WORDSETSORIGINAL
+            .iter()
+            .map(|wordset| PaddedWordset {
+                words: wordset.words.iter().map(|word| PaddedWord::new(word)).collect(),
+                prefixes: wordset
+                    .words
+                    .iter()
+                    .map(|word| PaddedWord::new(word_prefix(word, wordset.prefix_len)))
+                    .collect(),
+            })
+            .collect()
+    })
+}
+
+/// Returns 1 if both words are equal, 0 otherwise, comparing all of their bytes
+fn ct_eq(a: &PaddedWord, b: &PaddedWord) -> u64 {
+    let mut diff = (a.len ^ b.len) as u64;
+    for (x, y) in a.bytes.iter().zip(b.bytes.iter()) {
+        diff |= u64::from(x ^ y);
+    }
+    // diff | -diff has its high bit set for any non-zero diff
+    black_box(((diff | diff.wrapping_neg()) >> 63) ^ 1)
+}
+
+/// Finds the first position of given word in given words, comparing it with all of them
+fn ct_position(word: &PaddedWord, words: &[PaddedWord]) -> Option<usize> {
+    let mut found: u64 = 0;
+    let mut position: u64 = 0;
+    for (i, candidate) in words.iter().enumerate() {
+        let equal = ct_eq(word, candidate);
+        // All ones for the first match only
+        let first = (equal & !found).wrapping_neg();
+        position |= i as u64 & first;
+        found |= equal;
+    }
+    (found == 1).then_some(position as usize)
+}
+
+/// Derives hexadecimal seed from the given mnemonic seed like [`derive_hex_seed`](super::derive_hex_seed), in constant
+/// time with respect to the words of the seed
+///
+/// `derive_hex_seed` stops searching a wordset at the first match, so how long it takes depends on where the words are
+/// in the wordsets. This function compares every word of the seed with every word of every original wordset, without
+/// exiting early, so the time taken only depends on the number of words. This matters for seed entry on shared
+/// machines, where other processes could measure the timing.
+///
+/// The price is speed: every word is compared byte by byte with all 13008 words of the 8 wordsets, so decoding takes
+/// about as long as the slowest seeds take with `derive_hex_seed`, around twice as long as seeds of words at the start
+/// of the wordsets (under a millisecond per seed, see `cargo bench --bench derive_hex_seed_ct`). The NFKD normalization of
+/// non-ASCII words and the errors of invalid seeds are not constant time.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{derive_hex_seed, derive_hex_seed_ct};
+///
+/// let mnemonic: Vec<String> = vec!["tissue", "raking", "haunted", "huts", "afraid", "volcano", "howls", "liar", "egotistic", "befit", "rounded", "older", "bluntly", "imbalance", "pivot", "exotic", "tuxedo", "amaze", "mostly", "lukewarm", "macro", "vocal", "hounded", "biplane", "rounded"].iter().map(|s| s.to_string()).collect();
+/// let hex_seed: String = derive_hex_seed_ct(mnemonic.clone()).unwrap();
+/// assert_eq!(hex_seed, derive_hex_seed(mnemonic).unwrap());
+/// ```
+pub fn derive_hex_seed_ct(mnemonic_seed: Vec<String>) -> Result<String, KeyError> {
+    if !SEED_WORD_COUNTS.contains(&mnemonic_seed.len()) {
+        return Err(KeyError::WrongWordCount {
+            got: mnemonic_seed.len(),
+            expected: &SEED_WORD_COUNTS,
+        });
+    }
+    let mut mnemonic_seed = normalize_seed_words(mnemonic_seed);
+    let padded_seed: Vec<PaddedWord> = mnemonic_seed.iter().map(|word| PaddedWord::new(word)).collect();
+    let padded_wordsets = padded_wordsets();
+    // The wordset containing the most of the words, every wordset is searched for every word
+    let mut best: Option<usize> = None;
+    let mut most_matches: usize = 0;
+    for (index, padded_wordset) in padded_wordsets.iter().enumerate() {
+        let matches: usize = padded_seed
+            .iter()
+            .map(|word| ct_position(word, &padded_wordset.words).is_some() as usize)
+            .sum();
+        if matches > most_matches {
+            best = Some(index);
+            most_matches = matches;
+        }
+    }
+    let index = best.ok_or(KeyError::UnknownWordset)?;
+    let (the_wordset, padded_wordset) = (&WORDSETSORIGINAL[index], &padded_wordsets[index]);
+    if most_matches != mnemonic_seed.len() {
+        return Err(missing_word_error(&mnemonic_seed, the_wordset));
+    }
+    // Remove checksum word, if there is one
+    if the_wordset.prefix_len > 0 && mnemonic_seed.len() % 3 == 1 {
+        mnemonic_seed.pop();
+    }
+    words_to_hex_seed(&mnemonic_seed, the_wordset, |word| {
+        ct_position(&PaddedWord::new(word_prefix(word, the_wordset.prefix_len)), &padded_wordset.prefixes)
+    })
+}
@@ -0,0 +1,87 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+//! # Dice
+//!
+//! This module is for generating seeds from entropy made by hand, rolls of a six-sided die.
+
+use super::errors::KeyError;
+use super::keys::{generate_seed_from_entropy, seed_entropy_bits, seed_entropy_len};
+
+/// Chi-squared statistic (5 degrees of freedom) above which rolls are taken as biased, fair rolls go above it about once
+/// in a million
+const BIAS_THRESHOLD: f64 = 35.9;
+
+/// Returns the number of die rolls needed for given bits of entropy, each roll giving log2(6) ≈ 2.585 bits
+fn dice_rolls_needed(bits: u32) -> usize {
+    // The smallest n with 6^n >= 2^bits
+    (f64::from(bits) / 6f64.log2()).ceil() as usize
+}
+
+/// Generates a mnemonic phrase of given language and seed type from rolls of a six-sided die
+///
+/// Every roll must be 1 to 6, otherwise [`KeyError::InvalidDiceRoll`] is returned. The seed type needs at least 100
+/// rolls for `original`, 50 for `mymonero` and 59 for `polyseed`, enough for its bits of entropy (see
+/// [`seed_entropy_bits`](super::seed_entropy_bits)), fewer give [`KeyError::NotEnoughRolls`]. More rolls are welcome.
+/// The rolls are read as a base-6 number, the first roll being its most significant digit, whose low bytes are given
+/// to [`generate_seed_from_entropy`](super::generate_seed_from_entropy). The same rolls always give the same seed
+/// (polyseed seeds are born now, so only their secret is the same).
+///
+/// Rolls whose faces are too uneven to come from a fair die, e.g. all the same face, give [`KeyError::BiasedDiceRolls`].
+/// Only the counts of the faces are checked (a chi-squared test), so patterns like counting from 1 to 6 over and over
+/// are not caught: roll the die, do not make the numbers up.
+///
+/// > WARNING: Write the rolls nowhere and roll on an offline machine, anyone who gets them gets the seed
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{seed_from_dice, KeyError};
+///
+/// let rolls: Vec<u8> = (0..100u32).map(|i| (i * 7 % 11 % 6 + 1) as u8).collect();
+/// let mnemonic: Vec<String> = seed_from_dice(&rolls, "en", "original").unwrap();
+/// assert_eq!(mnemonic.len(), 25);
+/// assert_eq!(seed_from_dice(&rolls, "en", "original"), Ok(mnemonic));
+/// assert_eq!(seed_from_dice(&rolls[..99], "en", "original"), Err(KeyError::NotEnoughRolls { got: 99, need: 100 }));
+/// assert_eq!(seed_from_dice(&[6; 100], "en", "original"), Err(KeyError::BiasedDiceRolls));
+/// ```
+pub fn seed_from_dice(rolls: &[u8], language: &str, seed_type: &str) -> Result<Vec<String>, KeyError> {
+    let entropy_len = seed_entropy_len(seed_type)?;
+    if let Some((position, &roll)) = rolls.iter().enumerate().find(|(_, roll)| !(1..=6).contains(*roll)) {
+        return Err(KeyError::InvalidDiceRoll { position, roll });
+    }
+    let need = dice_rolls_needed(seed_entropy_bits(seed_type)?);
+    if rolls.len() < need {
+        return Err(KeyError::NotEnoughRolls { got: rolls.len(), need });
+    }
+    // Chi-squared test of the face counts against a fair die
+    let mut counts = [0usize; 6];
+    for &roll in rolls {
+        counts[usize::from(roll - 1)] += 1;
+    }
+    let expected = rolls.len() as f64 / 6.0;
+    let statistic: f64 = counts
+        .iter()
+        .map(|&count| (count as f64 - expected).powi(2) / expected)
+        .sum();
+    if statistic > BIAS_THRESHOLD {
+        return Err(KeyError::BiasedDiceRolls);
+    }
+    // Base-6 number modulo 2^(8 * entropy_len), big endian
+    let mut entropy = vec![0u8; entropy_len];
+    for &roll in rolls {
+        let mut carry = u16::from(roll - 1);
+        for byte in entropy.iter_mut().rev() {
+            let value = u16::from(*byte) * 6 + carry;
+            *byte = value as u8;
+            carry = value >> 8;
+        }
+    }
+    generate_seed_from_entropy(language, seed_type, &entropy)
+}
@@ -0,0 +1,210 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+use std::fmt;
+use std::panic::{catch_unwind, UnwindSafe};
+
+/// KeyError contains all errors that can happen while handling seeds, keys and addresses
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyError {
+    /// The seed does not have a supported number of words
+    WrongWordCount { got: usize, expected: &'static [usize] },
+    /// No supported wordset contains all the words of the seed
+    UnknownWordset,
+    /// The language code is not one of [`Language`](super::Language)
+    UnknownLanguage(String),
+    /// A word of the seed is not in its wordset, position is zero-based
+    InvalidWord { position: usize, word: String },
+    /// The words of the seed are from several wordsets, listed by language code starting with the one of most words
+    MixedLanguages { detected: Vec<&'static str> },
+    /// The checksum word of the seed does not match its other words
+    InvalidSeedChecksum,
+    /// Seeds of the given strength in bits cannot be generated
+    UnsupportedStrength(u16),
+    /// The seed could not be decoded into a hex seed
+    CorruptSeed,
+    /// The seed type is not one of `original`, `mymonero` and `polyseed`
+    UnknownSeedType(String),
+    /// The seed generation option is out of range or not supported by the seed type
+    InvalidSeedOption(&'static str),
+    /// The hex seed has an unsupported length
+    InvalidHexSeed,
+    /// The hex seed is degenerate (all zeros, all ones) and gives insecure keys
+    WeakSeed,
+    /// The given string is not valid hexadecimal
+    InvalidHex,
+    /// The given hexadecimal string does not have the expected number of characters
+    InvalidHexLength { got: usize, expected: usize },
+    /// The network is not supported
+    InvalidNetwork,
+    /// The address could not be decoded
+    InvalidAddress,
+    /// The address is not valid base58, the message tells what is wrong and where
+    Base58(String),
+    /// The checksum of the address does not match its content
+    InvalidChecksum,
+    /// The payment URI is malformed
+    InvalidUri,
+    /// The amount is not a valid XMR amount
+    InvalidAmount,
+    /// The RPC server answered with an error or a malformed reply, see [`KeyError::RpcUnreachable`] for network failures
+    Rpc(String),
+    /// The given string is not valid JSON
+    InvalidJson,
+    /// A required field is missing from the input
+    MissingField(&'static str),
+    /// The address does not belong to the given keys
+    AddressMismatch,
+    /// The public key is not a valid Ed25519 point
+    InvalidPoint,
+    /// The public spend key and the public view key are the same key
+    DuplicateKeys,
+    /// The named key of a key set does not match the one derived from its private spend key
+    InconsistentKeys(&'static str),
+    /// The threshold of a seed split is zero or more than the number of shares
+    InvalidThreshold { threshold: u8, shares: u8 },
+    /// The seed shares are malformed, duplicated or do not come from the same split
+    InvalidShares,
+    /// Fewer seed shares than their threshold were given
+    NotEnoughShares { got: usize, threshold: u8 },
+    /// The wallet secret bytes have the wrong length or hold invalid private keys
+    InvalidSecretBytes,
+    /// The wallet secret bytes were written with an unknown version of their layout
+    UnsupportedVersion(u8),
+    /// The block height anchor would make the chain go back in time
+    InvalidAnchor { height: u64, timestamp: u64 },
+    /// A known-answer test of the self test gave another answer, at the given step
+    SelfTestFailed(&'static str),
+    /// A wordset has a problem with one of its words (or its word count), found by the wordset integrity check
+    CorruptWordset { language: &'static str, word: &'static str, problem: &'static str },
+    /// The entropy given for a seed does not have the number of bytes its seed type needs
+    InsufficientEntropy { got: usize, need: usize },
+    /// An entry of a list of hex keys is not 64 hexadecimal characters, position is zero-based
+    InvalidHexKey { position: usize, entry: String },
+    /// The polyseed seed is encrypted with a passphrase, it must be decrypted first
+    EncryptedPolyseed,
+    /// The polyseed seed is not encrypted, so it cannot be decrypted
+    UnencryptedPolyseed,
+    /// The address decodes to bytes whose base58 encoding is another string
+    NonCanonicalEncoding,
+    /// The scalar is not reduced modulo the order of the Ed25519 group
+    NonCanonicalScalar,
+    /// The address prefix is not one of a Monero network and address type, given is its first byte
+    UnknownPrefix(u8),
+    /// A generated seed did not decode back to its bytes or its checksum word, it was not handed out
+    GenerationFailed,
+    /// The public key is the identity point or outside the prime-order subgroup, e.g. of a private key of zero
+    DegeneratePubKey,
+    /// A function called through [`catch_key_panic`] panicked, with the panic message
+    Panicked(String),
+    /// A die roll is not 1 to 6, position is zero-based
+    InvalidDiceRoll { position: usize, roll: u8 },
+    /// Fewer die rolls than the seed type needs for its entropy were given
+    NotEnoughRolls { got: usize, need: usize },
+    /// The faces of the die rolls are too uneven to come from a fair die
+    BiasedDiceRolls,
+    /// The polyseed seed has reserved feature bits set, polyseed wallets refuse it
+    UnsupportedPolyseedFeatures(u8),
+    /// The RPC server could not be reached (connection refused, DNS, TLS or timeout), the request may be retried
+    RpcUnreachable(String),
+}
+
+impl fmt::Display for KeyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            KeyError::WrongWordCount { got, expected } => write!(f, "Seed has {} words, expected one of {:?}", got, expected),
+            KeyError::UnknownWordset => write!(f, "Wordset could not be found for given seed, please check your seed"),
+            KeyError::UnknownLanguage(code) => write!(f, "Unknown language code: {}", code),
+            KeyError::InvalidWord { position, word } => write!(f, "Invalid word '{}' at position {} in seed, please check your seed", word, position),
+            KeyError::MixedLanguages { detected } => write!(f, "Seed mixes words of several languages ({}), please check your seed", detected.join(", ")),
+            KeyError::InvalidSeedChecksum => write!(f, "Checksum word of the seed is wrong, please check your seed"),
+            KeyError::UnsupportedStrength(bits) => write!(f, "Seeds of {} bits are not supported, use 128 or 256", bits),
+            KeyError::CorruptSeed => write!(f, "Something went wrong when decoding your private key, please try again"),
+            KeyError::UnknownSeedType(seed_type) => write!(f, "Unknown seed type '{}'", seed_type),
+            KeyError::InvalidSeedOption(option) => write!(f, "Invalid seed option '{}' for this seed type", option),
+            KeyError::InvalidHexSeed => write!(f, "Invalid hex seed"),
+            KeyError::WeakSeed => write!(f, "Hex seed is degenerate and gives insecure keys"),
+            KeyError::InvalidHex => write!(f, "Invalid hexadecimal string"),
+            KeyError::InvalidHexLength { got, expected } => write!(f, "Hexadecimal string has {} characters, expected {}", got, expected),
+            KeyError::InvalidNetwork => write!(f, "Invalid network"),
+            KeyError::InvalidAddress => write!(f, "Invalid address"),
+            KeyError::Base58(message) => write!(f, "Invalid base58: {}", message),
+            KeyError::InvalidChecksum => write!(f, "Invalid address checksum"),
+            KeyError::InvalidUri => write!(f, "Invalid payment URI"),
+            KeyError::InvalidAmount => write!(f, "Invalid amount"),
+            KeyError::Rpc(message) => write!(f, "{}", message),
+            KeyError::InvalidJson => write!(f, "Invalid JSON"),
+            KeyError::MissingField(field) => write!(f, "Missing field '{}'", field),
+            KeyError::AddressMismatch => write!(f, "Address does not match the given keys"),
+            KeyError::InvalidPoint => write!(f, "Public key is not a valid point"),
+            KeyError::DuplicateKeys => write!(f, "Public spend key and public view key are the same"),
+            KeyError::InconsistentKeys(key) => write!(f, "Key {} does not match the private spend key", key),
+            KeyError::InvalidThreshold { threshold, shares } => write!(f, "Threshold {} is not possible with {} shares", threshold, shares),
+            KeyError::InvalidShares => write!(f, "Invalid seed shares"),
+            KeyError::NotEnoughShares { got, threshold } => write!(f, "Got {} seed shares, {} are needed", got, threshold),
+            KeyError::InvalidSecretBytes => write!(f, "Invalid wallet secret bytes"),
+            KeyError::UnsupportedVersion(version) => write!(f, "Wallet secret bytes version {} is not supported", version),
+            KeyError::InvalidAnchor { height, timestamp } => write!(f, "Block {} at {} is out of order with the other anchors", height, timestamp),
+            KeyError::SelfTestFailed(step) => write!(f, "Self test failed at step '{}', key derivation is broken on this build", step),
+            KeyError::CorruptWordset { language, word, problem } => write!(f, "Wordset {} is corrupt: {} '{}'", language, problem, word),
+            KeyError::InsufficientEntropy { got, need } => write!(f, "Got {} bytes of entropy, the seed type needs exactly {}", got, need),
+            KeyError::InvalidHexKey { position, entry } => write!(f, "Invalid hex key '{}' at position {}, expected 64 hexadecimal characters", entry, position),
+            KeyError::EncryptedPolyseed => write!(f, "Polyseed seed is encrypted, please decrypt it with its passphrase"),
+            KeyError::UnencryptedPolyseed => write!(f, "Polyseed seed is not encrypted"),
+            KeyError::NonCanonicalEncoding => write!(f, "Address is not in canonical base58 encoding"),
+            KeyError::NonCanonicalScalar => write!(f, "Scalar is not reduced, it must be below the order of the Ed25519 group"),
+            KeyError::UnknownPrefix(byte) => write!(f, "Unknown address prefix {:#04x}", byte),
+            KeyError::GenerationFailed => write!(f, "Generated seed does not round-trip"),
+            KeyError::DegeneratePubKey => write!(f, "Degenerate public key"),
+            KeyError::Panicked(message) => write!(f, "Panicked: {}", message),
+            KeyError::InvalidDiceRoll { position, roll } => write!(f, "Invalid die roll {} at position {}", roll, position),
+            KeyError::NotEnoughRolls { got, need } => write!(f, "Not enough die rolls: got {}, need {}", got, need),
+            KeyError::BiasedDiceRolls => write!(f, "Die rolls look biased"),
+            KeyError::UnsupportedPolyseedFeatures(features) => write!(f, "Unsupported polyseed features {:#07b}", features),
+            KeyError::RpcUnreachable(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for KeyError {}
+
+/// Runs given closure, turning a panic into [`KeyError::Panicked`] with the panic message
+///
+/// > NOTE: This is a temporary stopgap for callers of the functions which still panic on bad input (e.g.
+/// > [`generate_seed`](super::generate_seed) with an unknown seed type) until they return `Result`, it will be removed
+/// > then. Prefer the functions returning `Result` where they exist.
+///
+/// Only unwinding panics are caught, not those of a crate built with `panic = "abort"`. The panic hook still runs, so
+/// the default hook prints the panic to stderr; the closure must be [`UnwindSafe`], wrap it in
+/// [`AssertUnwindSafe`](std::panic::AssertUnwindSafe) if it captures mutable references.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{catch_key_panic, generate_seed, KeyError};
+///
+/// let mnemonic: Vec<String> = catch_key_panic(|| generate_seed("en", "original")).unwrap();
+/// assert_eq!(mnemonic.len(), 25);
+/// assert_eq!(catch_key_panic(|| generate_seed("en", "bip39")), Err(KeyError::Panicked("Invalid seed type".to_string())));
+/// ```
+pub fn catch_key_panic<F, T>(f: F) -> Result<T, KeyError>
+where
+    F: FnOnce() -> T + UnwindSafe,
+{
+    catch_unwind(f).map_err(|payload| {
+        // Panics with a message carry a `&str` or a `String`, depending on whether it was formatted
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => payload
+                .downcast_ref::<&str>()
+                .map_or("unknown panic payload".to_string(), |message| message.to_string()),
+        };
+        KeyError::Panicked(message)
+    })
+}
@@ -0,0 +1,64 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+//! # Import
+//!
+//! This module is for importing keys exported by other wallets.
+
+use super::address::{decode_address, derive_address, AddressType};
+use super::errors::KeyError;
+use super::keys::{derive_pub_key, Keys};
+
+/// Imports keys from the JSON format used by `monero-wallet-cli --generate-from-json`
+///
+/// The `address` and `viewkey` fields are required, `spendkey` is optional. Without it, a view-only wallet is imported
+/// and `priv_spend` of the returned keys is empty. The address must belong to the given keys.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{import_from_wallet_json, Keys};
+///
+/// let json: &str = r#"{
+///     "version": 1,
+///     "address": "4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J",
+///     "viewkey": "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908",
+///     "spendkey": "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08"
+/// }"#;
+/// let keys: Keys = import_from_wallet_json(json).unwrap();
+/// assert_eq!(keys.pub_spend, "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95".to_string());
+/// ```
+pub fn import_from_wallet_json(json: &str) -> Result<Keys, KeyError> {
+    let parsed: serde_json::Value = serde_json::from_str(json).map_err(|_| KeyError::InvalidJson)?;
+    let address = parsed["address"].as_str().ok_or(KeyError::MissingField("address"))?;
+    let priv_view = parsed["viewkey"].as_str().ok_or(KeyError::MissingField("viewkey"))?;
+    let priv_spend = match &parsed["spendkey"] {
+        serde_json::Value::Null => None,
+        value => Some(value.as_str().ok_or(KeyError::MissingField("spendkey"))?),
+    };
+    let decoded = decode_address(address)?;
+    if decoded.address_type != AddressType::Standard {
+        return Err(KeyError::InvalidAddress);
+    }
+    let pub_view = derive_pub_key(priv_view.to_string())?;
+    let pub_spend = match priv_spend {
+        Some(priv_spend) => derive_pub_key(priv_spend.to_string())?,
+        // View-only wallets only know the public spend key from the address
+        None => decoded.public_spend_key.clone(),
+    };
+    if derive_address(pub_spend.clone(), pub_view.clone(), decoded.network as u8)? != address {
+        return Err(KeyError::AddressMismatch);
+    }
+    Ok(Keys {
+        priv_spend: priv_spend.unwrap_or("").to_string(),
+        pub_spend,
+        priv_view: priv_view.to_string(),
+        pub_view,
+    })
+}
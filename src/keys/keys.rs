@@ -12,217 +12,93 @@
 //!
 //! This module is for everything related to keys, such as generating seeds, deriving keys from seeds, deriving public keys from private keys, and deriving addresses from public keys etc.
 
-use crate::crypt::ed25519::sc_reduce32;
+use super::errors::KeyError;
+use super::polyseed::{now_timestamp, polyseed_checksum_valid, polyseed_find_word, polyseed_from_secret, polyseed_word_error, POLYSEED_WORD_COUNT};
+use super::scheme::seed_scheme;
+use crate::crypt::cryptonight::cn_slow_hash_v0;
+use crate::crypt::{cn_fast_hash, hash_to_scalar, needed_reduction, reduce_scalar};
 use crate::mnemonics::original::wordsets::{WordsetOriginal, WORDSETSORIGINAL};
+use crate::mnemonics::polyseed::wordsets::WORDSETSPOLYSEED;
 use crc32fast::Hasher;
-use curve25519_dalek::{constants::ED25519_BASEPOINT_TABLE, EdwardsPoint, Scalar};
-use rand::Rng;
-use sha3::{Digest, Keccak256};
+use curve25519_dalek::{constants::ED25519_BASEPOINT_TABLE, traits::IsIdentity, EdwardsPoint, Scalar};
+use rand::RngCore;
+use unicode_normalization::UnicodeNormalization;
 use core::panic;
+use std::collections::HashSet;
+use std::fmt;
 use std::ops::Mul;
 use std::vec;
 
-/// Returns cryptographically secure random element of the given array
-fn secure_random_element<'x>(array: &'x [&'x str]) -> &'x str {
-    let mut rng = rand::thread_rng();
-    let random_index = rng.gen_range(0..array.len());
-    array[random_index]
+/// Runs the given closure with the random number generator used for generating seeds
+#[cfg(not(feature = "deterministic-rng"))]
+pub(crate) fn with_rng<T>(f: impl FnOnce(&mut dyn RngCore) -> T) -> T {
+    f(&mut rand::thread_rng())
 }
 
-// Returns cryptographically secure random bits of given length
-fn get_random_bits(length: u64) -> Vec<bool> {
-    let mut rng = rand::thread_rng();
-    let mut bit_array = Vec::new();
-    for _ in 0..length {
-        bit_array.push(rng.gen_bool(0.5));
+/// Runs the given closure with a fixed-seed random number generator, so generated seeds are the same on every run
+///
+/// WARNING: This is for tests only, seeds generated with the `deterministic-rng` feature are NOT secure
+#[cfg(feature = "deterministic-rng")]
+pub(crate) fn with_rng<T>(f: impl FnOnce(&mut dyn RngCore) -> T) -> T {
+    use rand::{rngs::StdRng, SeedableRng};
+    use std::cell::RefCell;
+    thread_local! {
+        static RNG: RefCell<StdRng> = RefCell::new(StdRng::seed_from_u64(0));
+    }
+    RNG.with(|rng| f(&mut *rng.borrow_mut()))
+}
+
+/// Returns the first `prefix_length` characters (not bytes) of given word, or the whole word if it is shorter
+pub(crate) fn word_prefix(word: &str, prefix_length: usize) -> &str {
+    match word.char_indices().nth(prefix_length) {
+        Some((end, _)) => &word[..end],
+        None => word,
     }
-    bit_array
 }
 
 /// Calculates CRC32 checksum index for given array (probably the seed)
 fn get_checksum_index(array: &[&str], prefix_length: usize) -> usize {
     let mut trimmed_words: String = String::new();
     for word in array {
-        trimmed_words.push_str(&word[0..prefix_length]);
+        trimmed_words.push_str(word_prefix(word, prefix_length));
     }
     let mut hasher = Hasher::new();
     hasher.update(trimmed_words.as_bytes());
     usize::try_from(hasher.finalize()).unwrap() % array.len()
 }
 
-/// Generates a cryptographically secure 1626-type (25-word) seed for given language
-fn generate_original_seed(language: &str) -> Vec<&str> {
-    // Check if language is supported
-    if !WORDSETSORIGINAL.iter().any(|x| x.name == language) {
-        panic!("Language not found");
-    }
-    // Generate seed
-    let mut seed: Vec<&str> = Vec::new();
-    let mut prefix_len: usize = 3;
-    for wordset in WORDSETSORIGINAL.iter() {
-        if wordset.name == language {
-            prefix_len = wordset.prefix_len;
-            for _ in 0..24 {
-                let word = secure_random_element(&wordset.words[..]);
-                seed.push(word);
-            }
-            break;
-        } else {
-            continue;
-        }
-    }
-    // Add checksum word
-    let checksum_index = get_checksum_index(&seed, prefix_len);
-    seed.push(seed[checksum_index]);
-    // Finally, return the seed
-    seed
-}
-
-/// Generates a cryptographically secure 1626-type (13-word) seed for given language
-fn generate_mymonero_seed(language: &str) -> Vec<&str> {
-    // Check if language is supported
-    if !WORDSETSORIGINAL.iter().any(|x| x.name == language) {
-        panic!("Language not found");
-    }
-    // Generate seed
-    let mut seed: Vec<&str> = Vec::new();
-    let mut prefix_len: usize = 3;
-    for wordset in WORDSETSORIGINAL.iter() {
-        if wordset.name == language {
-            prefix_len = wordset.prefix_len;
-            for _ in 0..12 {
-                let word = secure_random_element(&wordset.words[..]);
-                seed.push(word);
-            }
-            break;
-        } else {
-            continue;
-        }
-    }
-    // Add checksum word
-    let checksum_index = get_checksum_index(&seed, prefix_len);
-    seed.push(seed[checksum_index]);
-    // Finally, return the seed
-    seed
-}
-
-fn print_seed_pretty(seed: Vec<Vec<bool>>) {
-    for word in seed.iter() {
-        for bit in word.iter() {
-            print!("{}", if *bit { "1" } else { "0" });
-        }
-        println!();
-    }
+/// Generates a cryptographically secure 1626-type seed of given language with given random number generator
+///
+/// `word_count` does not include the checksum word, 24 for original seeds and 12 for MyMonero seeds.
+/// The seed is decoded back before it is returned, a seed which does not round-trip gives [`KeyError::GenerationFailed`].
+pub(crate) fn generate_1626_seed(language: &str, word_count: usize, rng: &mut dyn RngCore) -> Result<Vec<String>, KeyError> {
+    let words = generate_1626_words(language, word_count, rng)?;
+    Ok(words.into_iter().map(|word| word.to_string()).collect())
 }
 
-static POLYSEED_MUL2_TABLE: [u16; 8] = [5, 7, 1, 3, 13, 15, 9, 11];
-
-fn gf_elem_mul2(x: u16) -> u16 {
-    if x < 1024 {
-        return 2 * x;
-    }
-    POLYSEED_MUL2_TABLE[x as usize % 8] + 16 * ((x - 1024) / 8)
+/// Generates a seed like [`generate_1626_seed`], giving references into the wordset instead of owned words
+pub(crate) fn generate_1626_words(language: &str, word_count: usize, rng: &mut dyn RngCore) -> Result<Vec<&'static str>, KeyError> {
+    let wordset = wordset_by_name(language)?;
+    // Random bytes are encoded instead of random words picked, as not every three words decode to 4 bytes
+    let mut seed = vec![0u8; word_count / 3 * 4];
+    rng.fill_bytes(&mut seed);
+    let words = encode_hex_to_words(&seed, wordset);
+    check_round_trip(&words, &seed, wordset)?;
+    Ok(words)
 }
 
-fn gf_poly_eval(coeff: &[u16; 16]) -> u16 {
-    // Horner's method at x = 2
-    let mut result = coeff[15];
-    for i in (0..15).rev() {
-        result = gf_elem_mul2(result) ^ coeff[i];
+/// Checks a freshly generated seed decodes back to the bytes it was generated from and ends with its checksum word,
+/// else gives [`KeyError::GenerationFailed`] so no seed which cannot be restored is handed out
+fn check_round_trip(words: &[&str], seed: &[u8], wordset: &WordsetOriginal) -> Result<(), KeyError> {
+    let mnemonic_seed: Vec<String> = words.iter().map(|word| word.to_string()).collect();
+    let decodes = derive_hex_seed(mnemonic_seed).is_ok_and(|hex_seed| hex_seed == hex::encode(seed));
+    let (checksum_word, words) = words.split_last().ok_or(KeyError::GenerationFailed)?;
+    if !decodes || words[get_checksum_index(words, wordset.prefix_len)] != *checksum_word {
+        return Err(KeyError::GenerationFailed);
     }
-    result
+    Ok(())
 }
 
-/*
-/// Generates a cryptographically secure 2048-type (16-word) seed for given language
-fn generate_polyseed_seed(language: &str) -> Vec<&str> {
-    // Encoding
-
-    // Each word contains 11 bits of information. The data are encoded as follows:
-    // word # 	contents
-    // 1 	checksum (11 bits)
-    // 2-6 	secret seed (10 bits) + features (1 bit)
-    // 7-16 	secret seed (10 bits) + birthday (1 bit)
-
-    // In total, there are 11 bits for the checksum, 150 bits for the secret seed, 5 feature bits and 10 birthday bits. Because the feature and birthday bits are non-random, they are spread over the 15 data words so that two different mnemonic phrases are unlikely to have the same word in the same position.
-    // Checksum
-    // The mnemonic phrase can be treated as a polynomial over GF(2048), which enables the use of an efficient Reed-Solomon error correction code with one check word. All single-word errors can be detected and all single-word erasures can be corrected without false positives.
-    
-    // Check if language is supported
-    if !WORDSETSPOLYSEED.iter().any(|x| x.name == language) {
-        panic!("Language not found");
-    }
-    // Get birthday
-    const POLYSEEDEPOCH: u64 = 1635768000; // The epoch for Polyseed birthdays. 1st November 2021 12:00 UTC
-    const TIMESTEP: u64 = 2629746; // The time step for Polyseed. 1/12 of the Gregorian year
-    let birthday: u16 = ((SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap()
-        .as_secs()
-        - POLYSEEDEPOCH)
-        / TIMESTEP)
-        .try_into()
-        .unwrap(); // The birthday of the seed from how much approximate months have passed since the epoch
-    let mut birthday_bits: Vec<bool> = birthday
-        .to_be_bytes()
-        .to_vec()
-        .iter()
-        .flat_map(|&byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
-        .collect();
-    birthday_bits.drain(..6);
-    let seed_bits = get_random_bits(150); // Get 150 random bits
-    let features_bits = [false; 5]; // We don't use any feature while generating the seed
-    let mut words_bits: Vec<Vec<bool>> = Vec::with_capacity(15); // 16 minus 1 checksum word
-    // Add secret seed and features bits
-    for (index, item) in features_bits.iter().enumerate() {
-        let mut word: Vec<bool> = Vec::with_capacity(11);
-        let sss = index * 10;
-        let sse = (index + 1) * 10;
-        let ssi = seed_bits[sss..sse].to_vec();
-        for bit in ssi {
-            word.push(bit);
-        }
-        word.push(*item);
-        words_bits.push(word);
-    }
-    // Add rest of the seed and birthday bits
-    for i in 5..15 {
-        let mut word: Vec<bool> = Vec::with_capacity(11);
-        let sss = i * 10;
-        let sse = (i + 1) * 10;
-        let ssi = seed_bits[sss..sse].to_vec();
-        for bit in ssi {
-            word.push(bit);
-        }
-        word.push(birthday_bits[i - 5]);
-        words_bits.push(word);
-    }
-    // Choose words based on each bits, corresponding to 0-2047
-    let mut words_indexes: [u16; 16] = [0; 16];
-    for (index, word_bits) in words_bits.iter().enumerate() {
-        let mut word_index: u16 = 0;
-        for (i, bit) in word_bits.iter().enumerate() {
-            if *bit {
-                word_index += 2u16.pow((10 - i) as u32);
-            }
-        }
-        words_indexes[index] = word_index;
-    }
-    print_seed_pretty(words_bits);
-    // Calculate checksum based on comment describing
-    let checksum = gf_poly_eval(&words_indexes);
-    // Add checksum word
-    let mut seed: Vec<&str> = Vec::new();
-    seed.push(WORDSETSPOLYSEED[0].words[checksum as usize]);
-    // Add rest of the words
-    for index in 0..15 {
-        seed.push(WORDSETSPOLYSEED[0].words[words_indexes[index] as usize]);
-    }
-    // Finally, return the seed
-    seed
-}
-*/
-
 /// Generates a cryptographically secure mnemonic phrase for given language and seed type
 ///
 /// Available seed types:
@@ -231,14 +107,31 @@ fn generate_polyseed_seed(language: &str) -> Vec<&str> {
 ///     - `eo` (Esperanto)
 ///     - `fr` (French)
 ///     - `it` (Italian)
-///     - `jp` (Japanese) (Works but not recommended)
+///     - `ja` (Japanese) (Works but not recommended)
 ///     - `lj` (Lojban)
 ///     - `pt` (Portuguese)
 ///     - `ru` (Russian)
 /// - `mymonero` : (13-word, MyMonero wallet type)
-///     - `en`, `eo`, `fr`, `it`, `jp`, `lj`, `pt`, `ru` (same as original)
-/// - `polyseed` : (TO BE IMPLEMENTED)
-/// > DISCLAIMER: polyseed is not implemented yet
+///     - `en`, `eo`, `fr`, `it`, `ja`, `lj`, `pt`, `ru` (same as original)
+/// - `polyseed` : (16-word, born now and without features)
+///     - `cs` (Czech)
+///     - `en` (English)
+///     - `es` (Spanish)
+///     - `fr` (French)
+///     - `it` (Italian)
+///     - `ja` (Japanese)
+///     - `ko` (Korean)
+///     - `pt` (Portuguese)
+///     - `zh_s` (Chinese, simplified)
+///     - `zh_t` (Chinese, traditional)
+///
+/// The codes are those of [`Language`](super::Language), see [`generate_seed_in`](super::generate_seed_in) for
+/// generating from it.
+///
+/// Original and MyMonero seeds are checked to decode back to the generated bytes and checksum word before they are
+/// returned, this panics if one does not rather than giving out a seed which cannot be restored.
+///
+/// > WARNING: With the `deterministic-rng` feature, which is for tests only, generated seeds are the same on every run and NOT secure
 ///
 /// Example:
 /// ```
@@ -249,17 +142,175 @@ fn generate_polyseed_seed(language: &str) -> Vec<&str> {
 /// assert_ne!(mnemonic, vec!["tissue", "raking", "haunted", "huts", "afraid", "volcano", "howls", "liar", "egotistic", "befit", "rounded", "older", "bluntly", "imbalance", "pivot", "exotic", "tuxedo", "amaze", "mostly", "lukewarm", "macro", "vocal", "hounded", "biplane", "rounded"].iter().map(|&s| s.to_string()).collect::<Vec<String>>());
 /// ```
 pub fn generate_seed(language: &str, seed_type: &str) -> Vec<String> {
-    let seed = match seed_type {
-        "original" => generate_original_seed(language),
-        "mymonero" => generate_mymonero_seed(language),
-        "polyseed" => panic!("Polyseed is not implemented yet"),
-        _ => panic!("Invalid seed type"),
+    let scheme = seed_scheme(seed_type).unwrap_or_else(|_| panic!("Invalid seed type"));
+    with_rng(|rng| scheme.generate(language, rng)).expect("Seed generation failed")
+}
+
+/// Generates a cryptographically secure mnemonic phrase like [`generate_seed`], streaming its words instead of
+/// collecting them into a `Vec<String>`
+///
+/// The words, the checksum word included, are references into the wordset. Polyseed words are given as stored in the
+/// wordset, decomposed (NFKD): for the languages with accents or Hangul they are equal to the words of
+/// [`generate_seed`] after normalization, not byte for byte. An unknown seed type gives [`KeyError::UnknownSeedType`]
+/// and a language without a wordset for it [`KeyError::UnknownWordset`].
+///
+/// Example:
+/// ```
+/// use libmonero::keys::generate_seed_iter;
+///
+/// let mut phrase = String::new();
+/// for word in generate_seed_iter("en", "original").unwrap() {
+///     phrase.push_str(word);
+///     phrase.push(' ');
+/// }
+/// assert_eq!(phrase.split_whitespace().count(), 25);
+/// ```
+pub fn generate_seed_iter(language: &str, seed_type: &str) -> Result<impl Iterator<Item = &'static str>, KeyError> {
+    let scheme = seed_scheme(seed_type)?;
+    let words = with_rng(|rng| scheme.generate_words(language, rng))?;
+    Ok(words.into_iter())
+}
+
+/// Encodes given entropy, e.g. bytes of a hardware random number generator, into a mnemonic phrase of given language and
+/// seed type
+///
+/// The entropy must be exactly as long as the seed type needs, see [`seed_entropy_len`]: 32 bytes for `original`, 16
+/// for `mymonero` and 19 for `polyseed`, of which only the low 6 bits of the last byte are used. Any other length gives
+/// [`KeyError::InsufficientEntropy`], nothing is padded or cut. Polyseed seeds are born now and without features.
+///
+/// > WARNING: The seed is only as secure as the entropy, which must come from a cryptographically secure source
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{generate_seed_from_entropy, KeyError};
+///
+/// let entropy: Vec<u8> = hex::decode("f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8").unwrap();
+/// let mnemonic: Vec<String> = generate_seed_from_entropy("en", "original", &entropy).unwrap();
+/// assert_eq!(mnemonic.join(" "), "tissue raking haunted huts afraid volcano howls liar egotistic befit rounded older bluntly imbalance pivot exotic tuxedo amaze mostly lukewarm macro vocal hounded biplane rounded");
+/// assert_eq!(generate_seed_from_entropy("en", "original", &entropy[..16]), Err(KeyError::InsufficientEntropy { got: 16, need: 32 }));
+/// ```
+pub fn generate_seed_from_entropy(language: &str, seed_type: &str, entropy: &[u8]) -> Result<Vec<String>, KeyError> {
+    let need = seed_entropy_len(seed_type)?;
+    if entropy.len() != need {
+        return Err(KeyError::InsufficientEntropy { got: entropy.len(), need });
+    }
+    if seed_type == "polyseed" {
+        let mut secret = [0u8; 19];
+        secret.copy_from_slice(entropy);
+        return polyseed_from_secret(language, POLYSEED_WORD_COUNT, now_timestamp(), 0, secret);
+    }
+    let wordset = wordset_by_name(language)?;
+    Ok(encode_hex_to_mnemonic(entropy, wordset))
+}
+
+/// Generates a cryptographically secure mnemonic phrase of the given strength for given language
+///
+/// Strengths:
+/// - `128` : 13-word seed, the same as the `mymonero` seed type
+/// - `256` : 25-word seed, the same as the `original` seed type
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{generate_seed_with_strength, KeyError};
+///
+/// let mnemonic: Vec<String> = generate_seed_with_strength("en", 128).unwrap();
+/// assert_eq!(mnemonic.len(), 13);
+/// assert_eq!(generate_seed_with_strength("en", 192), Err(KeyError::UnsupportedStrength(192)));
+/// ```
+pub fn generate_seed_with_strength(language: &str, bits: u16) -> Result<Vec<String>, KeyError> {
+    wordset_by_name(language)?;
+    match bits {
+        128 => Ok(generate_seed(language, "mymonero")),
+        256 => Ok(generate_seed(language, "original")),
+        _ => Err(KeyError::UnsupportedStrength(bits)),
+    }
+}
+
+/// Returns how many bytes of entropy the given seed type is generated from
+///
+/// - `original` : 32 bytes
+/// - `mymonero` : 16 bytes
+/// - `polyseed` : 19 bytes (only the low 6 bits of the last byte are used)
+///
+/// Any other seed type gives [`KeyError::UnknownSeedType`].
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{seed_entropy_len, KeyError};
+///
+/// assert_eq!(seed_entropy_len("original"), Ok(32));
+/// assert_eq!(seed_entropy_len("polyseed"), Ok(19));
+/// assert_eq!(seed_entropy_len("bip39"), Err(KeyError::UnknownSeedType("bip39".to_string())));
+/// ```
+pub fn seed_entropy_len(seed_type: &str) -> Result<usize, KeyError> {
+    match seed_type {
+        "original" => Ok(32),
+        "mymonero" => Ok(16),
+        "polyseed" => Ok(19),
+        _ => Err(KeyError::UnknownSeedType(seed_type.to_string())),
+    }
+}
+
+/// Returns how many bits of entropy the given seed type carries
+///
+/// - `original` : 256 bits
+/// - `mymonero` : 128 bits
+/// - `polyseed` : 150 bits (secret part only, birthday and features are not random)
+///
+/// Any other seed type gives [`KeyError::UnknownSeedType`].
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{seed_entropy_bits, KeyError};
+///
+/// assert_eq!(seed_entropy_bits("original"), Ok(256));
+/// assert_eq!(seed_entropy_bits("mymonero"), Ok(128));
+/// assert_eq!(seed_entropy_bits("bip39"), Err(KeyError::UnknownSeedType("bip39".to_string())));
+/// ```
+pub fn seed_entropy_bits(seed_type: &str) -> Result<u32, KeyError> {
+    match seed_type {
+        "polyseed" => Ok(150),
+        _ => Ok(seed_entropy_len(seed_type)? as u32 * 8),
+    }
+}
+
+/// Checks if the given hex seed looks like it was not randomly generated, returns true if it is weak
+///
+/// A seed is flagged if it is not valid hex, if its bytes form an arithmetic sequence (all zeros, all same, counting),
+/// if it repeats a pattern of up to 4 bytes or if it has too few distinct bytes. A random seed hits any of these with a
+/// probability below 2^-64, so legitimately random seeds are not flagged.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::weak_seed_check;
+///
+/// assert!(weak_seed_check("0000000000000000000000000000000000000000000000000000000000000000"));
+/// assert!(weak_seed_check("deadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeefdeadbeef"));
+/// assert!(!weak_seed_check("f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8"));
+/// ```
+pub fn weak_seed_check(hex_seed: &str) -> bool {
+    let bytes = match hex::decode(hex_seed) {
+        Ok(bytes) if !bytes.is_empty() => bytes,
+        _ => return true,
     };
-    let mut seed_string: Vec<String> = Vec::new();
-    for word in seed {
-        seed_string.push(word.to_string());
+    // All same bytes or a counting sequence
+    let step = bytes.get(1).unwrap_or(&bytes[0]).wrapping_sub(bytes[0]);
+    if bytes.windows(2).all(|pair| pair[1].wrapping_sub(pair[0]) == step) {
+        return true;
     }
-    seed_string
+    // Short repeating patterns
+    for period in 1..=4 {
+        if bytes.len() > period && bytes.iter().skip(period).zip(bytes.iter()).all(|(a, b)| a == b) {
+            return true;
+        }
+    }
+    // Too few distinct bytes, a random seed has about as many distinct bytes as its length
+    let mut seen = [false; 256];
+    for byte in bytes.iter() {
+        seen[*byte as usize] = true;
+    }
+    let distinct = seen.iter().filter(|&&x| x).count();
+    distinct < bytes.len() / 4
 }
 
 /// Swaps endianness of a 4-byte string
@@ -267,107 +318,612 @@ fn swap_endian_4_byte(s: &str) -> String {
     format!("{}{}{}{}", &s[6..8], &s[4..6], &s[2..4], &s[0..2])
 }
 
+/// Finds the original (1626-word) wordset of the given language, [`KeyError::UnknownWordset`] if there is none
+pub(crate) fn wordset_by_name(language: &str) -> Result<&'static WordsetOriginal, KeyError> {
+    WORDSETSORIGINAL
+        .iter()
+        .find(|wordset| wordset.name == language)
+        .ok_or(KeyError::UnknownWordset)
+}
+
+/// Replaces the given word, if it is not ASCII, with the wordset word it is equal to after NFKD normalization
+///
+/// Wordsets are stored in NFC, but pasted seeds may come decomposed or with full-width characters (e.g. Japanese).
+pub(crate) fn normalize_seed_word(word: &str) -> String {
+    if word.is_ascii() {
+        return word.to_string();
+    }
+    let normalized: String = word.nfkd().collect();
+    WORDSETSORIGINAL
+        .iter()
+        .flat_map(|wordset| wordset.words.iter())
+        .find(|known| known.nfkd().eq(normalized.chars()))
+        .map_or_else(|| word.to_string(), |known| known.to_string())
+}
+
+/// Replaces the words of the given seed with their normalized form, see [`normalize_seed_word`]
+pub(crate) fn normalize_seed_words(mnemonic_seed: Vec<String>) -> Vec<String> {
+    mnemonic_seed
+        .iter()
+        .map(|word| normalize_seed_word(word))
+        .collect()
+}
+
+/// Finds the wordset of the given seed, the one containing the most of its words, and checks all words are in it
+pub(crate) fn find_wordset(mnemonic_seed: &[String]) -> Result<&'static WordsetOriginal, KeyError> {
+    let mut the_wordset: Option<&'static WordsetOriginal> = None;
+    let mut most_matches: usize = 0;
+    for wordset in WORDSETSORIGINAL.iter() {
+        let matches = mnemonic_seed
+            .iter()
+            .filter(|elem| wordset.words.contains(&elem.as_str()))
+            .count();
+        if matches > most_matches {
+            the_wordset = Some(wordset);
+            most_matches = matches;
+        }
+    }
+    let the_wordset = the_wordset.ok_or(KeyError::UnknownWordset)?;
+    if mnemonic_seed
+        .iter()
+        .any(|elem| !the_wordset.words.contains(&elem.as_str()))
+    {
+        return Err(missing_word_error(mnemonic_seed, the_wordset));
+    }
+    Ok(the_wordset)
+}
+
+/// Gives the error of a seed with words that are not in its wordset (the one containing the most of its words)
+///
+/// If all those words are in other wordsets, the seed mixes languages and [`KeyError::MixedLanguages`] lists the
+/// wordsets the words came from, the seed's one first. Otherwise the first word that is in no wordset is reported as
+/// [`KeyError::InvalidWord`].
+pub(crate) fn missing_word_error(mnemonic_seed: &[String], the_wordset: &'static WordsetOriginal) -> KeyError {
+    let mut detected: Vec<&'static str> = vec![the_wordset.name];
+    for (position, elem) in mnemonic_seed.iter().enumerate() {
+        if the_wordset.words.contains(&elem.as_str()) {
+            continue;
+        }
+        match WORDSETSORIGINAL
+            .iter()
+            .find(|wordset| wordset.words.contains(&elem.as_str()))
+        {
+            Some(wordset) if !detected.contains(&wordset.name) => detected.push(wordset.name),
+            Some(_) => {}
+            None => {
+                return KeyError::InvalidWord {
+                    position,
+                    word: elem.clone(),
+                }
+            }
+        }
+    }
+    KeyError::MixedLanguages { detected }
+}
+
+/// WordsetInfo contains the metadata of a wordset, useful for validating seed words as they are typed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WordsetInfo {
+    /// Name (language code) of the wordset
+    pub name: &'static str,
+    /// Number of leading characters that identify a word, 0 if words must match fully
+    pub prefix_len: usize,
+    /// Number of words in the wordset, see [`SEED_WORD_COUNTS`] for the number of words in a seed
+    pub word_count: usize,
+}
+
+/// Returns the metadata of the original (1626-word) wordset of the given language, if it is supported
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{wordset_info, WordsetInfo};
+///
+/// let info: WordsetInfo = wordset_info("en").unwrap();
+/// assert_eq!(info.prefix_len, 3);
+/// assert_eq!(info.word_count, 1626);
+/// assert!(wordset_info("xx").is_none());
+/// ```
+pub fn wordset_info(language: &str) -> Option<WordsetInfo> {
+    wordset_by_name(language)
+        .ok()
+        .map(|wordset| WordsetInfo {
+            name: wordset.name,
+            prefix_len: wordset.prefix_len,
+            word_count: wordset.words.len(),
+        })
+}
+
+/// Number of words of an original wordset, 3 words encode 4 bytes as 1626^3 > 2^32
+const ORIGINAL_WORDSET_SIZE: usize = 1626;
+
+/// Checks the data of every original (1626-word) wordset: 1626 non-empty words, no word twice and no two words with the
+/// same prefix
+///
+/// Words are decoded by their prefix, so two words sharing one would make seeds decode to wrong bytes without any
+/// error. The first problem found is given as [`KeyError::CorruptWordset`].
+///
+/// Example:
+/// ```
+/// use libmonero::keys::verify_wordset_integrity;
+///
+/// assert_eq!(verify_wordset_integrity(), Ok(()));
+/// ```
+pub fn verify_wordset_integrity() -> Result<(), KeyError> {
+    for wordset in WORDSETSORIGINAL.iter() {
+        let corrupt = |word: &'static str, problem: &'static str| KeyError::CorruptWordset {
+            language: wordset.name,
+            word,
+            problem,
+        };
+        if wordset.words.len() != ORIGINAL_WORDSET_SIZE {
+            return Err(corrupt("", "wrong word count"));
+        }
+        let mut words: HashSet<&str> = HashSet::with_capacity(ORIGINAL_WORDSET_SIZE);
+        let mut prefixes: HashSet<&str> = HashSet::with_capacity(ORIGINAL_WORDSET_SIZE);
+        for &word in wordset.words.iter() {
+            if word.is_empty() {
+                return Err(corrupt(word, "empty word"));
+            }
+            if !words.insert(word) {
+                return Err(corrupt(word, "duplicate word"));
+            }
+            if !prefixes.insert(word_prefix(word, wordset.prefix_len)) {
+                return Err(corrupt(word, "duplicate prefix"));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks if given word is a word of the original (1626-word) wordset of the given language
+///
+/// Unknown languages have no valid words.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::is_valid_word;
+///
+/// assert!(is_valid_word("tissue", "en"));
+/// assert!(!is_valid_word("tissues", "en"));
+/// assert!(!is_valid_word("tissue", "fr"));
+/// ```
+pub fn is_valid_word(word: &str, language: &str) -> bool {
+    let word = normalize_seed_word(word);
+    wordset_by_name(language).is_ok_and(|wordset| wordset.words.contains(&word.as_str()))
+}
+
+/// Checks if given word would be accepted by [`derive_hex_seed`] for the wordset of the given language
+///
+/// Only the first characters of a word identify it (3 for English, 4 for most other languages), so a word whose prefix
+/// is right is accepted even if the rest is mistyped. Wordsets without a prefix length need the full word.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::word_matches_prefix;
+///
+/// assert!(word_matches_prefix("tissue", "en"));
+/// assert!(word_matches_prefix("tisuse", "en"));
+/// assert!(!word_matches_prefix("tsisue", "en"));
+/// ```
+pub fn word_matches_prefix(word: &str, language: &str) -> bool {
+    let word = normalize_seed_word(word);
+    wordset_by_name(language).is_ok_and(|wordset| {
+        let prefix = word_prefix(&word, wordset.prefix_len);
+        match wordset.prefix_len {
+            0 => wordset.words.contains(&prefix),
+            prefix_len => wordset.words.iter().any(|known| word_prefix(known, prefix_len) == prefix),
+        }
+    })
+}
+
+/// Returns the words of the original (1626-word) wordset of the given language starting with given partial word
+///
+/// The whole words are matched, also past the characters that identify them, so typing more narrows the candidates
+/// down further. Words are compared after NFKD normalization, unknown languages have no words.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::complete_word;
+///
+/// assert_eq!(complete_word("tis", "en"), vec!["tissue".to_string()]);
+/// assert_eq!(complete_word("hau", "en"), vec!["haunted".to_string()]);
+/// assert_eq!(complete_word("tiss", "fr"), vec!["tissu".to_string()]);
+/// ```
+pub fn complete_word(partial: &str, language: &str) -> Vec<String> {
+    let partial: String = partial.nfkd().collect();
+    wordset_by_name(language)
+        .ok()
+        .map_or_else(Vec::new, |wordset| {
+            wordset
+                .words
+                .iter()
+                .filter(|known| known.nfkd().collect::<String>().starts_with(&partial))
+                .map(|known| known.to_string())
+                .collect()
+        })
+}
+
+/// Word counts of the seeds [`derive_hex_seed`] can decode, with and without their checksum word
+///
+/// Polyseed (16-word) seeds are not decoded by it.
+pub const SEED_WORD_COUNTS: [usize; 4] = [12, 13, 24, 25];
+
 /// Derives hexadecimal seed from the given mnemonic seed
 ///
+/// Accepts original (25-word) and MyMonero (13-word) seeds, also without their checksum word (24 and 12 words)
+///
+/// Non-ASCII words are matched after NFKD normalization, so decomposed or full-width input is accepted. Every three
+/// words must decode to 4 bytes, otherwise [`KeyError::CorruptSeed`] is returned like Monero's wallet rejects them.
+/// A seed with words of several languages gives [`KeyError::MixedLanguages`].
+///
 /// Example:
 /// ```
 /// use libmonero::keys::derive_hex_seed;
 ///
 /// let mnemonic: Vec<String> = vec!["tissue", "raking", "haunted", "huts", "afraid", "volcano", "howls", "liar", "egotistic", "befit", "rounded", "older", "bluntly", "imbalance", "pivot", "exotic", "tuxedo", "amaze", "mostly", "lukewarm", "macro", "vocal", "hounded", "biplane", "rounded"].iter().map(|s| s.to_string()).collect();
-/// let hex_seed: String = derive_hex_seed(mnemonic);
+/// let hex_seed: String = derive_hex_seed(mnemonic).unwrap();
 /// assert_eq!(hex_seed, "f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8".to_string());
 /// ```
-pub fn derive_hex_seed(mut mnemonic_seed: Vec<String>) -> String {
-    // Find the wordset for the given seed
-    let mut the_wordset = &WordsetOriginal {
-        name: "x",
-        prefix_len: 0,
-        words: [""; 1626],
-    };
-    for wordset in WORDSETSORIGINAL.iter() {
-        if mnemonic_seed
-            .iter()
-            .all(|elem| wordset.words.contains(&elem.as_str()))
-        {
-            the_wordset = wordset;
-            break;
-        }
-    }
-    if the_wordset.name == "x" {
-        panic!("Wordset could not be found for given seed, please check your seed");
+pub fn derive_hex_seed(mnemonic_seed: Vec<String>) -> Result<String, KeyError> {
+    // Check the word count before anything else
+    if !SEED_WORD_COUNTS.contains(&mnemonic_seed.len()) {
+        return Err(KeyError::WrongWordCount {
+            got: mnemonic_seed.len(),
+            expected: &SEED_WORD_COUNTS,
+        });
     }
 
-    // Remove checksum word
-    if the_wordset.prefix_len > 0 {
+    let mut mnemonic_seed = normalize_seed_words(mnemonic_seed);
+    let the_wordset = find_wordset(&mnemonic_seed)?;
+    debug!("found {} wordset for {}-word seed", the_wordset.name, mnemonic_seed.len());
+
+    // Remove checksum word, if there is one
+    if the_wordset.prefix_len > 0 && mnemonic_seed.len() % 3 == 1 {
         mnemonic_seed.pop();
     }
 
     // Get a vector of truncated words
     let mut trunc_words: Vec<&str> = Vec::new();
     for word in the_wordset.words.iter() {
-        trunc_words.push(&word[..the_wordset.prefix_len]);
+        trunc_words.push(word_prefix(word, the_wordset.prefix_len));
     }
     if trunc_words.is_empty() {
-        panic!("Something went wrong when decoding your private key, please try again");
+        return Err(KeyError::CorruptSeed);
     }
 
-    // Derive hex seed
-    let mut hex_seed = String::new();
-    let wordset_len: usize = the_wordset.words.len();
-    for i in (0..mnemonic_seed.len()).step_by(3) {
-        let (w1, w2, w3): (usize, usize, usize);
+    words_to_hex_seed(&mnemonic_seed, the_wordset, |word| {
         if the_wordset.prefix_len == 0 {
-            w1 = the_wordset
-                .words
-                .iter()
-                .position(|&x| x == mnemonic_seed[i])
-                .unwrap_or_else(|| panic!("Invalid word in seed, please check your seed"));
-            w2 = the_wordset
-                .words
-                .iter()
-                .position(|&x| x == mnemonic_seed[i + 1])
-                .unwrap_or_else(|| panic!("Invalid word in seed, please check your seed"));
-            w3 = the_wordset
-                .words
-                .iter()
-                .position(|&x| x == mnemonic_seed[i + 2])
-                .unwrap_or_else(|| panic!("Invalid word in seed, please check your seed"));
+            the_wordset.words.iter().position(|&x| x == word)
         } else {
-            w1 = trunc_words
-                .iter()
-                .position(|&x| x.starts_with(&mnemonic_seed[i][..the_wordset.prefix_len]))
-                .unwrap_or_else(|| panic!("Invalid word in seed, please check your seed"));
-            w2 = trunc_words
+            trunc_words
                 .iter()
-                .position(|&x| x.starts_with(&mnemonic_seed[i + 1][..the_wordset.prefix_len]))
-                .unwrap_or_else(|| panic!("Invalid word in seed, please check your seed"));
-            w3 = trunc_words
-                .iter()
-                .position(|&x| x.starts_with(&mnemonic_seed[i + 2][..the_wordset.prefix_len]))
-                .unwrap_or_else(|| panic!("Invalid word in seed, please check your seed"));
+                .position(|&x| x == word_prefix(word, the_wordset.prefix_len))
         }
+    })
+}
 
-        let x = w1
-            + wordset_len * (((wordset_len - w1) + w2) % wordset_len)
-            + wordset_len * wordset_len * (((wordset_len - w2) + w3) % wordset_len);
-        if x % wordset_len != w1 {
-            panic!("Something went wrong when decoding your private key, please try again");
+/// Decodes the words of a seed without its checksum word into its hex seed, finding the index of each word with
+/// given function
+pub(crate) fn words_to_hex_seed(
+    mnemonic_seed: &[String],
+    the_wordset: &WordsetOriginal,
+    index_of: impl Fn(&str) -> Option<usize>,
+) -> Result<String, KeyError> {
+    let mut hex_seed = String::new();
+    let wordset_len: usize = the_wordset.words.len();
+    for i in (0..mnemonic_seed.len()).step_by(3) {
+        let mut indexes = [0usize; 3];
+        for (j, index) in indexes.iter_mut().enumerate() {
+            let position = i + j;
+            let word = &mnemonic_seed[position];
+            *index = index_of(word).ok_or_else(|| KeyError::InvalidWord {
+                position,
+                word: word.clone(),
+            })?;
+        }
+        let [w1, w2, w3] = indexes.map(|index| index as u64);
+
+        // 64-bit on every target, three words can reach 1626^3 which is more than 32 bits can hold
+        let n = wordset_len as u64;
+        let x = ((n - w2 + w3) % n)
+            .checked_mul(n)
+            .and_then(|x| x.checked_add((n - w1 + w2) % n))
+            .and_then(|x| x.checked_mul(n))
+            .and_then(|x| x.checked_add(w1))
+            .ok_or(KeyError::CorruptSeed)?;
+        // Like Monero's wallet, reject words that do not decode to 4 bytes
+        let x = u32::try_from(x).map_err(|_| KeyError::CorruptSeed)?;
+        if u64::from(x) % n != w1 {
+            return Err(KeyError::CorruptSeed);
         }
 
         hex_seed += &swap_endian_4_byte(&format!("{:08x}", x));
     }
 
-    hex_seed
+    debug!("derived {}-character hex seed", hex_seed.len());
+    Ok(hex_seed)
+}
+
+/// Encodes given seed bytes into a mnemonic seed of given wordset, three words per 4 bytes plus the checksum word
+fn encode_hex_to_mnemonic(seed: &[u8], wordset: &WordsetOriginal) -> Vec<String> {
+    encode_hex_to_words(seed, wordset)
+        .into_iter()
+        .map(|word| word.to_string())
+        .collect()
+}
+
+/// Encodes given bytes like [`encode_hex_to_mnemonic`], giving references into the wordset
+fn encode_hex_to_words(seed: &[u8], wordset: &WordsetOriginal) -> Vec<&'static str> {
+    let wordset_len = wordset.words.len() as u64;
+    let mut words: Vec<&'static str> = Vec::with_capacity(seed.len() / 4 * 3 + 1);
+    for chunk in seed.chunks_exact(4) {
+        let x = u64::from(u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]));
+        let w1 = x % wordset_len;
+        let w2 = (x / wordset_len + w1) % wordset_len;
+        let w3 = (x / wordset_len / wordset_len + w2) % wordset_len;
+        words.extend([w1, w2, w3].map(|w| wordset.words[w as usize]));
+    }
+    words.push(words[get_checksum_index(&words, wordset.prefix_len)]);
+    words
+}
+
+/// Reconstructs an original (25-word) seed of given language from a private spend key, taking the key as the hex seed
+///
+/// The private spend key is the hex seed reduced to a scalar, so the words only match the lost seed if its hex seed was
+/// already reduced. Otherwise they are different words which still restore the same wallet (same keys and address).
+///
+/// Example:
+/// ```
+/// use libmonero::keys::spend_key_to_mnemonic;
+///
+/// let mnemonic: Vec<String> = spend_key_to_mnemonic("6bdaf7a0a8f3f1ce4767d6d9c38b72b48ccc3ffa4f60be91389b1b96403ff20e", "en").unwrap();
+/// assert_eq!(mnemonic.join(" "), "five saved himself oust taunts pebbles fibula organs koala copy dying vein damp dauntless code gags copy roster geek toolbox joyous apart unlikely warped taunts");
+/// ```
+pub fn spend_key_to_mnemonic(priv_spend_key: &str, language: &str) -> Result<Vec<String>, KeyError> {
+    let the_wordset = wordset_by_name(language)?;
+    let seed = decode_hex32(priv_spend_key)?;
+    Ok(encode_hex_to_mnemonic(&seed, the_wordset))
+}
+
+/// Validates the given mnemonic seed, including its checksum word if it has one
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{validate_mnemonic, KeyError};
+///
+/// let mut mnemonic: Vec<String> = vec!["tissue", "raking", "haunted", "huts", "afraid", "volcano", "howls", "liar", "egotistic", "befit", "rounded", "older", "bluntly", "imbalance", "pivot", "exotic", "tuxedo", "amaze", "mostly", "lukewarm", "macro", "vocal", "hounded", "biplane", "rounded"].iter().map(|s| s.to_string()).collect();
+/// assert_eq!(validate_mnemonic(&mnemonic), Ok(()));
+/// mnemonic[24] = "tissue".to_string();
+/// assert_eq!(validate_mnemonic(&mnemonic), Err(KeyError::InvalidSeedChecksum));
+/// ```
+pub fn validate_mnemonic(mnemonic_seed: &[String]) -> Result<(), KeyError> {
+    derive_hex_seed(mnemonic_seed.to_vec())?;
+    if mnemonic_seed.len() % 3 == 1 {
+        let mnemonic_seed = normalize_seed_words(mnemonic_seed.to_vec());
+        let the_wordset = find_wordset(&mnemonic_seed)?;
+        let (words, checksum_word) = mnemonic_seed.split_at(mnemonic_seed.len() - 1);
+        let words: Vec<&str> = words.iter().map(|word| word.as_str()).collect();
+        if words[get_checksum_index(&words, the_wordset.prefix_len)] != checksum_word[0] {
+            return Err(KeyError::InvalidSeedChecksum);
+        }
+    }
+    Ok(())
+}
+
+/// Appends the checksum word to an original (24-word) or MyMonero (12-word) seed of the given language
+///
+/// Example:
+/// ```
+/// use libmonero::keys::append_checksum_word;
+///
+/// let mnemonic: Vec<String> = vec!["tissue", "raking", "haunted", "huts", "afraid", "volcano", "howls", "liar", "egotistic", "befit", "rounded", "older", "bluntly", "imbalance", "pivot", "exotic", "tuxedo", "amaze", "mostly", "lukewarm", "macro", "vocal", "hounded", "biplane"].iter().map(|s| s.to_string()).collect();
+/// let full_mnemonic: Vec<String> = append_checksum_word(mnemonic, "en").unwrap();
+/// assert_eq!(full_mnemonic[24], "rounded".to_string());
+/// ```
+pub fn append_checksum_word(mut words: Vec<String>, language: &str) -> Result<Vec<String>, KeyError> {
+    let the_wordset = wordset_by_name(language)?;
+    if words.len() != 12 && words.len() != 24 {
+        return Err(KeyError::WrongWordCount {
+            got: words.len(),
+            expected: &[12, 24],
+        });
+    }
+    if let Some(position) = words.iter().position(|word| !the_wordset.words.contains(&word.as_str())) {
+        return Err(KeyError::InvalidWord {
+            position,
+            word: words[position].clone(),
+        });
+    }
+    let word_refs: Vec<&str> = words.iter().map(|word| word.as_str()).collect();
+    let checksum_word = word_refs[get_checksum_index(&word_refs, the_wordset.prefix_len)].to_string();
+    words.push(checksum_word);
+    validate_mnemonic(&words)?;
+    Ok(words)
+}
+
+/// Gives the zero-based index of the word the checksum word of given original or MyMonero seed repeats, for recovery
+/// tools telling which word the checksum word should match
+///
+/// The seed may be given with its checksum word (25 or 13 words) or without (24 or 12 words), the checksum word itself
+/// is not checked. Its words must be in the wordset of given language.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::checksum_word_index;
+///
+/// let mnemonic: Vec<String> = vec!["tissue", "raking", "haunted", "huts", "afraid", "volcano", "howls", "liar", "egotistic", "befit", "rounded", "older", "bluntly", "imbalance", "pivot", "exotic", "tuxedo", "amaze", "mostly", "lukewarm", "macro", "vocal", "hounded", "biplane", "rounded"].iter().map(|s| s.to_string()).collect();
+/// let index: usize = checksum_word_index(&mnemonic, "en").unwrap();
+/// assert_eq!(index, 10);
+/// assert_eq!(mnemonic[index], mnemonic[24]);
+/// ```
+pub fn checksum_word_index(words: &[String], language: &str) -> Result<usize, KeyError> {
+    let the_wordset = wordset_by_name(language)?;
+    let words = match words.len() {
+        12 | 24 => words,
+        13 | 25 => &words[..words.len() - 1],
+        got => return Err(KeyError::WrongWordCount { got, expected: &SEED_WORD_COUNTS }),
+    };
+    let normalized = normalize_seed_words(words.to_vec());
+    if let Some(position) = normalized.iter().position(|word| !the_wordset.words.contains(&word.as_str())) {
+        return Err(KeyError::InvalidWord {
+            position,
+            word: words[position].clone(),
+        });
+    }
+    let word_refs: Vec<&str> = normalized.iter().map(|word| word.as_str()).collect();
+    Ok(get_checksum_index(&word_refs, the_wordset.prefix_len))
+}
+
+/// Returns the given original or MyMonero seed with its checksum word, detecting its language
+///
+/// Seeds that already have a checksum word are validated and returned as they are
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{with_checksum, without_checksum};
+///
+/// let mnemonic: Vec<String> = vec!["tissue", "raking", "haunted", "huts", "afraid", "volcano", "howls", "liar", "egotistic", "befit", "rounded", "older", "bluntly", "imbalance", "pivot", "exotic", "tuxedo", "amaze", "mostly", "lukewarm", "macro", "vocal", "hounded", "biplane", "rounded"].iter().map(|s| s.to_string()).collect();
+/// let stripped: Vec<String> = without_checksum(&mnemonic).unwrap();
+/// assert_eq!(stripped.len(), 24);
+/// assert_eq!(with_checksum(&stripped).unwrap(), mnemonic);
+/// assert_eq!(with_checksum(&mnemonic).unwrap(), mnemonic);
+/// ```
+pub fn with_checksum(words: &[String]) -> Result<Vec<String>, KeyError> {
+    let words = normalize_seed_words(words.to_vec());
+    match words.len() {
+        12 | 24 => {
+            let language = find_wordset(&words)?.name;
+            append_checksum_word(words, language)
+        }
+        13 | 25 => {
+            validate_mnemonic(&words)?;
+            Ok(words)
+        }
+        got => Err(KeyError::WrongWordCount { got, expected: &[12, 13, 24, 25] }),
+    }
+}
+
+/// Returns the given original or MyMonero seed without its checksum word, after checking it
+///
+/// Seeds without a checksum word are validated and returned as they are, `with_checksum` is the exact inverse
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{without_checksum, KeyError};
+///
+/// let mut mnemonic: Vec<String> = vec!["tissue", "raking", "haunted", "huts", "afraid", "volcano", "howls", "liar", "egotistic", "befit", "rounded", "older", "bluntly", "imbalance", "pivot", "exotic", "tuxedo", "amaze", "mostly", "lukewarm", "macro", "vocal", "hounded", "biplane", "rounded"].iter().map(|s| s.to_string()).collect();
+/// assert_eq!(without_checksum(&mnemonic).unwrap(), mnemonic[..24].to_vec());
+/// mnemonic[24] = "tissue".to_string();
+/// assert_eq!(without_checksum(&mnemonic), Err(KeyError::InvalidSeedChecksum));
+/// ```
+pub fn without_checksum(words: &[String]) -> Result<Vec<String>, KeyError> {
+    let mut words = normalize_seed_words(words.to_vec());
+    match words.len() {
+        12 | 24 | 13 | 25 => validate_mnemonic(&words)?,
+        got => return Err(KeyError::WrongWordCount { got, expected: &[12, 13, 24, 25] }),
+    }
+    if words.len() % 3 == 1 {
+        words.pop();
+    }
+    Ok(words)
+}
+
+/// Kinds of seeds, as told apart by [`classify_seed`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SeedKind {
+    /// Original (25-word, or 24 words without the checksum word)
+    Original,
+    /// MyMonero (13-word, or 12 words without the checksum word)
+    MyMonero,
+    /// Polyseed (16-word)
+    Polyseed,
+}
+
+/// Tells which kind of seed the given mnemonic is, from its word count and the wordsets containing its words
+///
+/// Original and MyMonero seeds share their wordsets, so they are told apart by their word count. The checksum is not
+/// checked, a seed that passes still has to be decoded.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{classify_seed, KeyError, SeedKind};
+///
+/// let mnemonic: Vec<String> = "raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language".split(' ').map(|s| s.to_string()).collect();
+/// assert_eq!(classify_seed(&mnemonic), Ok(SeedKind::Polyseed));
+/// let mnemonic: Vec<String> = "tissue raking haunted huts afraid volcano howls liar egotistic befit rounded older bluntly imbalance pivot exotic tuxedo amaze mostly lukewarm macro vocal hounded biplane rounded".split(' ').map(|s| s.to_string()).collect();
+/// assert_eq!(classify_seed(&mnemonic), Ok(SeedKind::Original));
+/// assert_eq!(classify_seed(&mnemonic[..20]), Err(KeyError::WrongWordCount { got: 20, expected: &[12, 13, 16, 24, 25] }));
+/// ```
+pub fn classify_seed(mnemonic: &[String]) -> Result<SeedKind, KeyError> {
+    let kind = match mnemonic.len() {
+        12 | 13 => SeedKind::MyMonero,
+        24 | 25 => SeedKind::Original,
+        POLYSEED_WORD_COUNT => {
+            let in_wordset = WORDSETSPOLYSEED.iter().any(|wordset| {
+                mnemonic
+                    .iter()
+                    .all(|word| polyseed_find_word(wordset, word).is_some())
+            });
+            if !in_wordset {
+                return Err(polyseed_word_error(mnemonic));
+            }
+            return Ok(SeedKind::Polyseed);
+        }
+        got => return Err(KeyError::WrongWordCount { got, expected: &[12, 13, 16, 24, 25] }),
+    };
+    find_wordset(&normalize_seed_words(mnemonic.to_vec()))?;
+    Ok(kind)
+}
+
+/// Guesses the word count of a seed from its first words, for progress displays like "7/25 words" while it is entered
+///
+/// Words all in a polyseed wordset but not all in an original one give 16, words all in an original wordset give 25.
+/// Many words are in both kinds of wordsets (e.g. English ones), those are taken for the start of an original seed
+/// unless they are 16 words of a polyseed seed with a valid checksum. Likewise 13 words of a MyMonero seed with a valid
+/// checksum word give 13. Returns `None` for no words, more than 25 words or words that are not all in one wordset.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::expected_word_count;
+///
+/// let mnemonic: Vec<String> = "raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language".split(' ').map(|s| s.to_string()).collect();
+/// assert_eq!(expected_word_count(&mnemonic), Some(16));
+/// let mnemonic: Vec<String> = "tissue raking haunted huts".split(' ').map(|s| s.to_string()).collect();
+/// assert_eq!(expected_word_count(&mnemonic), Some(25));
+/// assert_eq!(expected_word_count(&["tissue".to_string(), "xyz".to_string()]), None);
+/// ```
+pub fn expected_word_count(first_few_words: &[String]) -> Option<usize> {
+    if first_few_words.is_empty() || first_few_words.len() > 25 {
+        return None;
+    }
+    let words = normalize_seed_words(first_few_words.to_vec());
+    let in_original = WORDSETSORIGINAL
+        .iter()
+        .any(|wordset| words.iter().all(|word| wordset.words.contains(&word.as_str())));
+    let in_polyseed = first_few_words.len() <= POLYSEED_WORD_COUNT
+        && WORDSETSPOLYSEED.iter().any(|wordset| {
+            first_few_words
+                .iter()
+                .all(|word| polyseed_find_word(wordset, word).is_some())
+        });
+    if in_polyseed && (!in_original || polyseed_checksum_valid(first_few_words)) {
+        return Some(POLYSEED_WORD_COUNT);
+    }
+    if !in_original {
+        return None;
+    }
+    if words.len() == 13 && validate_mnemonic(&words).is_ok() {
+        return Some(13);
+    }
+    Some(25)
 }
 
 /// Derives private keys for original (25-word) (64-byte hex) type seeds
-fn derive_original_priv_keys(hex_seed: String) -> Vec<String> {
+fn derive_original_priv_keys(hex_seed: String) -> Result<Vec<String>, KeyError> {
     // Turn hex seed into bytes
-    let hex_bytes = hex::decode(hex_seed).unwrap();
-    let mut hex_bytes_array = [0u8; 32];
-    hex_bytes_array.copy_from_slice(&hex_bytes);
-    // Pass bytes through sc_reduce32 function to get private spend key
-    sc_reduce32(&mut hex_bytes_array);
+    let hex_bytes_array = decode_hex32(&hex_seed)?;
+    // Reduce bytes to a scalar to get private spend key
+    let hex_bytes_array = reduce_scalar(hex_bytes_array);
     let mut priv_spend_key = String::new();
     for i in (0..hex_bytes_array.len()).step_by(32) {
         let mut priv_key = String::new();
@@ -376,13 +932,8 @@ fn derive_original_priv_keys(hex_seed: String) -> Vec<String> {
         }
         priv_spend_key.push_str(&priv_key);
     }
-    // Turn private spend key into bytes and pass through Keccak256 function
-    let priv_spend_key_bytes = hex::decode(priv_spend_key.clone()).unwrap();
-    let priv_view_key_bytes = Keccak256::digest(priv_spend_key_bytes);
-    let mut priv_view_key_array = [0u8; 32];
-    priv_view_key_array.copy_from_slice(&priv_view_key_bytes);
-    // Pass bytes through sc_reduce32 function to get private view key
-    sc_reduce32(&mut priv_view_key_array as &mut [u8; 32]);
+    // Pass private spend key through Keccak256 function and reduce the hash to a scalar to get private view key
+    let priv_view_key_array = reduce_scalar(cn_fast_hash(&hex_bytes_array));
     let mut priv_view_key = String::new();
     for i in (0..priv_view_key_array.len()).step_by(32) {
         let mut priv_key = String::new();
@@ -391,18 +942,19 @@ fn derive_original_priv_keys(hex_seed: String) -> Vec<String> {
         }
         priv_view_key.push_str(&priv_key);
     }
+    debug!("derived private keys from original hex seed");
     // Finally, return the keys
-    vec![priv_spend_key, priv_view_key]
+    Ok(vec![priv_spend_key, priv_view_key])
 }
 
 /// Derives private keys for MyMonero (13-word) (32-byte hex) type seeds
-fn derive_mymonero_priv_keys(hex_seed: String) -> Vec<String> {
-    // Keccak and sc_reduce32 to get private spend key
-    let hex_bytes = hex::decode(hex_seed).unwrap();
-    let priv_spend_key_bytes = Keccak256::digest(&hex_bytes);
-    let mut priv_spend_key_array = [0u8; 32];
-    priv_spend_key_array.copy_from_slice(&priv_spend_key_bytes);
-    sc_reduce32(&mut priv_spend_key_array as &mut [u8; 32]);
+pub(crate) fn derive_mymonero_priv_keys(hex_seed: String) -> Result<Vec<String>, KeyError> {
+    if hex_seed.len() != 32 {
+        return Err(KeyError::InvalidHexLength { got: hex_seed.len(), expected: 32 });
+    }
+    // Keccak and reduce to a scalar to get private spend key
+    let hex_bytes = hex::decode(hex_seed).map_err(|_| KeyError::InvalidHex)?;
+    let priv_spend_key_array = reduce_scalar(cn_fast_hash(&hex_bytes));
     let mut priv_spend_key = String::new();
     for i in (0..priv_spend_key_array.len()).step_by(32) {
         let mut priv_key = String::new();
@@ -411,15 +963,8 @@ fn derive_mymonero_priv_keys(hex_seed: String) -> Vec<String> {
         }
         priv_spend_key.push_str(&priv_key);
     }
-    // Double Keccak and sc_reduce32 of hex_seed to get private view key
-    let priv_view_key_bytes = Keccak256::digest(&hex_bytes);
-    let mut priv_view_key_array = [0u8; 32];
-    priv_view_key_array.copy_from_slice(&priv_view_key_bytes);
-    // Keccak again
-    let priv_view_key_bytes = Keccak256::digest(priv_view_key_array);
-    priv_view_key_array.copy_from_slice(&priv_view_key_bytes);
-    // sc_reduce32
-    sc_reduce32(&mut priv_view_key_array as &mut [u8; 32]);
+    // Double Keccak and reduce to a scalar of hex_seed to get private view key
+    let priv_view_key_array = reduce_scalar(cn_fast_hash(&cn_fast_hash(&hex_bytes)));
     let mut priv_view_key = String::new();
     for i in (0..priv_view_key_array.len()).step_by(32) {
         let mut priv_key = String::new();
@@ -428,28 +973,260 @@ fn derive_mymonero_priv_keys(hex_seed: String) -> Vec<String> {
         }
         priv_view_key.push_str(&priv_key);
     }
+    debug!("derived private keys from MyMonero hex seed");
     // Finally, return the keys
-    vec![priv_spend_key, priv_view_key]
+    Ok(vec![priv_spend_key, priv_view_key])
+}
+
+/// Normalizes a pasted hex seed: surrounding whitespace (e.g. a trailing newline) and an `0x` prefix are removed, and
+/// the digits are lowercased
+///
+/// Nothing else is checked, the length and digits are checked when the seed is decoded.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::normalize_hex_seed;
+///
+/// let hex_seed: String = normalize_hex_seed(" 0xF7B3BEABC9BD6CED864096C0891A8FDF94DC714178A09828775DBA01B4DF9AB8\n");
+/// assert_eq!(hex_seed, "f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8".to_string());
+/// ```
+pub fn normalize_hex_seed(hex_seed: &str) -> String {
+    let hex_seed = hex_seed.trim();
+    let hex_seed = hex_seed
+        .strip_prefix("0x")
+        .or_else(|| hex_seed.strip_prefix("0X"))
+        .unwrap_or(hex_seed);
+    hex_seed.to_ascii_lowercase()
 }
 
 /// Derives private keys from given hex seed
 ///
 /// Vector's first element is private spend key, second element is private view key
 ///
+/// > NOTE: This is the legacy API, kept for backward compatibility. [`derive_priv_keypair`] gives the same keys with
+/// > named fields, which cannot be swapped by indexing the wrong element.
+///
+/// The hex seed is normalized with [`normalize_hex_seed`] first, so a pasted seed with an `0x` prefix, surrounding
+/// whitespace or uppercase digits gives the same keys.
+///
+/// The seed type is guessed from the length of the hex seed: 32 characters for MyMonero, 64 for original and polyseed.
+/// The guess is wrong for a truncated original hex seed of 32 characters, which is taken as a MyMonero one and gives
+/// other keys without any error. Use [`derive_priv_keys_typed`] when the seed type is known.
+///
 /// Example:
 /// ```
 /// use libmonero::keys::derive_priv_keys;
 ///
 /// let hex_seed: String = "f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8".to_string();
-/// let priv_keys: Vec<String> = derive_priv_keys(hex_seed);
+/// let priv_keys: Vec<String> = derive_priv_keys(hex_seed).unwrap();
 /// assert_eq!(priv_keys, vec!["c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08", "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908"].iter().map(|&s| s.to_string()).collect::<Vec<String>>());
 /// ```
-pub fn derive_priv_keys(hex_seed: String) -> Vec<String> {
+pub fn derive_priv_keys(hex_seed: String) -> Result<Vec<String>, KeyError> {
+    let hex_seed = normalize_hex_seed(&hex_seed);
     match hex_seed.len() {
         32 => derive_mymonero_priv_keys(hex_seed),
         64 => derive_original_priv_keys(hex_seed),
-        _ => panic!("Invalid hex seed"),
+        _ => Err(KeyError::InvalidHexSeed),
+    }
+}
+
+/// Derives private keys from given hex seed like [`derive_priv_keys`], as a [`PrivKeyPair`] instead of a vector
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{derive_priv_keypair, PrivKeyPair};
+///
+/// let hex_seed: String = "f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8".to_string();
+/// let priv_keys: PrivKeyPair = derive_priv_keypair(hex_seed).unwrap();
+/// assert_eq!(priv_keys.spend, "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08".to_string());
+/// assert_eq!(priv_keys.view, "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908".to_string());
+/// ```
+pub fn derive_priv_keypair(hex_seed: String) -> Result<PrivKeyPair, KeyError> {
+    let mut priv_keys = derive_priv_keys(hex_seed)?;
+    let view = priv_keys.pop().ok_or(KeyError::InvalidHexSeed)?;
+    let spend = priv_keys.pop().ok_or(KeyError::InvalidHexSeed)?;
+    Ok(PrivKeyPair { spend, view })
+}
+
+/// Derives private keys from given hex seed of given seed type, instead of guessing the type from its length
+///
+/// Vector's first element is private spend key, second element is private view key. The hex seed is normalized with
+/// [`normalize_hex_seed`] first, hex seeds of another length than the one of the seed type (32 characters for
+/// MyMonero, 64 for original and polyseed) then give [`KeyError::InvalidHexLength`].
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{derive_priv_keys, derive_priv_keys_typed, KeyError, SeedKind};
+///
+/// let hex_seed: String = "f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8".to_string();
+/// assert_eq!(derive_priv_keys_typed(hex_seed.clone(), SeedKind::Original), derive_priv_keys(hex_seed.clone()));
+/// // Truncated, it would be taken as a MyMonero hex seed by derive_priv_keys
+/// assert_eq!(derive_priv_keys_typed(hex_seed[..32].to_string(), SeedKind::Original), Err(KeyError::InvalidHexLength { got: 32, expected: 64 }));
+/// ```
+pub fn derive_priv_keys_typed(hex_seed: String, kind: SeedKind) -> Result<Vec<String>, KeyError> {
+    let hex_seed = normalize_hex_seed(&hex_seed);
+    match kind {
+        SeedKind::MyMonero => derive_mymonero_priv_keys(hex_seed),
+        SeedKind::Original | SeedKind::Polyseed => derive_original_priv_keys(hex_seed),
+    }
+}
+
+/// Derives private keys from given hex seed like [`derive_priv_keys`], rejecting degenerate seeds
+///
+/// All-zero and all-0xff hex seeds, and seeds whose private spend key reduces to zero (the identity keypair), give
+/// [`KeyError::WeakSeed`]. Other seeds are not judged, see [`weak_seed_check`] for a broader heuristic.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{derive_priv_keys, derive_priv_keys_checked, KeyError};
+///
+/// let hex_seed: String = "f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8".to_string();
+/// assert_eq!(derive_priv_keys_checked(hex_seed.clone()).unwrap(), derive_priv_keys(hex_seed).unwrap());
+/// assert_eq!(derive_priv_keys_checked("00".repeat(32)), Err(KeyError::WeakSeed));
+/// assert_eq!(derive_priv_keys_checked("ff".repeat(16)), Err(KeyError::WeakSeed));
+/// ```
+pub fn derive_priv_keys_checked(hex_seed: String) -> Result<Vec<String>, KeyError> {
+    let hex_seed = normalize_hex_seed(&hex_seed);
+    let priv_keys = derive_priv_keys(hex_seed.clone())?;
+    let degenerate = |key: &str, digit: u8| key.bytes().all(|c| c.to_ascii_lowercase() == digit);
+    if degenerate(&hex_seed, b'0') || degenerate(&hex_seed, b'f') || degenerate(&priv_keys[0], b'0') {
+        return Err(KeyError::WeakSeed);
+    }
+    Ok(priv_keys)
+}
+
+/// Derives private keys from given hex seed and seed offset passphrase, the way Monero's wallet does
+///
+/// The passphrase offsets the seed before the keys are derived, `seed - cn_slow_hash(passphrase)` modulo the order of
+/// the Ed25519 group, so the same words restore a different wallet for every passphrase. The empty passphrase leaves the
+/// seed as it is, giving the keys of [`derive_priv_keys`]. Only original (64-character) hex seeds take a passphrase.
+/// The hex seed is normalized with [`normalize_hex_seed`] first, with or without a passphrase.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{derive_priv_keys, derive_priv_keys_with_passphrase};
+///
+/// let hex_seed: String = "f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8".to_string();
+/// let priv_keys: Vec<String> = derive_priv_keys_with_passphrase(hex_seed.clone(), "").unwrap();
+/// assert_eq!(priv_keys, derive_priv_keys(hex_seed.clone()).unwrap());
+/// let hidden_keys: Vec<String> = derive_priv_keys_with_passphrase(hex_seed, "hunter2").unwrap();
+/// assert_ne!(hidden_keys, priv_keys);
+/// ```
+pub fn derive_priv_keys_with_passphrase(hex_seed: String, passphrase: &str) -> Result<Vec<String>, KeyError> {
+    let hex_seed = normalize_hex_seed(&hex_seed);
+    if passphrase.is_empty() {
+        return derive_priv_keys(hex_seed);
+    }
+    if hex_seed.len() != 64 {
+        return Err(KeyError::InvalidHexSeed);
+    }
+    let seed = Scalar::from_bytes_mod_order(decode_hex32(&hex_seed)?);
+    let offset = Scalar::from_bytes_mod_order(decode_hex32(&cn_slow_hash_v0(passphrase.as_bytes()))?);
+    derive_original_priv_keys(hex::encode((seed - offset).to_bytes()))
+}
+
+/// Cases of hexadecimal keys, keys are lowercase unless a function takes a case
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HexCase {
+    #[default]
+    Lower,
+    Upper,
+}
+
+/// HexCase functions etc.
+impl HexCase {
+    /// Returns given lowercase hex string in this case
+    fn apply(self, hex: String) -> String {
+        match self {
+            HexCase::Lower => hex,
+            HexCase::Upper => hex.to_ascii_uppercase(),
+        }
+    }
+}
+
+/// Derives private keys from given hex seed, in given hex case
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{derive_priv_keys_with_case, HexCase};
+///
+/// let hex_seed: String = "f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8".to_string();
+/// let priv_keys: Vec<String> = derive_priv_keys_with_case(hex_seed, HexCase::Upper).unwrap();
+/// assert_eq!(priv_keys, vec!["C8982EADA77BA2245183F2BFF85DFAF993DC714178A09828775DBA01B4DF9A08", "0D13A94C82D7A60ABB54D2217D38935C3F715295E30378F8848A1CA1ABC8D908"].iter().map(|&s| s.to_string()).collect::<Vec<String>>());
+/// ```
+pub fn derive_priv_keys_with_case(hex_seed: String, case: HexCase) -> Result<Vec<String>, KeyError> {
+    let priv_keys = derive_priv_keys(hex_seed)?;
+    Ok(priv_keys.into_iter().map(|key| case.apply(key)).collect())
+}
+
+/// Decodes a 64-character hex string into 32 bytes, without allocating and in constant time for valid input
+///
+/// Strings of another length give [`KeyError::InvalidHexLength`], so truncated keys are told apart from mistyped ones.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{decode_hex32, KeyError};
+///
+/// let bytes: [u8; 32] = decode_hex32("c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08").unwrap();
+/// assert_eq!(bytes[0], 0xc8);
+/// assert_eq!(decode_hex32("c898"), Err(KeyError::InvalidHexLength { got: 4, expected: 64 }));
+/// ```
+pub fn decode_hex32(s: &str) -> Result<[u8; 32], KeyError> {
+    let chars = s.as_bytes();
+    if chars.len() != 64 {
+        return Err(KeyError::InvalidHexLength { got: chars.len(), expected: 64 });
+    }
+    let mut bytes = [0u8; 32];
+    let mut invalid: i16 = 0;
+    for (byte, pair) in bytes.iter_mut().zip(chars.chunks_exact(2)) {
+        let (high, high_invalid) = decode_hex_nibble(pair[0]);
+        let (low, low_invalid) = decode_hex_nibble(pair[1]);
+        *byte = (high << 4) | low;
+        invalid |= high_invalid | low_invalid;
     }
+    if invalid != 0 {
+        return Err(KeyError::InvalidHex);
+    }
+    Ok(bytes)
+}
+
+/// Parses a list of 32-byte hex keys separated by whitespace and/or commas, e.g. from a text file or CLI arguments
+///
+/// Blank lines and leading, trailing or repeated separators are ignored. Every entry must be 64 hex characters, the
+/// first one that is not is given as [`KeyError::InvalidHexKey`] with its zero-based position among the entries.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{parse_hex_keys, KeyError};
+///
+/// let input = "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95,\n157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47,\n";
+/// let keys: Vec<[u8; 32]> = parse_hex_keys(input).unwrap();
+/// assert_eq!(keys.len(), 2);
+/// assert_eq!(parse_hex_keys("e78d891d, 157d278a"), Err(KeyError::InvalidHexKey { position: 0, entry: "e78d891d".to_string() }));
+/// ```
+pub fn parse_hex_keys(input: &str) -> Result<Vec<[u8; 32]>, KeyError> {
+    input
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|entry| !entry.is_empty())
+        .enumerate()
+        .map(|(position, entry)| {
+            decode_hex32(entry).map_err(|_| KeyError::InvalidHexKey {
+                position,
+                entry: entry.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Decodes a hex character without branching on it, returns its value and -1 if it is not a hex character (0 otherwise)
+fn decode_hex_nibble(c: u8) -> (u8, i16) {
+    let digit = c as i16 - b'0' as i16;
+    let letter = (c | 0x20) as i16 - b'a' as i16;
+    // All ones if the value is in range, zero otherwise
+    let digit_mask = !((digit | (9 - digit)) >> 15);
+    let letter_mask = !((letter | (5 - letter)) >> 15);
+    let value = (digit & digit_mask) | ((letter + 10) & letter_mask);
+    (value as u8, !(digit_mask | letter_mask))
 }
 
 /// Derives private view key from given private spend key
@@ -459,17 +1236,14 @@ pub fn derive_priv_keys(hex_seed: String) -> Vec<String> {
 /// use libmonero::keys::derive_priv_vk_from_priv_sk;
 ///
 /// let private_spend_key: String = "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08".to_string();
-/// let private_view_key: String = derive_priv_vk_from_priv_sk(private_spend_key);
+/// let private_view_key: String = derive_priv_vk_from_priv_sk(private_spend_key).unwrap();
 /// assert_eq!(private_view_key, "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908".to_string());
 /// ```
-pub fn derive_priv_vk_from_priv_sk(private_spend_key: String) -> String {
+pub fn derive_priv_vk_from_priv_sk(private_spend_key: String) -> Result<String, KeyError> {
     // Turn private spend key into bytes and pass through Keccak256 function
-    let priv_spend_key_bytes = hex::decode(private_spend_key.clone()).unwrap();
-    let priv_view_key_bytes = Keccak256::digest(priv_spend_key_bytes);
-    let mut priv_view_key_array = [0u8; 32];
-    priv_view_key_array.copy_from_slice(&priv_view_key_bytes);
-    // Pass bytes through sc_reduce32 function to get private view key
-    sc_reduce32(&mut priv_view_key_array as &mut [u8; 32]);
+    let priv_spend_key_bytes = decode_hex32(&private_spend_key)?;
+    // Reduce the hash to a scalar to get private view key
+    let priv_view_key_array = reduce_scalar(cn_fast_hash(&priv_spend_key_bytes));
     let mut priv_view_key = String::new();
     for i in (0..priv_view_key_array.len()).step_by(32) {
         let mut priv_key = String::new();
@@ -479,7 +1253,30 @@ pub fn derive_priv_vk_from_priv_sk(private_spend_key: String) -> String {
         priv_view_key.push_str(&priv_key);
     }
     // Finally, return the private view key
-    priv_view_key
+    Ok(priv_view_key)
+}
+
+/// Derives the private spend key and private view key of the child wallet at given index of a parent private spend key
+///
+/// The child private spend key is `hash_to_scalar(parent || index)`, the index being 4 little-endian bytes, and its
+/// private view key is derived from it like in any Monero wallet. Every child is an independent Monero wallet with its
+/// own main address: they are not BIP32 keys nor subaddresses, and nothing on chain links them to their parent.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::derive_child_keys;
+///
+/// let parent: &str = "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08";
+/// let (priv_spend, priv_view) = derive_child_keys(parent, 0).unwrap();
+/// assert_eq!(priv_spend, "689c610df9b46ebfaff745971cd340d706ed8a801c9bb9561f3acb0f38730a01".to_string());
+/// assert_eq!(priv_view, "a1d36a44249fc477ab1ebe398a1c42ca17cf7c3a8f273896691143a9ce0c320a".to_string());
+/// ```
+pub fn derive_child_keys(parent_priv_spend: &str, index: u32) -> Result<(String, String), KeyError> {
+    let mut data = decode_hex32(parent_priv_spend)?.to_vec();
+    data.extend_from_slice(&index.to_le_bytes());
+    let priv_spend = hex::encode(hash_to_scalar(&data));
+    let priv_view = derive_priv_vk_from_priv_sk(priv_spend.clone())?;
+    Ok((priv_spend, priv_view))
 }
 
 /// Performs scalar multiplication of the Ed25519 base point by a given scalar, yielding a corresponding point on the elliptic curve
@@ -494,57 +1291,277 @@ fn ge_scalar_mult_base(scalar: &Scalar) -> EdwardsPoint {
 /// use libmonero::keys::derive_pub_key;
 ///
 /// let private_spend_key: String = "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08".to_string();
-/// let public_spend_key: String = derive_pub_key(private_spend_key);
+/// let public_spend_key: String = derive_pub_key(private_spend_key).unwrap();
 /// assert_eq!(public_spend_key, "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95".to_string());
 /// ```
-pub fn derive_pub_key(private_key: String) -> String {
+pub fn derive_pub_key(private_key: String) -> Result<String, KeyError> {
     // Turn private key into bytes
-    let private_key_bytes = hex::decode(private_key.clone()).unwrap();
-    let mut private_key_array = [0u8; 32];
-    private_key_array.copy_from_slice(&private_key_bytes);
-    let key_scalar = Scalar::from_bytes_mod_order(private_key_array);
+    let private_key_array = decode_hex32(&private_key)?;
+    Ok(hex::encode(derive_pub_key_bytes(private_key_array)))
+}
+
+/// Derives public key from given private key (spend or view), both as bytes
+///
+/// Like [`derive_pub_key`] without the hex round-trip, e.g. to pass keys on to [`derive_address_bytes`](super::derive_address_bytes).
+///
+/// Example:
+/// ```
+/// use libmonero::keys::derive_pub_key_bytes;
+///
+/// let private_spend_key: [u8; 32] = hex::decode("c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08").unwrap().try_into().unwrap();
+/// let public_spend_key: [u8; 32] = derive_pub_key_bytes(private_spend_key);
+/// assert_eq!(hex::encode(public_spend_key), "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95");
+/// ```
+pub fn derive_pub_key_bytes(private_key: [u8; 32]) -> [u8; 32] {
+    let key_scalar = Scalar::from_bytes_mod_order(private_key);
     // Scalar multiplication with the base point
-    let result_point = ge_scalar_mult_base(&key_scalar);
-    // The result_point now contains the public key
-    let public_key_bytes = result_point.compress().to_bytes();
-    let mut public_key = String::new();
-    for i in (0..public_key_bytes.len()).step_by(32) {
-        let mut pub_key = String::new();
-        for item in public_key_bytes.iter().skip(i).take(32) {
-            pub_key.push_str(&format!("{:02x}", item));
-        }
-        public_key.push_str(&pub_key);
+    ge_scalar_mult_base(&key_scalar).compress().to_bytes()
+}
+
+/// Derives public key from given private key (spend or view) like [`derive_pub_key`], rejecting degenerate public keys
+///
+/// The point must not be the identity and must be in the prime-order subgroup, otherwise [`KeyError::DegeneratePubKey`]
+/// is returned. A multiple of the base point is always in the subgroup, so in practice this catches private keys which
+/// are zero modulo the order of the Ed25519 group, e.g. all zeros or the group order itself, whose public key is the
+/// identity: an address built around it could be spent from by anyone.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{derive_pub_key, derive_pub_key_checked, KeyError};
+///
+/// let private_spend_key: String = "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08".to_string();
+/// assert_eq!(derive_pub_key_checked(private_spend_key.clone()), derive_pub_key(private_spend_key));
+/// assert_eq!(derive_pub_key_checked("00".repeat(32)), Err(KeyError::DegeneratePubKey));
+/// ```
+pub fn derive_pub_key_checked(private_key: String) -> Result<String, KeyError> {
+    let key_scalar = Scalar::from_bytes_mod_order(decode_hex32(&private_key)?);
+    let point = ge_scalar_mult_base(&key_scalar);
+    if point.is_identity() || !point.is_torsion_free() {
+        return Err(KeyError::DegeneratePubKey);
     }
-    // Finally, return the public key
-    public_key
+    Ok(hex::encode(point.compress().to_bytes()))
 }
 
-/// Derives main public address from given public spend key, public view key and network
+/// Derives public key from given private key (spend or view), in given hex case
 ///
-/// Networks:
-/// - `0` : Monero Mainnet
-/// - `1` : Monero Testnet
+/// Example:
+/// ```
+/// use libmonero::keys::{derive_pub_key_with_case, HexCase};
+///
+/// let private_spend_key: String = "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08".to_string();
+/// let public_spend_key: String = derive_pub_key_with_case(private_spend_key, HexCase::Upper).unwrap();
+/// assert_eq!(public_spend_key, "E78D891DD2BE407F24E6470CAAD956E1B746AE0B41CD8252F96684090BC05D95".to_string());
+/// ```
+pub fn derive_pub_key_with_case(private_key: String, case: HexCase) -> Result<String, KeyError> {
+    Ok(case.apply(derive_pub_key(private_key)?))
+}
+
+/// Keys struct contains all four keys of a wallet
+///
+/// Its `Debug` output redacts the private keys, so they do not end up in logs. Use [`Keys::reveal`] to print them.
 ///
 /// Example:
 /// ```
-/// use libmonero::keys::derive_address;
+/// use libmonero::keys::Keys;
 ///
-/// let public_spend_key: String = "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95".to_string();
-/// let public_view_key: String = "157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47".to_string();
-/// let public_address: String = derive_address(public_spend_key, public_view_key, 0);
-/// assert_eq!(public_address, "4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J".to_string());
+/// let keys = Keys {
+///     priv_spend: "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08".to_string(),
+///     pub_spend: "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95".to_string(),
+///     priv_view: "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908".to_string(),
+///     pub_view: "157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47".to_string(),
+/// };
+/// assert!(!format!("{:?}", keys).contains(&keys.priv_spend));
+/// assert!(format!("{:?}", keys).contains("priv_spend: [REDACTED]"));
+/// assert!(keys.reveal().contains(&keys.priv_spend));
 /// ```
-pub fn derive_address(public_spend_key: String, public_view_key: String, network: u8) -> String {
-    let network_byte = match network {
-        0 => vec![0x12], // Monero mainnet
-        1 => vec![0x35], // Monero testnet
-        _ => panic!("Invalid network"),
-    };
-    let pub_sk_bytes = hex::decode(public_spend_key.clone()).unwrap();
-    let pub_vk_bytes = hex::decode(public_view_key.clone()).unwrap();
-    let mut data = [&network_byte[..], &pub_sk_bytes[..], &pub_vk_bytes[..]].concat();
-    let hash = Keccak256::digest(&data);
-    data.append(&mut hash[..4].to_vec());
+#[derive(Clone, PartialEq, Eq)]
+pub struct Keys {
+    pub priv_spend: String,
+    pub pub_spend: String,
+    pub priv_view: String,
+    pub pub_view: String,
+}
 
-    base58_monero::encode(&data).unwrap()
+impl Keys {
+    /// Formats all keys like `Debug`, with the private keys redacted or not
+    fn fmt_keys(&self, f: &mut fmt::Formatter, redact: bool) -> fmt::Result {
+        f.debug_struct("Keys")
+            .field("priv_spend", secret(&self.priv_spend, redact))
+            .field("pub_spend", &self.pub_spend)
+            .field("priv_view", secret(&self.priv_view, redact))
+            .field("pub_view", &self.pub_view)
+            .finish()
+    }
+
+    /// Returns the `Debug` output of the keys with the private keys shown
+    ///
+    /// > WARNING: The output holds the private keys, anyone who gets it can spend the funds of the wallet
+    pub fn reveal(&self) -> String {
+        format!("{:?}", DebugWith(|f| self.fmt_keys(f, false)))
+    }
+}
+
+impl fmt::Debug for Keys {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_keys(f, true)
+    }
+}
+
+/// PrivKeyPair struct contains the private spend and view keys of a wallet, see [`derive_priv_keypair`]
+///
+/// Like [`Keys`], its `Debug` output redacts both keys. Use [`PrivKeyPair::reveal`] to print them.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::PrivKeyPair;
+///
+/// let priv_keys = PrivKeyPair {
+///     spend: "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08".to_string(),
+///     view: "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908".to_string(),
+/// };
+/// assert_eq!(format!("{:?}", priv_keys), "PrivKeyPair { spend: [REDACTED], view: [REDACTED] }");
+/// assert!(priv_keys.reveal().contains(&priv_keys.view));
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct PrivKeyPair {
+    pub spend: String,
+    pub view: String,
+}
+
+impl PrivKeyPair {
+    /// Formats both keys like `Debug`, redacted or not
+    fn fmt_keys(&self, f: &mut fmt::Formatter, redact: bool) -> fmt::Result {
+        f.debug_struct("PrivKeyPair")
+            .field("spend", secret(&self.spend, redact))
+            .field("view", secret(&self.view, redact))
+            .finish()
+    }
+
+    /// Returns the `Debug` output of the keys with the private keys shown
+    ///
+    /// > WARNING: The output holds the private keys, anyone who gets it can spend the funds of the wallet
+    pub fn reveal(&self) -> String {
+        format!("{:?}", DebugWith(|f| self.fmt_keys(f, false)))
+    }
+}
+
+impl fmt::Debug for PrivKeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_keys(f, true)
+    }
+}
+
+/// Placeholder printed by `Debug` instead of a secret
+struct Redacted;
+
+impl fmt::Debug for Redacted {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+/// Returns given secret to be printed by `Debug`, or the placeholder if it is redacted
+pub(crate) fn secret(value: &dyn fmt::Debug, redact: bool) -> &dyn fmt::Debug {
+    if redact {
+        &Redacted
+    } else {
+        value
+    }
+}
+
+/// Prints with given closure as `Debug`, for the `reveal` methods of types with redacted secrets
+pub(crate) struct DebugWith<F: Fn(&mut fmt::Formatter) -> fmt::Result>(pub F);
+
+impl<F: Fn(&mut fmt::Formatter) -> fmt::Result> fmt::Debug for DebugWith<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        (self.0)(f)
+    }
+}
+
+/// Derives all four keys (private/public spend and view keys) from given mnemonic seed
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{derive_all_keys, Keys};
+///
+/// let mnemonic: Vec<String> = vec!["tissue", "raking", "haunted", "huts", "afraid", "volcano", "howls", "liar", "egotistic", "befit", "rounded", "older", "bluntly", "imbalance", "pivot", "exotic", "tuxedo", "amaze", "mostly", "lukewarm", "macro", "vocal", "hounded", "biplane", "rounded"].iter().map(|s| s.to_string()).collect();
+/// let keys: Keys = derive_all_keys(mnemonic).unwrap();
+/// assert_eq!(keys.priv_spend, "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08".to_string());
+/// assert_eq!(keys.pub_spend, "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95".to_string());
+/// assert_eq!(keys.priv_view, "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908".to_string());
+/// assert_eq!(keys.pub_view, "157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47".to_string());
+/// ```
+pub fn derive_all_keys(mnemonic: Vec<String>) -> Result<Keys, KeyError> {
+    let hex_seed = derive_hex_seed(mnemonic)?;
+    let priv_keys = derive_priv_keys(hex_seed)?;
+    let pub_spend = derive_pub_key(priv_keys[0].clone())?;
+    let pub_view = derive_pub_key(priv_keys[1].clone())?;
+    Ok(Keys {
+        priv_spend: priv_keys[0].clone(),
+        pub_spend,
+        priv_view: priv_keys[1].clone(),
+        pub_view,
+    })
+}
+
+/// Derives all four keys from given private spend key as a scalar, which must already be reduced
+///
+/// Unlike [`derive_priv_keys`] the spend key is not reduced: a scalar that is not canonical (not below the order of the
+/// Ed25519 group) gives [`KeyError::NonCanonicalScalar`] instead of being silently replaced by another one. The private
+/// view key is derived from the spend key like for original and polyseed wallets.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{keys_from_spend_scalar, KeyError, Keys};
+///
+/// let spend: [u8; 32] = hex::decode("c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08").unwrap().try_into().unwrap();
+/// let keys: Keys = keys_from_spend_scalar(spend).unwrap();
+/// assert_eq!(keys.priv_view, "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908".to_string());
+/// assert_eq!(keys.pub_spend, "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95".to_string());
+/// assert_eq!(keys_from_spend_scalar([0xff; 32]), Err(KeyError::NonCanonicalScalar));
+/// ```
+pub fn keys_from_spend_scalar(spend: [u8; 32]) -> Result<Keys, KeyError> {
+    if needed_reduction(&spend) {
+        return Err(KeyError::NonCanonicalScalar);
+    }
+    let view = reduce_scalar(cn_fast_hash(&spend));
+    Ok(Keys {
+        priv_spend: hex::encode(spend),
+        pub_spend: hex::encode(derive_pub_key_bytes(spend)),
+        priv_view: hex::encode(view),
+        pub_view: hex::encode(derive_pub_key_bytes(view)),
+    })
+}
+
+/// Checks all four keys of given key set belong together, to catch keys pasted from different wallets
+///
+/// The public keys must be their private keys times the base point and the private view key must be derived from the
+/// private spend key, as it is for original and polyseed wallets (MyMonero wallets derive it from the seed instead).
+/// The first key that does not match is named in [`KeyError::InconsistentKeys`].
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{verify_keys_consistency, KeyError, Keys};
+///
+/// let mut keys: Keys = Keys {
+///     priv_spend: "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08".to_string(),
+///     pub_spend: "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95".to_string(),
+///     priv_view: "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908".to_string(),
+///     pub_view: "157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47".to_string(),
+/// };
+/// assert_eq!(verify_keys_consistency(&keys), Ok(()));
+/// keys.pub_view = keys.pub_spend.clone();
+/// assert_eq!(verify_keys_consistency(&keys), Err(KeyError::InconsistentKeys("pub_view")));
+/// ```
+pub fn verify_keys_consistency(keys: &Keys) -> Result<(), KeyError> {
+    if !derive_pub_key(keys.priv_spend.clone())?.eq_ignore_ascii_case(&keys.pub_spend) {
+        return Err(KeyError::InconsistentKeys("pub_spend"));
+    }
+    if !derive_priv_vk_from_priv_sk(keys.priv_spend.clone())?.eq_ignore_ascii_case(&keys.priv_view) {
+        return Err(KeyError::InconsistentKeys("priv_view"));
+    }
+    if !derive_pub_key(keys.priv_view.clone())?.eq_ignore_ascii_case(&keys.pub_view) {
+        return Err(KeyError::InconsistentKeys("pub_view"));
+    }
+    Ok(())
 }
@@ -0,0 +1,166 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+//! # Language
+//!
+//! This module lists the languages of the supported wordsets, so they can be passed around without string codes.
+
+use super::errors::KeyError;
+use super::keys::{find_wordset, normalize_seed_words, wordset_by_name, with_rng};
+use super::polyseed::{detect_polyseed_language, POLYSEED_WORD_COUNT};
+use super::scheme::seed_scheme;
+use crate::mnemonics::polyseed::wordsets::WORDSETSPOLYSEED;
+use std::fmt;
+use std::str::FromStr;
+
+/// Languages of the supported wordsets, see [`Language::supports`] for the seed types each one can be used with
+///
+/// Example:
+/// ```
+/// use libmonero::keys::Language;
+///
+/// let language: Language = "ja".parse().unwrap();
+/// assert_eq!(language, Language::Ja);
+/// assert_eq!(Language::ZhS.as_str(), "zh_s");
+/// assert!(Language::Eo.supports("original"));
+/// assert!(!Language::Eo.supports("polyseed"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    /// Czech
+    Cs,
+    /// English
+    En,
+    /// Esperanto
+    Eo,
+    /// Spanish
+    Es,
+    /// French
+    Fr,
+    /// Italian
+    It,
+    /// Japanese
+    Ja,
+    /// Korean
+    Ko,
+    /// Lojban
+    Lj,
+    /// Portuguese
+    Pt,
+    /// Russian
+    Ru,
+    /// Chinese, simplified
+    ZhS,
+    /// Chinese, traditional
+    ZhT,
+}
+
+impl Language {
+    /// All languages, in the order of their codes
+    pub const ALL: [Language; 13] = [
+        Language::Cs,
+        Language::En,
+        Language::Eo,
+        Language::Es,
+        Language::Fr,
+        Language::It,
+        Language::Ja,
+        Language::Ko,
+        Language::Lj,
+        Language::Pt,
+        Language::Ru,
+        Language::ZhS,
+        Language::ZhT,
+    ];
+
+    /// Returns the code of the language, as taken by the string-based functions like [`generate_seed`](super::generate_seed)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Language::Cs => "cs",
+            Language::En => "en",
+            Language::Eo => "eo",
+            Language::Es => "es",
+            Language::Fr => "fr",
+            Language::It => "it",
+            Language::Ja => "ja",
+            Language::Ko => "ko",
+            Language::Lj => "lj",
+            Language::Pt => "pt",
+            Language::Ru => "ru",
+            Language::ZhS => "zh_s",
+            Language::ZhT => "zh_t",
+        }
+    }
+
+    /// Checks if the language has a wordset for given seed type, `original`, `mymonero` or `polyseed`
+    pub fn supports(&self, seed_type: &str) -> bool {
+        match seed_type {
+            "original" | "mymonero" => wordset_by_name(self.as_str()).is_ok(),
+            "polyseed" => WORDSETSPOLYSEED.iter().any(|wordset| wordset.name == self.as_str()),
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for Language {
+    type Err = KeyError;
+
+    fn from_str(s: &str) -> Result<Language, KeyError> {
+        Language::ALL
+            .iter()
+            .find(|language| language.as_str() == s)
+            .copied()
+            .ok_or_else(|| KeyError::UnknownLanguage(s.to_string()))
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Generates a cryptographically secure mnemonic phrase for given language and seed type
+///
+/// Unlike [`generate_seed`](super::generate_seed), an unknown seed type gives [`KeyError::UnknownSeedType`] and a
+/// language without a wordset for the seed type [`KeyError::UnknownWordset`] instead of panicking.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{generate_seed_in, KeyError, Language};
+///
+/// let mnemonic: Vec<String> = generate_seed_in(Language::Fr, "mymonero").unwrap();
+/// assert_eq!(mnemonic.len(), 13);
+/// assert_eq!(generate_seed_in(Language::Ko, "original"), Err(KeyError::UnknownWordset));
+/// ```
+pub fn generate_seed_in(language: Language, seed_type: &str) -> Result<Vec<String>, KeyError> {
+    let scheme = seed_scheme(seed_type)?;
+    with_rng(|rng| scheme.generate(language.as_str(), rng))
+}
+
+/// Detects the language of given mnemonic seed, of any seed type
+///
+/// Polyseed seeds whose words are in more than one polyseed wordset give [`KeyError::UnknownWordset`].
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{detect_language, Language};
+///
+/// let mnemonic: Vec<String> = vec!["tissue", "raking", "haunted", "huts", "afraid", "volcano", "howls", "liar", "egotistic", "befit", "rounded", "older", "bluntly", "imbalance", "pivot", "exotic", "tuxedo", "amaze", "mostly", "lukewarm", "macro", "vocal", "hounded", "biplane", "rounded"].iter().map(|s| s.to_string()).collect();
+/// assert_eq!(detect_language(&mnemonic), Ok(Language::En));
+/// ```
+pub fn detect_language(mnemonic: &[String]) -> Result<Language, KeyError> {
+    let name = if mnemonic.len() == POLYSEED_WORD_COUNT {
+        detect_polyseed_language(mnemonic).ok_or(KeyError::UnknownWordset)?
+    } else {
+        find_wordset(&normalize_seed_words(mnemonic.to_vec()))?.name
+    };
+    name.parse()
+}
@@ -0,0 +1,161 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+//! # Message
+//!
+//! This module is for signing messages with the private spend or view key of an address and verifying them, to prove
+//! control of the address.
+
+use super::address::{decode_address, encode_varint};
+use super::errors::KeyError;
+use super::keys::decode_hex32;
+use crate::crypt::{cn_fast_hash, hash_to_scalar};
+use curve25519_dalek::{constants::ED25519_BASEPOINT_TABLE, edwards::CompressedEdwardsY, traits::IsIdentity, EdwardsPoint, Scalar};
+use rand::RngCore;
+
+/// Prefix of message signatures over the Keccak-256 hash of the message, the first version of Monero's wallet
+const SIGNATURE_PREFIX_V1: &str = "SigV1";
+
+/// Prefix of message signatures over the message, the address and the signing key, the current version
+const SIGNATURE_PREFIX_V2: &str = "SigV2";
+
+/// Domain separator of `SigV2` message hashes, `config::HASH_KEY_MESSAGE_SIGNING` of Monero with its NUL terminator
+const MESSAGE_SIGNING_DOMAIN: &[u8] = b"MoneroMessageSignature\0";
+
+/// Computes the challenge of a signature, `Hs(message_hash || public_key || commitment)`
+fn challenge(message_hash: &[u8; 32], public_key: &EdwardsPoint, commitment: &EdwardsPoint) -> Scalar {
+    let data = [&message_hash[..], public_key.compress().as_bytes(), commitment.compress().as_bytes()].concat();
+    Scalar::from_bytes_mod_order(hash_to_scalar(&data))
+}
+
+/// Computes the hash signed by `SigV2` signatures, like `get_message_hash` of Monero's wallet: the Keccak-256 hash of
+/// the domain separator, the public spend and view keys of the address, the signing mode (0 for the spend key, 1 for
+/// the view key), the varint length of the message and the message
+fn message_hash_v2(message: &[u8], public_spend_key: &[u8; 32], public_view_key: &[u8; 32], mode: u8) -> [u8; 32] {
+    let data = [
+        MESSAGE_SIGNING_DOMAIN,
+        public_spend_key,
+        public_view_key,
+        &[mode],
+        &encode_varint(message.len() as u64),
+        message,
+    ]
+    .concat();
+    cn_fast_hash(&data)
+}
+
+/// Checks a signature of given hash, made with the private key of given public key
+fn check_signature(message_hash: &[u8; 32], public_key: &[u8; 32], signature: &[u8]) -> bool {
+    let Some(public_key) = CompressedEdwardsY(*public_key).decompress() else {
+        return false;
+    };
+    // Both scalars must be reduced and the challenge nonzero, like Monero's wallet checks them
+    let scalar = |bytes: &[u8]| Option::<Scalar>::from(Scalar::from_canonical_bytes(bytes.try_into().unwrap()));
+    let (Some(c), Some(r)) = (scalar(&signature[..32]), scalar(&signature[32..])) else {
+        return false;
+    };
+    if c == Scalar::ZERO {
+        return false;
+    }
+    let commitment = EdwardsPoint::vartime_double_scalar_mul_basepoint(&c, &public_key, &r);
+    !commitment.is_identity() && challenge(message_hash, &public_key, &commitment) == c
+}
+
+/// Signs given message with the private spend or view key of given address, giving a `SigV2` signature like
+/// `monero-wallet-cli sign` does
+///
+/// The signature is Monero's Schnorr signature over a hash of the message, the public keys of the address and which
+/// of its keys signed, `"SigV2"` followed by the base58 encoding of its challenge and response. It proves control of
+/// the key without revealing it, and is checked with the address, see [`verify_message`]. The key is the one of the
+/// address whose public key matches, else [`KeyError::AddressMismatch`] is returned.
+///
+/// The nonce is `Hs(private_key || Keccak-256(message) || randomness)`, with fresh randomness of the operating system
+/// generator even with the `deterministic-rng` feature, so two signatures never share a nonce. Signing the same
+/// message twice thus gives two different signatures which both verify.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{sign_message, verify_message};
+///
+/// let address: &str = "4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J";
+/// let private_spend_key: &str = "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08";
+/// let signature: String = sign_message(address, private_spend_key, b"I own this address").unwrap();
+/// assert!(signature.starts_with("SigV2"));
+/// assert!(verify_message(address, b"I own this address", &signature));
+/// assert!(!verify_message(address, b"I own another address", &signature));
+/// ```
+pub fn sign_message(address: &str, private_key: &str, message: &[u8]) -> Result<String, KeyError> {
+    let decoded = decode_address(address)?;
+    let public_spend_key = decode_hex32(&decoded.public_spend_key)?;
+    let public_view_key = decode_hex32(&decoded.public_view_key)?;
+    let secret_bytes = decode_hex32(private_key)?;
+    let secret = Scalar::from_bytes_mod_order(secret_bytes);
+    let public_key = &secret * ED25519_BASEPOINT_TABLE;
+    let mode = match public_key.compress().to_bytes() {
+        key if key == public_spend_key => 0,
+        key if key == public_view_key => 1,
+        _ => return Err(KeyError::AddressMismatch),
+    };
+    let message_hash = message_hash_v2(message, &public_spend_key, &public_view_key, mode);
+    let mut randomness = [0u8; 32];
+    rand::thread_rng().fill_bytes(&mut randomness);
+    let nonce_data = [&secret_bytes[..], &cn_fast_hash(message), &randomness].concat();
+    let nonce = Scalar::from_bytes_mod_order(hash_to_scalar(&nonce_data));
+    let c = challenge(&message_hash, &public_key, &(&nonce * ED25519_BASEPOINT_TABLE));
+    let r = nonce - c * secret;
+    let signature = base58_monero::encode(&[c.to_bytes(), r.to_bytes()].concat())
+        .map_err(|error| KeyError::Base58(error.to_string()))?;
+    Ok(format!("{}{}", SIGNATURE_PREFIX_V2, signature))
+}
+
+/// Verifies a signature of given message by given address, see [`sign_message`]
+///
+/// `SigV2` signatures verify when made with either the spend or the view key of the address. `SigV1` signatures, over
+/// the Keccak-256 hash of the message only, are still verified with the public spend key like Monero's wallet does,
+/// but are no longer made. Malformed addresses or signatures, signatures with a zero challenge and signatures of
+/// another version, do not verify.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::verify_message;
+///
+/// let address: &str = "4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J";
+/// let signature: &str = "SigV2Xa8E3uUC8n6djh8cun3QgsfCdmfRGohxiPpSmsnEhzFe2urfLNnJWj5Dyjqf9te1ugQTcynEU3qDXYgs5ZA9hYro";
+/// assert!(verify_message(address, b"Hello, world!", signature));
+/// assert!(!verify_message(address, b"Hello, world", signature));
+/// let signature: &str = "SigV1RP7a84ataBoHj35TeqT3ST3732igZgrMW7cYF56Fd5ypQPny97RMX4yRBbHSVQTTu59wAgmp2DFnTFgsJ7cH4vPy";
+/// assert!(verify_message(address, b"Hello, world!", signature));
+/// ```
+pub fn verify_message(address: &str, message: &[u8], signature: &str) -> bool {
+    let Ok(decoded) = decode_address(address) else {
+        return false;
+    };
+    let keys = (decode_hex32(&decoded.public_spend_key), decode_hex32(&decoded.public_view_key));
+    let (Ok(public_spend_key), Ok(public_view_key)) = keys else {
+        return false;
+    };
+    let decode = |prefix: &str| {
+        signature
+            .strip_prefix(prefix)
+            .and_then(|encoded| base58_monero::decode(encoded).ok())
+            .filter(|bytes| bytes.len() == 64)
+    };
+    if let Some(signature) = decode(SIGNATURE_PREFIX_V1) {
+        return check_signature(&cn_fast_hash(message), &public_spend_key, &signature);
+    }
+    let Some(signature) = decode(SIGNATURE_PREFIX_V2) else {
+        return false;
+    };
+    // Monero's wallet tries the spend key first, then the view key
+    let signers = [(0, public_spend_key), (1, public_view_key)];
+    signers.iter().any(|(mode, public_key)| {
+        check_signature(&message_hash_v2(message, &public_spend_key, &public_view_key, *mode), public_key, &signature)
+    })
+}
@@ -9,5 +9,39 @@
  */
 
 pub(crate) mod keys;
+pub(crate) mod address;
+pub(crate) mod errors;
+pub(crate) mod subaddress;
+pub(crate) mod import;
+pub(crate) mod outputs;
+pub(crate) mod polyseed;
+pub(crate) mod shamir;
+pub(crate) mod scheme;
+pub(crate) mod builder;
+pub(crate) mod language;
+pub(crate) mod restore_height;
+pub(crate) mod batch;
+pub(crate) mod self_test;
+pub(crate) mod bip39;
+pub(crate) mod constant_time;
+pub(crate) mod message;
+pub(crate) mod dice;
 
-pub use keys::*;
\ No newline at end of file
+pub use keys::*;
+pub use address::*;
+pub use errors::*;
+pub use subaddress::*;
+pub use import::*;
+pub use outputs::*;
+pub use polyseed::*;
+pub use shamir::*;
+pub use scheme::*;
+pub use builder::*;
+pub use language::*;
+pub use restore_height::*;
+pub use batch::*;
+pub use self_test::*;
+pub use bip39::*;
+pub use constant_time::*;
+pub use message::*;
+pub use dice::*;
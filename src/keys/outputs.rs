@@ -0,0 +1,199 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+//! # Outputs
+//!
+//! This module is for finding the outputs of transactions that belong to a wallet.
+
+use super::address::encode_varint;
+use super::errors::KeyError;
+use super::keys::decode_hex32;
+use crate::crypt::{cn_fast_hash, hash_to_scalar, keccak_prf, mul8};
+use curve25519_dalek::{constants::ED25519_BASEPOINT_TABLE, edwards::CompressedEdwardsY, EdwardsPoint, Scalar};
+
+/// Domain separator of view tags
+const VIEW_TAG_DOMAIN: &[u8] = b"view_tag";
+/// Byte appended to the key derivation when deriving the payment ID encryption key
+const ENCRYPTED_PAYMENT_ID_TAIL: u8 = 0x8d;
+
+/// Derives the view tag of an output from the key derivation (shared secret) and the index of the output
+///
+/// View tags let wallets skip the full ownership check for most outputs that do not belong to them.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::derive_view_tag;
+///
+/// let shared_secret: Vec<u8> = hex::decode("0fc47054f355ced4d67de73bfa12e4c78ff19089548fffa7d07a674741860f97").unwrap();
+/// assert_eq!(derive_view_tag(&shared_secret, 0), 0x76);
+/// ```
+pub fn derive_view_tag(shared_secret: &[u8], output_index: u64) -> u8 {
+    keccak_prf(VIEW_TAG_DOMAIN, &[shared_secret, &encode_varint(output_index)])[0]
+}
+
+/// Derives the key derivation `8 * a * R` from the transaction public key `R` and the private view key `a`
+///
+/// The result is the shared secret of the sender and the receiver, the sender gets the same point as `8 * r * A`.
+/// Multiplying by the cofactor (8) removes any small-order component of the transaction public key.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::derive_key_derivation;
+///
+/// let tx_pub_key: &str = "10fccee43c0612968c2db809b140abbf1c799fe6158305be1c023509f3ae9a94";
+/// let private_view_key: &str = "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908";
+/// let derivation: String = derive_key_derivation(tx_pub_key, private_view_key).unwrap();
+/// assert_eq!(derivation, "7e78087373ccb7ee318352660e0daf3a24ebaa6af7b0f836b1f56f9e2e3dfa3e".to_string());
+/// ```
+pub fn derive_key_derivation(tx_pub_key: &str, private_view_key: &str) -> Result<String, KeyError> {
+    let tx_pub_point = CompressedEdwardsY(decode_hex32(tx_pub_key)?)
+        .decompress()
+        .ok_or(KeyError::InvalidPoint)?;
+    let view_scalar = Scalar::from_bytes_mod_order(decode_hex32(private_view_key)?);
+    let derivation = mul8(&(view_scalar * tx_pub_point));
+    Ok(hex::encode(derivation.compress().to_bytes()))
+}
+
+/// Derives the scalar `Hs(derivation || output_index)` of an output from the key derivation
+///
+/// Example:
+/// ```
+/// use libmonero::keys::derive_output_scalar;
+///
+/// let derivation: &str = "7e78087373ccb7ee318352660e0daf3a24ebaa6af7b0f836b1f56f9e2e3dfa3e";
+/// let scalar: String = derive_output_scalar(derivation, 0).unwrap();
+/// assert_eq!(scalar, "547a2cf59182f2659bd7ebf727cd1c6ab03ea0a7422eb8c1ab1c830259aecf0f".to_string());
+/// ```
+pub fn derive_output_scalar(derivation: &str, output_index: u64) -> Result<String, KeyError> {
+    let mut data = decode_hex32(derivation)?.to_vec();
+    data.extend(encode_varint(output_index));
+    Ok(hex::encode(hash_to_scalar(&data)))
+}
+
+/// Derives the key encrypting the short (8-byte) payment ID of an integrated address, `Keccak(derivation || 0x8d)[..8]`
+///
+/// The payment ID is encrypted and decrypted by XORing it with the key, the sender and the receiver get the same key
+/// from the same key derivation.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::payment_id_encryption_key;
+///
+/// let derivation: &str = "7e78087373ccb7ee318352660e0daf3a24ebaa6af7b0f836b1f56f9e2e3dfa3e";
+/// let key: [u8; 8] = payment_id_encryption_key(derivation).unwrap();
+/// assert_eq!(hex::encode(key), "25b2d7de9b4b52e9");
+/// ```
+pub fn payment_id_encryption_key(derivation: &str) -> Result<[u8; 8], KeyError> {
+    let mut data = decode_hex32(derivation)?.to_vec();
+    data.push(ENCRYPTED_PAYMENT_ID_TAIL);
+    let mut key = [0u8; 8];
+    key.copy_from_slice(&cn_fast_hash(&data)[..8]);
+    Ok(key)
+}
+
+/// Checks if the output with given public key and index belongs to the wallet, by checking `P == Hs(8aR || i)G + B`
+///
+/// Returns false for malformed keys too.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::is_output_ours;
+///
+/// let tx_pub_key: &str = "10fccee43c0612968c2db809b140abbf1c799fe6158305be1c023509f3ae9a94";
+/// let private_view_key: &str = "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908";
+/// let public_spend_key: &str = "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95";
+/// let output_pub_key: &str = "30660d6645e727e6e6981a2956bc5590e7d2943c8de1f20f6a7fd3f57316e43f";
+/// assert!(is_output_ours(tx_pub_key, private_view_key, public_spend_key, output_pub_key, 0));
+/// assert!(!is_output_ours(tx_pub_key, private_view_key, public_spend_key, output_pub_key, 1));
+/// ```
+pub fn is_output_ours(
+    tx_pub_key: &str,
+    private_view_key: &str,
+    public_spend_key: &str,
+    output_pub_key: &str,
+    output_index: u64,
+) -> bool {
+    scan_outputs(tx_pub_key, private_view_key, public_spend_key, &[(output_pub_key.to_string(), output_index)])[0]
+}
+
+/// Checks which outputs of a transaction, given as `(output public key, index)` pairs, belong to the wallet
+///
+/// The key derivation `8aR` is computed once for the whole transaction instead of once per output as with
+/// [`is_output_ours`], which is most of the cost of scanning. Malformed output keys are not ours, and if the
+/// transaction or wallet keys are malformed no output is.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::scan_outputs;
+///
+/// let tx_pub_key: &str = "10fccee43c0612968c2db809b140abbf1c799fe6158305be1c023509f3ae9a94";
+/// let private_view_key: &str = "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908";
+/// let public_spend_key: &str = "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95";
+/// let outputs: Vec<(String, u64)> = vec![
+///     ("30660d6645e727e6e6981a2956bc5590e7d2943c8de1f20f6a7fd3f57316e43f".to_string(), 0),
+///     ("30660d6645e727e6e6981a2956bc5590e7d2943c8de1f20f6a7fd3f57316e43f".to_string(), 1),
+/// ];
+/// assert_eq!(scan_outputs(tx_pub_key, private_view_key, public_spend_key, &outputs), vec![true, false]);
+/// ```
+pub fn scan_outputs(tx_pub_key: &str, private_view_key: &str, public_spend_key: &str, outputs: &[(String, u64)]) -> Vec<bool> {
+    let keys = || -> Result<([u8; 32], EdwardsPoint), KeyError> {
+        let derivation = decode_hex32(&derive_key_derivation(tx_pub_key, private_view_key)?)?;
+        let spend_point = CompressedEdwardsY(decode_hex32(public_spend_key)?)
+            .decompress()
+            .ok_or(KeyError::InvalidPoint)?;
+        Ok((derivation, spend_point))
+    };
+    let Ok((derivation, spend_point)) = keys() else {
+        return vec![false; outputs.len()];
+    };
+    outputs
+        .iter()
+        .map(|(output_pub_key, output_index)| {
+            let data = [&derivation[..], &encode_varint(*output_index)].concat();
+            let output_scalar = Scalar::from_bytes_mod_order(hash_to_scalar(&data));
+            let expected = (&output_scalar * ED25519_BASEPOINT_TABLE + spend_point).compress().to_bytes();
+            decode_hex32(output_pub_key).is_ok_and(|actual| actual == expected)
+        })
+        .collect()
+}
+
+/// Derives the one-time public key `P = Hs(8rA || i)G + B` of the output at given index paying given recipient
+///
+/// This is the sender side of [`is_output_ours`]: `r` is the transaction secret key, whose public key `R = rG` goes
+/// in the transaction, and `A`, `B` are the public view and spend keys of the recipient's address.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{derive_output_public_key, is_output_ours};
+///
+/// let tx_secret_key: &str = "729e239f0a1b4c74199be9dfa71e1fc2ef35b9bfd4f8013b9fa2795085496b04";
+/// let public_view_key: &str = "157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47";
+/// let public_spend_key: &str = "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95";
+/// let output_pub_key: String = derive_output_public_key(tx_secret_key, public_view_key, public_spend_key, 0).unwrap();
+/// assert_eq!(output_pub_key, "d5b8cc74a28af0ec8e4d63c56a95ec2b7fbdd922a5cee4a241a8bdf89cc76ba3".to_string());
+/// // The recipient finds it with the transaction public key R = rG
+/// let tx_pub_key: &str = "09de961b02af3df2f894231e61d3bab3e6ccd20fe9b07bdd3ff34ea7617d561f";
+/// let private_view_key: &str = "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908";
+/// assert!(is_output_ours(tx_pub_key, private_view_key, public_spend_key, &output_pub_key, 0));
+/// ```
+pub fn derive_output_public_key(
+    tx_secret_key: &str,
+    recipient_view_pub: &str,
+    recipient_spend_pub: &str,
+    output_index: u64,
+) -> Result<String, KeyError> {
+    // 8rA is the same point as the 8aR the recipient computes
+    let derivation = derive_key_derivation(recipient_view_pub, tx_secret_key)?;
+    let output_scalar = Scalar::from_bytes_mod_order(decode_hex32(&derive_output_scalar(&derivation, output_index)?)?);
+    let spend_point = CompressedEdwardsY(decode_hex32(recipient_spend_pub)?)
+        .decompress()
+        .ok_or(KeyError::InvalidPoint)?;
+    Ok(hex::encode((&output_scalar * ED25519_BASEPOINT_TABLE + spend_point).compress().to_bytes()))
+}
@@ -0,0 +1,567 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+//! # Polyseed
+//!
+//! This module is for polyseed (16-word) seeds, see <https://github.com/tevador/polyseed>.
+
+use super::errors::KeyError;
+use crate::mnemonics::polyseed::wordsets::{WordsetPolyseed, WORDSETSPOLYSEED};
+use pbkdf2::pbkdf2_hmac;
+use rand::RngCore;
+use sha2::Sha256;
+use std::time::{SystemTime, UNIX_EPOCH};
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+/// Number of words in a polyseed seed
+pub const POLYSEED_WORD_COUNT: usize = 16;
+/// Word counts of the polyseed seeds accepted for Monero
+///
+/// The spec only defines 16-word seeds. The encoding works with any word count (one checksum word, then 10 secret
+/// bits and a feature or birthday bit per word), so forks with shorter seeds only need another count here.
+pub const POLYSEED_WORD_COUNTS: [usize; 1] = [POLYSEED_WORD_COUNT];
+/// Number of secret bits in each data word, the 11th bit is a feature or birthday bit
+const SECRET_BITS_PER_WORD: usize = 10;
+/// Number of birthday bits, the feature bits come before them
+const DATE_BITS: u16 = 10;
+/// The epoch of polyseed birthdays, 1st November 2021 12:00 UTC
+const POLYSEED_EPOCH: u64 = 1635768000;
+/// The time step of polyseed birthdays, 1/12 of the Gregorian year
+const TIME_STEP: u64 = 2629746;
+/// Salt prefix and number of iterations of the PBKDF2-HMAC-SHA256 key derivation
+const KEYGEN_SALT: &[u8] = b"POLYSEED key";
+const KEYGEN_ITERATIONS: u32 = 10000;
+/// Salt prefix of the PBKDF2-HMAC-SHA256 derivation of the mask of encrypted seeds, with the same number of iterations
+const MASK_SALT: &[u8] = b"POLYSEED mask";
+
+static POLYSEED_MUL2_TABLE: [u16; 8] = [5, 7, 1, 3, 13, 15, 9, 11];
+
+fn gf_elem_mul2(x: u16) -> u16 {
+    if x < 1024 {
+        return 2 * x;
+    }
+    POLYSEED_MUL2_TABLE[x as usize % 8] + 16 * ((x - 1024) / 8)
+}
+
+/// Evaluates the polynomial of given coefficients, of any degree, at x = 2
+pub(crate) fn gf_poly_eval(coeff: &[u16]) -> u16 {
+    // Horner's method
+    coeff
+        .iter()
+        .rev()
+        .fold(0, |result, &c| gf_elem_mul2(result) ^ c)
+}
+
+/// Checks given word count is one of [`POLYSEED_WORD_COUNTS`]
+pub(crate) fn check_polyseed_word_count(word_count: usize) -> Result<(), KeyError> {
+    if !POLYSEED_WORD_COUNTS.contains(&word_count) {
+        return Err(KeyError::WrongWordCount {
+            got: word_count,
+            expected: &POLYSEED_WORD_COUNTS,
+        });
+    }
+    Ok(())
+}
+
+/// Number of secret bits of a seed of given word count, 150 for 16 words
+fn secret_bit_count(word_count: usize) -> usize {
+    (word_count - 1) * SECRET_BITS_PER_WORD
+}
+
+/// PolyseedData contains everything stored in a polyseed seed
+pub(crate) struct PolyseedData {
+    /// 150 secret bits for 16 words, the last byte only has its 6 low bits set
+    pub secret: [u8; 19],
+    /// Months since the polyseed epoch
+    pub birthday: u16,
+    pub features: u8,
+}
+
+/// Normalizes given word the way polyseed compares words of the given wordset
+///
+/// Words are decomposed (NFKD), accents are removed for wordsets that ignore them and only the prefix is kept for
+/// wordsets that identify words by their prefix.
+fn polyseed_word_key(wordset: &WordsetPolyseed, word: &str) -> String {
+    let chars = word
+        .nfkd()
+        .filter(|c| !(wordset.has_accents && is_combining_mark(*c)));
+    match wordset.prefix_len {
+        0 => chars.collect(),
+        prefix_len => chars.take(prefix_len).collect(),
+    }
+}
+
+/// Finds the index of given word in the polyseed wordset
+pub(crate) fn polyseed_find_word(wordset: &WordsetPolyseed, word: &str) -> Option<usize> {
+    let key = polyseed_word_key(wordset, word);
+    wordset
+        .words
+        .iter()
+        .position(|known| polyseed_word_key(wordset, known) == key)
+}
+
+/// Detects the language of a polyseed (16-word) seed, if it is one
+///
+/// Returns `None` if the seed does not have 16 words, if no polyseed wordset contains all of its words or if more than
+/// one does (e.g. English and French share some words), so a restore flow can route the seed to the right decoder.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::detect_polyseed_language;
+///
+/// let mnemonic: Vec<String> = "raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language".split(' ').map(|s| s.to_string()).collect();
+/// assert_eq!(detect_polyseed_language(&mnemonic), Some("en"));
+/// ```
+pub fn detect_polyseed_language(mnemonic: &[String]) -> Option<&'static str> {
+    if !POLYSEED_WORD_COUNTS.contains(&mnemonic.len()) {
+        return None;
+    }
+    let mut matching = WORDSETSPOLYSEED.iter().filter(|wordset| {
+        mnemonic
+            .iter()
+            .all(|word| polyseed_find_word(wordset, word).is_some())
+    });
+    match (matching.next(), matching.next()) {
+        (Some(wordset), None) => Some(wordset.name),
+        _ => None,
+    }
+}
+
+/// Turns the words of a polyseed seed into the coefficients of its polynomial, checking its checksum and that none of
+/// its reserved feature bits is set
+///
+/// The coin is Monero, which leaves the polynomial as it is.
+pub(crate) fn polyseed_to_poly(mnemonic: &[String]) -> Result<Vec<u16>, KeyError> {
+    polyseed_decode(mnemonic).map(|(_, coeff)| coeff)
+}
+
+/// Turns the words of a polyseed seed into the coefficients of its polynomial like `polyseed_to_poly`, also giving
+/// the wordset of the words
+fn polyseed_decode(mnemonic: &[String]) -> Result<(&'static WordsetPolyseed, Vec<u16>), KeyError> {
+    check_polyseed_word_count(mnemonic.len())?;
+    // Wordsets may share words, so every wordset containing all of the words is tried
+    let mut found_wordset = false;
+    for wordset in WORDSETSPOLYSEED.iter() {
+        let indexes: Option<Vec<usize>> = mnemonic
+            .iter()
+            .map(|word| polyseed_find_word(wordset, word))
+            .collect();
+        if let Some(indexes) = indexes {
+            found_wordset = true;
+            let coeff: Vec<u16> = indexes.into_iter().map(|index| index as u16).collect();
+            if gf_poly_eval(&coeff) == 0 {
+                debug!("decoded polyseed seed of {} wordset", wordset.name);
+                // Like the reference implementation, seeds with reserved feature bits are refused
+                let features = poly_to_data(&coeff).features;
+                if features & !(ENCRYPTED_FEATURE | ((1 << USER_FEATURE_BITS) - 1)) != 0 {
+                    return Err(KeyError::UnsupportedPolyseedFeatures(features));
+                }
+                return Ok((wordset, coeff));
+            }
+        }
+    }
+    if found_wordset {
+        return Err(KeyError::InvalidSeedChecksum);
+    }
+    Err(polyseed_word_error(mnemonic))
+}
+
+/// Checks if the first word of given polyseed (16-word) seed is the checksum of its other 15 words
+///
+/// This is a quick check for restore forms, before decoding the seed. Seeds of another word count, with words that
+/// are not all in one polyseed wordset or with reserved feature bits set are not valid.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::polyseed_checksum_valid;
+///
+/// let mut mnemonic: Vec<String> = "raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language".split(' ').map(|s| s.to_string()).collect();
+/// assert!(polyseed_checksum_valid(&mnemonic));
+/// mnemonic[0] = "tail".to_string();
+/// assert!(!polyseed_checksum_valid(&mnemonic));
+/// ```
+pub fn polyseed_checksum_valid(mnemonic: &[String]) -> bool {
+    // With the right checksum as first coefficient the whole polynomial evaluates to zero
+    polyseed_to_poly(mnemonic).is_ok()
+}
+
+/// Returns the error for a polyseed seed whose words are not all in one wordset
+///
+/// Reports the first word missing from the wordset containing the most of the words.
+pub(crate) fn polyseed_word_error(mnemonic: &[String]) -> KeyError {
+    let found_in = |wordset: &WordsetPolyseed| -> Vec<bool> {
+        mnemonic
+            .iter()
+            .map(|word| polyseed_find_word(wordset, word).is_some())
+            .collect()
+    };
+    let found = WORDSETSPOLYSEED
+        .iter()
+        .map(found_in)
+        .max_by_key(|found| found.iter().filter(|&&x| x).count())
+        .unwrap_or_default();
+    if !found.contains(&true) {
+        return KeyError::UnknownWordset;
+    }
+    match found.iter().position(|&x| !x) {
+        Some(position) => KeyError::InvalidWord {
+            position,
+            word: mnemonic[position].clone(),
+        },
+        None => KeyError::UnknownWordset,
+    }
+}
+
+/// Extracts the secret, birthday and features from the coefficients of a polyseed polynomial
+pub(crate) fn poly_to_data(coeff: &[u16]) -> PolyseedData {
+    let mut data = PolyseedData {
+        secret: [0u8; 19],
+        birthday: 0,
+        features: 0,
+    };
+    let mut extra_val: u16 = 0;
+    let mut secret_idx: usize = 0;
+    let mut secret_bits: usize = 0;
+    // The first coefficient is the checksum
+    for &word in coeff.iter().skip(1) {
+        // The lowest bit of each data word is a feature or birthday bit, most significant first
+        extra_val = (extra_val << 1) | (word & 1);
+        let word_val = word >> 1;
+        let mut word_bits = SECRET_BITS_PER_WORD;
+        // The other 10 bits are appended to the secret, most significant first
+        while word_bits > 0 {
+            if secret_bits == 8 {
+                secret_idx += 1;
+                secret_bits = 0;
+            }
+            let chunk_bits = word_bits.min(8 - secret_bits);
+            word_bits -= chunk_bits;
+            let chunk = ((word_val >> word_bits) & ((1 << chunk_bits) - 1)) as u8;
+            // A whole byte chunk starts an empty byte, shifting it by 8 would overflow
+            if chunk_bits < 8 {
+                data.secret[secret_idx] <<= chunk_bits;
+            }
+            data.secret[secret_idx] |= chunk;
+            secret_bits += chunk_bits;
+        }
+    }
+    data.birthday = extra_val & ((1 << DATE_BITS) - 1);
+    data.features = (extra_val >> DATE_BITS) as u8;
+    data
+}
+
+/// Turns the secret, birthday and features into the coefficients of a polyseed polynomial of given word count,
+/// checksum included
+pub(crate) fn data_to_poly(data: &PolyseedData, word_count: usize) -> Vec<u16> {
+    let mut coeff = vec![0u16; word_count];
+    let extra_val = ((data.features as u16) << DATE_BITS) | data.birthday;
+    let mut extra_bits = coeff.len() - 1;
+    let mut secret_idx: usize = 0;
+    let mut secret_bits: usize = 8;
+    let mut remaining_bits: usize = secret_bit_count(word_count) - 8;
+    // The first coefficient is the checksum
+    for c in coeff.iter_mut().skip(1) {
+        let mut word_bits: usize = 0;
+        let mut word_val: u16 = 0;
+        // 10 bits of the secret, most significant first
+        while word_bits < SECRET_BITS_PER_WORD {
+            if secret_bits == 0 {
+                secret_idx += 1;
+                secret_bits = remaining_bits.min(8);
+                remaining_bits -= secret_bits;
+            }
+            let chunk_bits = secret_bits.min(SECRET_BITS_PER_WORD - word_bits);
+            secret_bits -= chunk_bits;
+            word_bits += chunk_bits;
+            let chunk = (data.secret[secret_idx] as u16 >> secret_bits) & ((1 << chunk_bits) - 1);
+            word_val = (word_val << chunk_bits) | chunk;
+        }
+        // Then a feature or birthday bit, most significant first
+        extra_bits -= 1;
+        *c = (word_val << 1) | ((extra_val >> extra_bits) & 1);
+    }
+    coeff[0] = gf_poly_eval(&coeff);
+    coeff
+}
+
+/// Encodes given unix timestamp into a polyseed birthday, the number of time steps since the polyseed epoch
+pub(crate) fn birthday_encode(timestamp: u64) -> u16 {
+    (timestamp.saturating_sub(POLYSEED_EPOCH) / TIME_STEP % (1 << DATE_BITS)) as u16
+}
+
+/// Decodes given polyseed birthday into the unix timestamp of the start of its time step
+pub(crate) fn birthday_decode(birthday: u16) -> u64 {
+    POLYSEED_EPOCH + u64::from(birthday) * TIME_STEP
+}
+
+/// Derives the 32-byte key of a polyseed seed with PBKDF2-HMAC-SHA256, salted with its coin, birthday and features
+///
+/// Monero's wallet uses the key like the hex seed of an original seed.
+pub(crate) fn polyseed_keygen(data: &PolyseedData) -> [u8; 32] {
+    let mut salt = [0u8; 32];
+    salt[..KEYGEN_SALT.len()].copy_from_slice(KEYGEN_SALT);
+    salt[13..16].fill(0xff);
+    // The coin, 0 for Monero, is left as it is in bytes 16 to 20
+    salt[20..24].copy_from_slice(&(data.birthday as u32).to_le_bytes());
+    salt[24..28].copy_from_slice(&(data.features as u32).to_le_bytes());
+    let mut secret = [0u8; 32];
+    secret[..data.secret.len()].copy_from_slice(&data.secret);
+    let mut key = [0u8; 32];
+    pbkdf2_hmac::<Sha256>(&secret, &salt, KEYGEN_ITERATIONS, &mut key);
+    key
+}
+
+/// Number of feature bits users can set, the other feature bits are reserved (e.g. for encryption)
+pub(crate) const USER_FEATURE_BITS: u8 = 3;
+/// The feature bit of encrypted seeds
+pub(crate) const ENCRYPTED_FEATURE: u8 = 16;
+
+/// Returns the current unix timestamp, the birthday of seeds generated now
+pub(crate) fn now_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+/// Generates a polyseed seed of given language, word count, birthday (unix timestamp) and user features with given
+/// random number generator
+pub(crate) fn generate_polyseed(language: &str, word_count: usize, timestamp: u64, features: u8, rng: &mut dyn RngCore) -> Result<Vec<String>, KeyError> {
+    let words = generate_polyseed_words(language, word_count, timestamp, features, rng)?;
+    Ok(show_polyseed_words(language, words))
+}
+
+/// Encodes given secret into a polyseed seed like [`generate_polyseed`], the bits past the secret bits are cleared
+pub(crate) fn polyseed_from_secret(language: &str, word_count: usize, timestamp: u64, features: u8, secret: [u8; 19]) -> Result<Vec<String>, KeyError> {
+    let words = polyseed_words_from_secret(language, word_count, timestamp, features, secret)?;
+    Ok(show_polyseed_words(language, words))
+}
+
+/// Turns the stored (decomposed) words of a polyseed seed into the words shown, composed for the wordsets shown so
+fn show_polyseed_words(language: &str, words: Vec<&'static str>) -> Vec<String> {
+    let compose = WORDSETSPOLYSEED
+        .iter()
+        .any(|wordset| wordset.name == language && wordset.compose);
+    words
+        .into_iter()
+        .map(|word| if compose { word.nfc().collect() } else { word.to_string() })
+        .collect()
+}
+
+/// Generates a polyseed seed like [`generate_polyseed`], giving the words as they are stored in the wordset
+/// (decomposed, NFKD) instead of as they are shown
+pub(crate) fn generate_polyseed_words(language: &str, word_count: usize, timestamp: u64, features: u8, rng: &mut dyn RngCore) -> Result<Vec<&'static str>, KeyError> {
+    let mut secret = [0u8; 19];
+    rng.fill_bytes(&mut secret);
+    polyseed_words_from_secret(language, word_count, timestamp, features, secret)
+}
+
+/// Clears the bits of given secret past the secret bits of a seed of given word count
+fn clear_unused_secret_bits(secret: &mut [u8; 19], word_count: usize) {
+    let bits = secret_bit_count(word_count);
+    let secret_len = bits.div_ceil(8);
+    secret[secret_len..].fill(0);
+    if !bits.is_multiple_of(8) {
+        secret[secret_len - 1] &= (1 << (bits % 8)) - 1;
+    }
+}
+
+/// Encodes given secret into the stored words of a polyseed seed
+fn polyseed_words_from_secret(language: &str, word_count: usize, timestamp: u64, features: u8, secret: [u8; 19]) -> Result<Vec<&'static str>, KeyError> {
+    check_polyseed_word_count(word_count)?;
+    let wordset = WORDSETSPOLYSEED
+        .iter()
+        .find(|wordset| wordset.name == language)
+        .ok_or(KeyError::UnknownWordset)?;
+    if features >= 1 << USER_FEATURE_BITS {
+        return Err(KeyError::InvalidSeedOption("features"));
+    }
+    let mut data = PolyseedData {
+        secret,
+        birthday: birthday_encode(timestamp),
+        features,
+    };
+    clear_unused_secret_bits(&mut data.secret, word_count);
+    let seed = data_to_poly(&data, word_count)
+        .iter()
+        .map(|&index| wordset.words[index as usize])
+        .collect();
+    Ok(seed)
+}
+
+/// Extracts the raw 19-byte secret (150 bits, the last byte only has its 6 low bits set) from a polyseed seed
+///
+/// The secret is independent of key derivation, so it lets two wallets check they decode the same polyseed.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::polyseed_entropy;
+///
+/// let mnemonic: Vec<String> = "raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language".split(' ').map(|s| s.to_string()).collect();
+/// let secret: [u8; 19] = polyseed_entropy(&mnemonic).unwrap();
+/// assert_eq!(hex::encode(secret), "dd76e7359a0ded37cd0ff0f3c829a5ae016733");
+/// ```
+pub fn polyseed_entropy(mnemonic: &[String]) -> Result<[u8; 19], KeyError> {
+    let coeff = polyseed_to_poly(mnemonic)?;
+    Ok(poly_to_data(&coeff).secret)
+}
+
+/// Checks if given polyseed seed is encrypted with a passphrase, see [`encrypt_polyseed`]
+///
+/// Wallets restoring a polyseed seed should ask for the passphrase of encrypted seeds, the keys of an encrypted seed are
+/// not the keys of the wallet.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::is_polyseed_encrypted;
+///
+/// let mnemonic: Vec<String> = "raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language".split(' ').map(|s| s.to_string()).collect();
+/// assert!(!is_polyseed_encrypted(&mnemonic).unwrap());
+/// ```
+pub fn is_polyseed_encrypted(mnemonic: &[String]) -> Result<bool, KeyError> {
+    let coeff = polyseed_to_poly(mnemonic)?;
+    Ok(poly_to_data(&coeff).features & ENCRYPTED_FEATURE != 0)
+}
+
+/// Encrypts or decrypts the secret of given seed data with given passphrase and toggles its encrypted feature bit
+///
+/// The secret is XORed with a mask derived from the passphrase, so encrypting twice with the same passphrase decrypts.
+fn polyseed_crypt(data: &mut PolyseedData, passphrase: &str, word_count: usize) {
+    let password: String = passphrase.nfkd().collect();
+    let mut salt = [0u8; 32];
+    salt[..MASK_SALT.len()].copy_from_slice(MASK_SALT);
+    salt[14..16].fill(0xff);
+    let mut mask = [0u8; 32];
+    pbkdf2_hmac::<Sha256>(password.as_bytes(), &salt, KEYGEN_ITERATIONS, &mut mask);
+    for (byte, mask_byte) in data.secret.iter_mut().zip(mask) {
+        *byte ^= mask_byte;
+    }
+    clear_unused_secret_bits(&mut data.secret, word_count);
+    data.features ^= ENCRYPTED_FEATURE;
+}
+
+/// Encrypts or decrypts given polyseed seed, which must be encrypted or not as given
+fn polyseed_crypt_words(mnemonic: &[String], passphrase: &str, encrypted: bool) -> Result<Vec<String>, KeyError> {
+    let (wordset, coeff) = polyseed_decode(mnemonic)?;
+    let mut data = poly_to_data(&coeff);
+    match (data.features & ENCRYPTED_FEATURE != 0, encrypted) {
+        (true, false) => return Err(KeyError::EncryptedPolyseed),
+        (false, true) => return Err(KeyError::UnencryptedPolyseed),
+        _ => {}
+    }
+    polyseed_crypt(&mut data, passphrase, mnemonic.len());
+    let words = data_to_poly(&data, mnemonic.len())
+        .iter()
+        .map(|&index| wordset.words[index as usize])
+        .collect();
+    Ok(show_polyseed_words(wordset.name, words))
+}
+
+/// Encrypts given polyseed seed with given passphrase, giving a seed of the same language, birthday and features with
+/// the encrypted feature bit set
+///
+/// This is the encryption of the polyseed reference implementation: the secret is XORed with a mask derived from the
+/// passphrase (NFKD normalized) with PBKDF2-HMAC-SHA256, 10000 iterations. The encrypted seed is still a valid polyseed
+/// seed, [`decrypt_polyseed`] with the same passphrase gives back the original seed. Any passphrase decrypts a seed, a
+/// wrong one gives the seed of another wallet. Encrypting an encrypted seed fails with
+/// [`KeyError::EncryptedPolyseed`].
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{decrypt_polyseed, encrypt_polyseed, is_polyseed_encrypted};
+///
+/// let mnemonic: Vec<String> = "raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language".split(' ').map(|s| s.to_string()).collect();
+/// let encrypted: Vec<String> = encrypt_polyseed(&mnemonic, "correct horse battery staple").unwrap();
+/// assert!(is_polyseed_encrypted(&encrypted).unwrap());
+/// assert_eq!(decrypt_polyseed(&encrypted, "correct horse battery staple").unwrap(), mnemonic);
+/// ```
+pub fn encrypt_polyseed(mnemonic: &[String], passphrase: &str) -> Result<Vec<String>, KeyError> {
+    polyseed_crypt_words(mnemonic, passphrase, false)
+}
+
+/// Decrypts given polyseed seed encrypted with [`encrypt_polyseed`] with given passphrase, giving back the original
+/// seed
+///
+/// Decrypting a seed that is not encrypted fails with [`KeyError::UnencryptedPolyseed`].
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{decrypt_polyseed, encrypt_polyseed, KeyError};
+///
+/// let mnemonic: Vec<String> = "raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language".split(' ').map(|s| s.to_string()).collect();
+/// let encrypted: Vec<String> = encrypt_polyseed(&mnemonic, "passphrase").unwrap();
+/// assert_eq!(decrypt_polyseed(&encrypted, "passphrase").unwrap(), mnemonic);
+/// assert_eq!(decrypt_polyseed(&mnemonic, "passphrase"), Err(KeyError::UnencryptedPolyseed));
+/// ```
+pub fn decrypt_polyseed(mnemonic: &[String], passphrase: &str) -> Result<Vec<String>, KeyError> {
+    polyseed_crypt_words(mnemonic, passphrase, true)
+}
+
+/// Decodes the birthday of given polyseed seed into a unix timestamp, the start of the time step (1/12 of a year) the
+/// seed was generated in
+///
+/// The 10 birthday bits are the lowest bits of the 15 data words, most significant first, after the feature bits. A
+/// seed generated at a given time gives back that time, rounded down to the start of its time step, so a restore
+/// height estimated from it (e.g. with [`estimate_restore_height`](super::estimate_restore_height)) is at most a time
+/// step before the wallet was created. Birthdays wrap around after 1024 time steps, about 85 years.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::polyseed_birthday;
+///
+/// // Generated on 2 December 2021
+/// let mnemonic: Vec<String> = "raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language".split(' ').map(|s| s.to_string()).collect();
+/// assert_eq!(polyseed_birthday(&mnemonic).unwrap(), 1638397746);
+/// ```
+pub fn polyseed_birthday(mnemonic: &[String]) -> Result<u64, KeyError> {
+    let coeff = polyseed_to_poly(mnemonic)?;
+    Ok(birthday_decode(poly_to_data(&coeff).birthday))
+}
+
+/// Joins the words of a polyseed seed of given language into the phrase shown to users
+///
+/// Words are separated the way polyseed shows them: with a space, or an ideographic space (U+3000) for Japanese. An
+/// unknown language gives [`KeyError::UnknownWordset`]. See [`split_polyseed_phrase`] for the other way around.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{generate_seed, join_polyseed_words};
+///
+/// let mnemonic: Vec<String> = generate_seed("ja", "polyseed");
+/// assert_eq!(join_polyseed_words(&mnemonic, "ja").unwrap(), mnemonic.join("\u{3000}"));
+/// assert_eq!(join_polyseed_words(&mnemonic[..2], "en").unwrap(), mnemonic[..2].join(" "));
+/// ```
+pub fn join_polyseed_words(mnemonic: &[String], language: &str) -> Result<String, KeyError> {
+    let wordset = WORDSETSPOLYSEED
+        .iter()
+        .find(|wordset| wordset.name == language)
+        .ok_or(KeyError::UnknownWordset)?;
+    Ok(mnemonic.join(wordset.separator))
+}
+
+/// Splits a polyseed phrase, as typed or pasted by users, into its words
+///
+/// Words may be separated by the separator of any polyseed language (e.g. the ideographic space of Japanese phrases)
+/// or by any whitespace, repeated separators are ignored. The words are not checked, see
+/// [`polyseed_checksum_valid`].
+///
+/// Example:
+/// ```
+/// use libmonero::keys::split_polyseed_phrase;
+///
+/// assert_eq!(split_polyseed_phrase("あいこくしん\u{3000}あいさつ"), vec!["あいこくしん".to_string(), "あいさつ".to_string()]);
+/// assert_eq!(split_polyseed_phrase(" raven  tail\n"), vec!["raven".to_string(), "tail".to_string()]);
+/// ```
+pub fn split_polyseed_phrase(phrase: &str) -> Vec<String> {
+    phrase
+        .split(|c: char| c.is_whitespace() || WORDSETSPOLYSEED.iter().any(|wordset| wordset.separator.contains(c)))
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_string())
+        .collect()
+}
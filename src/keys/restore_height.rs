@@ -0,0 +1,131 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+//! # Restore height
+//!
+//! This module is for estimating the block height a wallet has to be restored from, from the time it was created.
+
+use super::errors::KeyError;
+
+/// Known `(height, unix timestamp)` pairs of mainnet blocks: the genesis block, the v2 hard fork, when the block time
+/// went from 1 to 2 minutes, and the v7, v10, v12 and v15 hard forks
+///
+/// The timestamps of the genesis block and the v2 fork are the ones Monero's wallet uses. Those of the later forks are
+/// noon UTC of the day the fork block was mined, within half a day (360 blocks) of the block's own timestamp.
+pub const MAINNET_HEIGHT_ANCHORS: [(u64, u64); 6] = [
+    (0, 1397818193),
+    (1009827, 1458748658),
+    // 6 April 2018
+    (1546000, 1523016000),
+    // 9 March 2019
+    (1788000, 1552132800),
+    // 30 November 2019
+    (1978433, 1575115200),
+    // 13 August 2022
+    (2688888, 1660392000),
+];
+/// Block time in seconds since the v2 hard fork, assumed past the last anchor
+const BLOCK_TIME: u64 = 120;
+
+/// RestoreHeightEstimator estimates block heights from timestamps by interpolating between known blocks (anchors)
+///
+/// Between two anchors the block time is the average one between them, past the last anchor it is 2 minutes and
+/// before the first one the height is the one of the first anchor. The more anchors, the closer the estimate gets to
+/// the real height around forks and periods of unusual block times.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{estimate_restore_height, RestoreHeightEstimator};
+///
+/// assert_eq!(RestoreHeightEstimator::new().estimate(1704067200), estimate_restore_height(1704067200));
+/// // A chain with a block every minute for its first 1000 blocks, and 2 minutes since
+/// let estimator = RestoreHeightEstimator::empty().add_anchor(0, 1600000000).unwrap().add_anchor(1000, 1600060000).unwrap();
+/// assert_eq!(estimator.estimate(1600030000), 500);
+/// assert_eq!(estimator.estimate(1600060000 + 1200), 1010);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RestoreHeightEstimator {
+    /// Sorted by height, timestamps increase with heights
+    anchors: Vec<(u64, u64)>,
+}
+
+impl Default for RestoreHeightEstimator {
+    fn default() -> RestoreHeightEstimator {
+        RestoreHeightEstimator::new()
+    }
+}
+
+impl RestoreHeightEstimator {
+    /// Creates an estimator for mainnet, with the anchors of [`MAINNET_HEIGHT_ANCHORS`]
+    pub fn new() -> RestoreHeightEstimator {
+        RestoreHeightEstimator {
+            anchors: MAINNET_HEIGHT_ANCHORS.to_vec(),
+        }
+    }
+
+    /// Creates an estimator without anchors, for other chains, which estimates height 0 until anchors are added
+    pub fn empty() -> RestoreHeightEstimator {
+        RestoreHeightEstimator { anchors: Vec::new() }
+    }
+
+    /// Adds the block at given height with given unix timestamp as anchor
+    ///
+    /// Anchors must not go back in time: a block cannot be older than a lower block nor newer than a higher one, else
+    /// [`KeyError::InvalidAnchor`] is given. Adding an anchor of an existing height replaces it.
+    pub fn add_anchor(mut self, height: u64, timestamp: u64) -> Result<RestoreHeightEstimator, KeyError> {
+        self.anchors.retain(|&(h, _)| h != height);
+        let index = self.anchors.partition_point(|&(h, _)| h < height);
+        let after_previous = index == 0 || self.anchors[index - 1].1 <= timestamp;
+        let before_next = index == self.anchors.len() || timestamp <= self.anchors[index].1;
+        if !after_previous || !before_next {
+            return Err(KeyError::InvalidAnchor { height, timestamp });
+        }
+        self.anchors.insert(index, (height, timestamp));
+        Ok(self)
+    }
+
+    /// Estimates the block height at given unix timestamp
+    pub fn estimate(&self, timestamp: u64) -> u64 {
+        let next = self.anchors.partition_point(|&(_, t)| t <= timestamp);
+        match (next.checked_sub(1).map(|i| self.anchors[i]), self.anchors.get(next)) {
+            (None, None) => 0,
+            // Before the first anchor
+            (None, Some(&(height, _))) => height,
+            // Past the last anchor
+            (Some((height, t)), None) => height + (timestamp - t) / BLOCK_TIME,
+            (Some((height, t)), Some(&(next_height, next_t))) => {
+                let blocks = u128::from(next_height - height) * u128::from(timestamp - t) / u128::from(next_t - t);
+                height + blocks as u64
+            }
+        }
+    }
+}
+
+/// Estimates the mainnet block height at the given unix timestamp, to be used as restore height of a wallet
+///
+/// Original seeds have no birthday, so the creation time of the wallet has to come from the user. Polyseed birthdays,
+/// decoded with [`polyseed_birthday`](super::polyseed_birthday), are timestamps too and can be passed as they are.
+///
+/// The estimate interpolates between the blocks of [`MAINNET_HEIGHT_ANCHORS`] and assumes blocks come every 120
+/// seconds after the last one. Real block times vary, so the estimate is typically off by up to a few thousand
+/// blocks. Subtract a margin (e.g. a week, 5040 blocks) to be sure not to miss any transaction, or add anchors close to
+/// the timestamp with [`RestoreHeightEstimator`].
+///
+/// Example:
+/// ```
+/// use libmonero::keys::estimate_restore_height;
+///
+/// // 2024-01-01 00:00:00 UTC
+/// let height: u64 = estimate_restore_height(1704067200);
+/// assert!(height > 3_050_000 && height < 3_060_000);
+/// ```
+pub fn estimate_restore_height(creation_timestamp: u64) -> u64 {
+    RestoreHeightEstimator::new().estimate(creation_timestamp)
+}
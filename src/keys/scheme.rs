@@ -0,0 +1,176 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+//! # Seed schemes
+//!
+//! This module abstracts over the kinds of mnemonic seeds. Each scheme has its own way of turning random bits into
+//! words, words into a hex seed and a hex seed into private keys, and the free functions like `generate_seed` delegate
+//! to them. Other schemes can be added by implementing [`SeedScheme`].
+
+use super::errors::KeyError;
+use super::keys::{derive_hex_seed, derive_priv_keys, generate_1626_seed, generate_1626_words};
+use super::polyseed::{generate_polyseed, generate_polyseed_words, now_timestamp, poly_to_data, polyseed_keygen, polyseed_to_poly, ENCRYPTED_FEATURE, POLYSEED_WORD_COUNT};
+use rand::RngCore;
+
+/// SeedScheme is a kind of mnemonic seed, from generating it to deriving its private keys
+pub trait SeedScheme {
+    /// Generates a new mnemonic seed of given language with given random number generator
+    fn generate(&self, language: &str, rng: &mut dyn RngCore) -> Result<Vec<String>, KeyError>;
+    /// Decodes given mnemonic seed into the hex seed its keys are derived from
+    fn to_hex_seed(&self, words: &[String]) -> Result<String, KeyError>;
+    /// Derives the private spend key and private view key from given hex seed
+    fn derive_keys(&self, hex_seed: &str) -> Result<(String, String), KeyError>;
+}
+
+/// BuiltinSeedScheme is a seed scheme of this library, whose words are those of a wordset
+pub(crate) trait BuiltinSeedScheme: SeedScheme {
+    /// Generates a new mnemonic seed like [`SeedScheme::generate`], giving the words as stored in the wordset
+    fn generate_words(&self, language: &str, rng: &mut dyn RngCore) -> Result<Vec<&'static str>, KeyError>;
+}
+
+/// Finds the seed scheme of given seed type, `original`, `mymonero` or `polyseed`
+pub(crate) fn seed_scheme(seed_type: &str) -> Result<&'static dyn BuiltinSeedScheme, KeyError> {
+    match seed_type {
+        "original" => Ok(&OriginalSeed),
+        "mymonero" => Ok(&MyMoneroSeed),
+        "polyseed" => Ok(&PolyseedSeed),
+        _ => Err(KeyError::UnknownSeedType(seed_type.to_string())),
+    }
+}
+
+/// Original (25-word) seeds, 256 bits encoded with the 1626-word wordsets
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{OriginalSeed, SeedScheme};
+///
+/// let mnemonic: Vec<String> = vec!["tissue", "raking", "haunted", "huts", "afraid", "volcano", "howls", "liar", "egotistic", "befit", "rounded", "older", "bluntly", "imbalance", "pivot", "exotic", "tuxedo", "amaze", "mostly", "lukewarm", "macro", "vocal", "hounded", "biplane", "rounded"].iter().map(|s| s.to_string()).collect();
+/// let hex_seed: String = OriginalSeed.to_hex_seed(&mnemonic).unwrap();
+/// let (priv_spend, priv_view) = OriginalSeed.derive_keys(&hex_seed).unwrap();
+/// assert_eq!(priv_spend, "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08".to_string());
+/// assert_eq!(priv_view, "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908".to_string());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OriginalSeed;
+
+/// MyMonero (13-word) seeds, 128 bits encoded with the 1626-word wordsets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MyMoneroSeed;
+
+/// Polyseed (16-word) seeds, whose hex seed is the key derived from their secret, birthday and features
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{PolyseedSeed, SeedScheme};
+///
+/// let mnemonic: Vec<String> = "raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language".split(' ').map(|s| s.to_string()).collect();
+/// let hex_seed: String = PolyseedSeed.to_hex_seed(&mnemonic).unwrap();
+/// assert_eq!(hex_seed, "21268a76048a3b25a4a9ac179d86b12fab5800b8d858da9facf4b0a778dc2840".to_string());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PolyseedSeed;
+
+/// Checks given mnemonic seed has one of given word counts
+fn check_word_count(words: &[String], expected: &'static [usize]) -> Result<(), KeyError> {
+    if !expected.contains(&words.len()) {
+        return Err(KeyError::WrongWordCount {
+            got: words.len(),
+            expected,
+        });
+    }
+    Ok(())
+}
+
+/// Derives the private keys from given hex seed, which must be of given length
+fn derive_keys_of_len(hex_seed: &str, len: usize) -> Result<(String, String), KeyError> {
+    if hex_seed.len() != len {
+        return Err(KeyError::InvalidHexSeed);
+    }
+    let mut priv_keys = derive_priv_keys(hex_seed.to_string())?;
+    let priv_view = priv_keys.pop().unwrap_or_default();
+    let priv_spend = priv_keys.pop().unwrap_or_default();
+    Ok((priv_spend, priv_view))
+}
+
+impl PolyseedSeed {
+    /// Generates a new polyseed seed like [`SeedScheme::generate`], of given word count, birthday (unix timestamp) and
+    /// user features instead of 16 words born now and without features
+    pub(crate) fn generate_with(&self, language: &str, word_count: usize, timestamp: u64, features: u8, rng: &mut dyn RngCore) -> Result<Vec<String>, KeyError> {
+        generate_polyseed(language, word_count, timestamp, features, rng)
+    }
+}
+
+impl SeedScheme for OriginalSeed {
+    fn generate(&self, language: &str, rng: &mut dyn RngCore) -> Result<Vec<String>, KeyError> {
+        generate_1626_seed(language, 24, rng)
+    }
+
+    fn to_hex_seed(&self, words: &[String]) -> Result<String, KeyError> {
+        check_word_count(words, &[24, 25])?;
+        derive_hex_seed(words.to_vec())
+    }
+
+    fn derive_keys(&self, hex_seed: &str) -> Result<(String, String), KeyError> {
+        derive_keys_of_len(hex_seed, 64)
+    }
+}
+
+impl SeedScheme for MyMoneroSeed {
+    fn generate(&self, language: &str, rng: &mut dyn RngCore) -> Result<Vec<String>, KeyError> {
+        generate_1626_seed(language, 12, rng)
+    }
+
+    fn to_hex_seed(&self, words: &[String]) -> Result<String, KeyError> {
+        check_word_count(words, &[12, 13])?;
+        derive_hex_seed(words.to_vec())
+    }
+
+    fn derive_keys(&self, hex_seed: &str) -> Result<(String, String), KeyError> {
+        derive_keys_of_len(hex_seed, 32)
+    }
+}
+
+impl SeedScheme for PolyseedSeed {
+    fn generate(&self, language: &str, rng: &mut dyn RngCore) -> Result<Vec<String>, KeyError> {
+        self.generate_with(language, POLYSEED_WORD_COUNT, now_timestamp(), 0, rng)
+    }
+
+    fn to_hex_seed(&self, words: &[String]) -> Result<String, KeyError> {
+        let data = poly_to_data(&polyseed_to_poly(words)?);
+        // The keys of an encrypted seed are not the keys of the wallet
+        if data.features & ENCRYPTED_FEATURE != 0 {
+            return Err(KeyError::EncryptedPolyseed);
+        }
+        Ok(hex::encode(polyseed_keygen(&data)))
+    }
+
+    fn derive_keys(&self, hex_seed: &str) -> Result<(String, String), KeyError> {
+        // Monero's wallet derives the keys from the polyseed key the way it does from an original hex seed
+        derive_keys_of_len(hex_seed, 64)
+    }
+}
+
+impl BuiltinSeedScheme for OriginalSeed {
+    fn generate_words(&self, language: &str, rng: &mut dyn RngCore) -> Result<Vec<&'static str>, KeyError> {
+        generate_1626_words(language, 24, rng)
+    }
+}
+
+impl BuiltinSeedScheme for MyMoneroSeed {
+    fn generate_words(&self, language: &str, rng: &mut dyn RngCore) -> Result<Vec<&'static str>, KeyError> {
+        generate_1626_words(language, 12, rng)
+    }
+}
+
+impl BuiltinSeedScheme for PolyseedSeed {
+    fn generate_words(&self, language: &str, rng: &mut dyn RngCore) -> Result<Vec<&'static str>, KeyError> {
+        generate_polyseed_words(language, POLYSEED_WORD_COUNT, now_timestamp(), 0, rng)
+    }
+}
@@ -0,0 +1,96 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+//! # Self test
+//!
+//! This module is for checking at runtime that the key derivation of the crate gives known answers.
+
+use super::address::derive_address;
+use super::errors::KeyError;
+use super::keys::{derive_hex_seed, derive_priv_keys, derive_pub_key};
+use super::polyseed::polyseed_entropy;
+
+/// A known answer of every step from a mnemonic seed to its main address
+struct KnownAnswer {
+    mnemonic: &'static str,
+    hex_seed: &'static str,
+    priv_spend: &'static str,
+    priv_view: &'static str,
+    pub_spend: &'static str,
+    pub_view: &'static str,
+    address: &'static str,
+}
+
+/// Known answers of an original and a MyMonero seed, the vectors of the documentation examples
+const KNOWN_ANSWERS: [KnownAnswer; 2] = [
+    KnownAnswer {
+        mnemonic: "tissue raking haunted huts afraid volcano howls liar egotistic befit rounded older bluntly imbalance pivot exotic tuxedo amaze mostly lukewarm macro vocal hounded biplane rounded",
+        hex_seed: "f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8",
+        priv_spend: "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08",
+        priv_view: "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908",
+        pub_spend: "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95",
+        pub_view: "157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47",
+        address: "4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J",
+    },
+    KnownAnswer {
+        mnemonic: "dwarf itinerary army dehydrate yahoo hairy eels dexterity tamper wedge begun nestle hairy",
+        hex_seed: "fddb58a226ba0a61cc48e2a393739378",
+        priv_spend: "5d2aa8349968ed30f4688ab684813ec22a8a5bf21a957e72bc10539642892802",
+        priv_view: "502167754302403657cf241bc2f3e5d5e262a05e35eb93f0695cc7b18e98f602",
+        pub_spend: "635a987fa7bbe6df277142ffff9254f3b593d4f93480090ed6e3b9ca07023423",
+        pub_view: "1d054fc8a8ddf35323d31ed67407c30f44e6f26617e3137aab2da3521c7bcfa0",
+        address: "45PUwJmgsiVeKshFDNaPWshmHYyQyvTb63UxjRoK8hGw6seL4uX68t2EuZa77D5Z1c3Z8Xta3PCW6MX3JbSUJsu8K7oWjFs",
+    },
+];
+
+/// A polyseed seed and its secret
+const POLYSEED_KNOWN_ANSWER: (&str, &str) = (
+    "raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language",
+    "dd76e7359a0ded37cd0ff0f3c829a5ae016733",
+);
+
+/// Checks given result is the known answer, else fails the self test at given step
+fn check(result: Result<String, KeyError>, expected: &str, step: &'static str) -> Result<(), KeyError> {
+    match result {
+        Ok(answer) if answer == expected => Ok(()),
+        _ => Err(KeyError::SelfTestFailed(step)),
+    }
+}
+
+/// Runs known-answer tests of the key derivation: mnemonic to hex seed, hex seed to private keys, private to public
+/// keys and public keys to address, for original and MyMonero seeds, and polyseed decoding
+///
+/// Meant to be run once at startup, to stop before handing out wrong keys or addresses if the crate or its
+/// dependencies were miscompiled or badly configured for the target. The first failing step is given as
+/// [`KeyError::SelfTestFailed`].
+///
+/// Example:
+/// ```
+/// use libmonero::keys::self_test;
+///
+/// self_test().expect("libmonero self test failed");
+/// ```
+pub fn self_test() -> Result<(), KeyError> {
+    for answer in KNOWN_ANSWERS.iter() {
+        let mnemonic: Vec<String> = answer.mnemonic.split(' ').map(|word| word.to_string()).collect();
+        check(derive_hex_seed(mnemonic), answer.hex_seed, "hex_seed")?;
+        let priv_keys = derive_priv_keys(answer.hex_seed.to_string()).map_err(|_| KeyError::SelfTestFailed("priv_keys"))?;
+        if priv_keys.len() != 2 || priv_keys[0] != answer.priv_spend || priv_keys[1] != answer.priv_view {
+            return Err(KeyError::SelfTestFailed("priv_keys"));
+        }
+        check(derive_pub_key(answer.priv_spend.to_string()), answer.pub_spend, "pub_spend")?;
+        check(derive_pub_key(answer.priv_view.to_string()), answer.pub_view, "pub_view")?;
+        check(derive_address(answer.pub_spend.to_string(), answer.pub_view.to_string(), 0), answer.address, "address")?;
+    }
+    let (mnemonic, secret) = POLYSEED_KNOWN_ANSWER;
+    let mnemonic: Vec<String> = mnemonic.split(' ').map(|word| word.to_string()).collect();
+    check(polyseed_entropy(&mnemonic).map(hex::encode), secret, "polyseed")?;
+    Ok(())
+}
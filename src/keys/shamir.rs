@@ -0,0 +1,147 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+//! # Shamir
+//!
+//! This module is for splitting hex seeds into shares with Shamir's secret sharing over GF(256), so any `threshold` of
+//! the shares restore the seed while fewer reveal nothing about it.
+//!
+//! A share is a hex string of its threshold, its index and one byte per byte of the seed. Shares are not
+//! authenticated, a tampered share restores a wrong seed, which the checksum of its mnemonic seed then gives away.
+
+use super::errors::KeyError;
+use super::keys::with_rng;
+use rand::Rng;
+
+/// Multiplies two elements of GF(256) (AES polynomial x^8 + x^4 + x^3 + x + 1) without branching on them
+fn gf256_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product: u8 = 0;
+    for _ in 0..8 {
+        product ^= a & (b & 1).wrapping_neg();
+        let carry = (a >> 7).wrapping_neg();
+        a = (a << 1) ^ (carry & 0x1b);
+        b >>= 1;
+    }
+    product
+}
+
+/// Inverts a non-zero element of GF(256), as a^254
+fn gf256_inv(a: u8) -> u8 {
+    let mut result: u8 = 1;
+    let mut power = a;
+    let mut exponent: u8 = 254;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = gf256_mul(result, power);
+        }
+        power = gf256_mul(power, power);
+        exponent >>= 1;
+    }
+    result
+}
+
+/// Evaluates the polynomial with given coefficients (constant term first) at x
+fn gf256_poly_eval(coeff: &[u8], x: u8) -> u8 {
+    coeff.iter().rev().fold(0, |acc, &c| gf256_mul(acc, x) ^ c)
+}
+
+/// Splits given hex seed (32 or 64 characters) into `shares` shares, any `threshold` of which restore it
+///
+/// Shares are hex strings, see [`combine_seed`] for restoring the seed.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{combine_seed, split_seed};
+///
+/// let hex_seed: &str = "f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8";
+/// let shares: Vec<String> = split_seed(hex_seed, 2, 3).unwrap();
+/// assert_eq!(shares.len(), 3);
+/// assert_eq!(combine_seed(&shares[1..]).unwrap(), hex_seed.to_string());
+/// ```
+pub fn split_seed(hex_seed: &str, threshold: u8, shares: u8) -> Result<Vec<String>, KeyError> {
+    if threshold == 0 || threshold > shares {
+        return Err(KeyError::InvalidThreshold { threshold, shares });
+    }
+    if hex_seed.len() != 32 && hex_seed.len() != 64 {
+        return Err(KeyError::InvalidHexSeed);
+    }
+    let seed = hex::decode(hex_seed).map_err(|_| KeyError::InvalidHex)?;
+    // One random polynomial of degree threshold - 1 per byte of the seed, the byte being its constant term
+    let polynomials: Vec<Vec<u8>> = seed
+        .iter()
+        .map(|&byte| {
+            let mut coeff = vec![byte; threshold as usize];
+            with_rng(|rng| rng.fill(&mut coeff[1..]));
+            coeff
+        })
+        .collect();
+    let split = (1..=shares)
+        .map(|x| {
+            let mut share = vec![threshold, x];
+            share.extend(polynomials.iter().map(|coeff| gf256_poly_eval(coeff, x)));
+            hex::encode(share)
+        })
+        .collect();
+    Ok(split)
+}
+
+/// Restores a hex seed from shares made by [`split_seed`]
+///
+/// At least as many shares as the threshold they were made with are needed, in any order.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::{combine_seed, split_seed, KeyError};
+///
+/// let hex_seed: &str = "f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8";
+/// let shares: Vec<String> = split_seed(hex_seed, 3, 5).unwrap();
+/// assert_eq!(combine_seed(&[shares[4].clone(), shares[0].clone(), shares[2].clone()]).unwrap(), hex_seed.to_string());
+/// assert_eq!(combine_seed(&shares[..2]), Err(KeyError::NotEnoughShares { got: 2, threshold: 3 }));
+/// ```
+pub fn combine_seed(shares: &[String]) -> Result<String, KeyError> {
+    let decoded: Vec<Vec<u8>> = shares
+        .iter()
+        .map(|share| hex::decode(share).map_err(|_| KeyError::InvalidShares))
+        .collect::<Result<_, _>>()?;
+    let first = decoded.first().ok_or(KeyError::NotEnoughShares { got: 0, threshold: 1 })?;
+    if first.len() < 2 {
+        return Err(KeyError::InvalidShares);
+    }
+    let (threshold, seed_len) = (first[0], first.len() - 2);
+    if threshold == 0 || (seed_len != 16 && seed_len != 32) {
+        return Err(KeyError::InvalidShares);
+    }
+    // Shares must come from the same split and have distinct non-zero indexes
+    for (i, share) in decoded.iter().enumerate() {
+        if share.len() != seed_len + 2 || share[0] != threshold || share[1] == 0 {
+            return Err(KeyError::InvalidShares);
+        }
+        if decoded[..i].iter().any(|other| other[1] == share[1]) {
+            return Err(KeyError::InvalidShares);
+        }
+    }
+    if decoded.len() < threshold as usize {
+        return Err(KeyError::NotEnoughShares { got: decoded.len(), threshold });
+    }
+    // Lagrange interpolation at x = 0 with the first threshold shares
+    let used = &decoded[..threshold as usize];
+    let mut seed = vec![0u8; seed_len];
+    for share in used.iter() {
+        let x = share[1];
+        let basis = used
+            .iter()
+            .filter(|other| other[1] != x)
+            .fold(1u8, |acc, other| gf256_mul(acc, gf256_mul(other[1], gf256_inv(other[1] ^ x))));
+        for (byte, y) in seed.iter_mut().zip(&share[2..]) {
+            *byte ^= gf256_mul(*y, basis);
+        }
+    }
+    Ok(hex::encode(seed))
+}
@@ -30,19 +30,180 @@
 //!         - [`get_height(node: DaemonNode) -> u64`](blocks/fn.get_height.html)
 //!         - [`get_block_from_height(node: DaemonNode, height: u64) -> Block`](blocks/fn.get_block_from_height.html)
 //!         - [`get_transaction_from_hash(node: DaemonNode, hash: &str) -> RawTx`](blocks/fn.get_transaction_from_hash.html)
+//!         - [`validate_address_rpc(address: &str, rpc_url: &str) -> Result<bool, KeyError>`](blocks/fn.validate_address_rpc.html) - needs the `rpc` feature
 //! - Crypt
 //!     - [`cryptonight`](crypt/cryptonight/index.html)
 //!         - [`cn_slow_hash_original(input: &[u8]) -> String`](crypt/cryptonight/fn.cn_slow_hash_original.html) - EXPERIMENTAL!
+//!     - [`cn_fast_hash(data: &[u8]) -> [u8; 32]`](crypt/fn.cn_fast_hash.html)
+//!     - [`hash_to_scalar(data: &[u8]) -> [u8; 32]`](crypt/fn.hash_to_scalar.html)
+//!     - [`keccak_prf(domain: &[u8], inputs: &[&[u8]]) -> [u8; 32]`](crypt/fn.keccak_prf.html)
+//!     - [`mul8(point: &EdwardsPoint) -> EdwardsPoint`](crypt/fn.mul8.html)
+//!     - [`needed_reduction(bytes: &[u8; 32]) -> bool`](crypt/fn.needed_reduction.html)
+//!     - [`reduce_scalar(bytes: [u8; 32]) -> [u8; 32]`](crypt/fn.reduce_scalar.html)
 //! - Keys
-//!     - [`derive_address(public_spend_key: String, public_view_key: String, network: i8) -> String`](keys/fn.derive_address.html)
-//!     - [`derive_hex_seed(mnemonic_seed: Vec<String>) -> String`](keys/fn.derive_hex_seed.html)
-//!     - [`derive_priv_keys(hex_seed: String) -> Vec<String>`](keys/fn.derive_priv_keys.html)
-//!     - [`derive_priv_vk_from_priv_sk(private_spend_key: String) -> String`](keys/fn.derive_priv_vk_from_priv_sk.html)
-//!     - [`derive_pub_key(private_key: String) -> String`](keys/fn.derive_pub_key.html)
+//!     - [`address_from_priv_keys(private_spend_key: &str, private_view_key: &str, network: u8) -> Result<String, KeyError>`](keys/fn.address_from_priv_keys.html)
+//!     - [`address_public_spend_key(address: &str) -> Result<String, KeyError>`](keys/fn.address_public_spend_key.html)
+//!     - [`address_public_view_key(address: &str) -> Result<String, KeyError>`](keys/fn.address_public_view_key.html)
+//!     - [`address_to_bytes(address: &str) -> Result<Vec<u8>, KeyError>`](keys/fn.address_to_bytes.html)
+//!     - [`AddressType`](keys/enum.AddressType.html)
+//!     - [`append_checksum_word(words: Vec<String>, language: &str) -> Result<Vec<String>, KeyError>`](keys/fn.append_checksum_word.html)
+//!     - [`bip39_to_monero_keys(bip39_mnemonic: &str, passphrase: &str) -> Result<Keys, KeyError>`](keys/fn.bip39_to_monero_keys.html)
+//!     - [`BIP39_WORD_COUNTS`](keys/constant.BIP39_WORD_COUNTS.html)
+//!     - [`bytes_to_address(bytes: &[u8]) -> Result<String, KeyError>`](keys/fn.bytes_to_address.html)
+//!     - [`catch_key_panic<F, T>(f: F) -> Result<T, KeyError>`](keys/fn.catch_key_panic.html)
+//!     - [`checksum_word_index(words: &[String], language: &str) -> Result<usize, KeyError>`](keys/fn.checksum_word_index.html)
+//!     - [`classify_seed(mnemonic: &[String]) -> Result<SeedKind, KeyError>`](keys/fn.classify_seed.html)
+//!     - [`combine_seed(shares: &[String]) -> Result<String, KeyError>`](keys/fn.combine_seed.html)
+//!     - [`complete_word(partial: &str, language: &str) -> Vec<String>`](keys/fn.complete_word.html)
+//!     - [`Coin`](keys/enum.Coin.html)
+//!         - [`address_prefix(&self, network: u8) -> Option<u64>`](keys/enum.Coin.html#method.address_prefix)
+//!     - [`decode_address(address: &str) -> Result<DecodedAddress, KeyError>`](keys/fn.decode_address.html)
+//!     - [`decode_hex32(s: &str) -> Result<[u8; 32], KeyError>`](keys/fn.decode_hex32.html)
+//!     - [`DecodedAddress`](keys/struct.DecodedAddress.html)
+//!     - [`decrypt_polyseed(mnemonic: &[String], passphrase: &str) -> Result<Vec<String>, KeyError>`](keys/fn.decrypt_polyseed.html)
+//!     - [`DEFAULT_MAJOR_LOOKAHEAD`](keys/constant.DEFAULT_MAJOR_LOOKAHEAD.html)
+//!     - [`DEFAULT_MINOR_LOOKAHEAD`](keys/constant.DEFAULT_MINOR_LOOKAHEAD.html)
+//!     - [`derive_address(public_spend_key: String, public_view_key: String, network: u8) -> Result<String, KeyError>`](keys/fn.derive_address.html)
+//!     - [`derive_address_bytes(public_spend_key: [u8; 32], public_view_key: [u8; 32], network: Network) -> Result<String, KeyError>`](keys/fn.derive_address_bytes.html)
+//!     - [`derive_address_for_coin(public_spend_key: String, public_view_key: String, network: u8, coin: Coin) -> Result<String, KeyError>`](keys/fn.derive_address_for_coin.html)
+//!     - [`derive_all_keys(mnemonic: Vec<String>) -> Result<Keys, KeyError>`](keys/fn.derive_all_keys.html)
+//!     - [`derive_all_network_addresses(public_spend_key: &str, public_view_key: &str) -> Result<HashMap<Network, String>, KeyError>`](keys/fn.derive_all_network_addresses.html)
+//!     - [`derive_child_keys(parent_priv_spend: &str, index: u32) -> Result<(String, String), KeyError>`](keys/fn.derive_child_keys.html)
+//!     - [`derive_hex_seed(mnemonic_seed: Vec<String>) -> Result<String, KeyError>`](keys/fn.derive_hex_seed.html)
+//!     - [`derive_hex_seed_ct(mnemonic_seed: Vec<String>) -> Result<String, KeyError>`](keys/fn.derive_hex_seed_ct.html)
+//!     - [`derive_hex_seeds_batch(seeds: Vec<Vec<String>>) -> Vec<Result<String, KeyError>>`](keys/fn.derive_hex_seeds_batch.html)
+//!     - [`derive_integrated_address(public_spend_key: &str, public_view_key: &str, network: Network, payment_id: [u8; 8]) -> Result<String, KeyError>`](keys/fn.derive_integrated_address.html)
+//!     - [`derive_integrated_address_full(public_spend_key: &str, public_view_key: &str, network: Network, payment_id: Option<[u8; 8]>) -> Result<(String, [u8; 8]), KeyError>`](keys/fn.derive_integrated_address_full.html)
+//!     - [`derive_key_derivation(tx_pub_key: &str, private_view_key: &str) -> Result<String, KeyError>`](keys/fn.derive_key_derivation.html)
+//!     - [`derive_output_public_key(tx_secret_key: &str, recipient_view_pub: &str, recipient_spend_pub: &str, output_index: u64) -> Result<String, KeyError>`](keys/fn.derive_output_public_key.html)
+//!     - [`derive_output_scalar(derivation: &str, output_index: u64) -> Result<String, KeyError>`](keys/fn.derive_output_scalar.html)
+//!     - [`derive_priv_keypair(hex_seed: String) -> Result<PrivKeyPair, KeyError>`](keys/fn.derive_priv_keypair.html)
+//!     - [`derive_priv_keys(hex_seed: String) -> Result<Vec<String>, KeyError>`](keys/fn.derive_priv_keys.html)
+//!     - [`derive_priv_keys_checked(hex_seed: String) -> Result<Vec<String>, KeyError>`](keys/fn.derive_priv_keys_checked.html)
+//!     - [`derive_priv_keys_with_case(hex_seed: String, case: HexCase) -> Result<Vec<String>, KeyError>`](keys/fn.derive_priv_keys_with_case.html)
+//!     - [`derive_priv_keys_with_passphrase(hex_seed: String, passphrase: &str) -> Result<Vec<String>, KeyError>`](keys/fn.derive_priv_keys_with_passphrase.html)
+//!     - [`derive_priv_keys_typed(hex_seed: String, kind: SeedKind) -> Result<Vec<String>, KeyError>`](keys/fn.derive_priv_keys_typed.html)
+//!     - [`derive_priv_vk_from_priv_sk(private_spend_key: String) -> Result<String, KeyError>`](keys/fn.derive_priv_vk_from_priv_sk.html)
+//!     - [`derive_pub_key(private_key: String) -> Result<String, KeyError>`](keys/fn.derive_pub_key.html)
+//!     - [`derive_pub_key_bytes(private_key: [u8; 32]) -> [u8; 32]`](keys/fn.derive_pub_key_bytes.html)
+//!     - [`derive_pub_key_checked(private_key: String) -> Result<String, KeyError>`](keys/fn.derive_pub_key_checked.html)
+//!     - [`derive_pub_key_with_case(private_key: String, case: HexCase) -> Result<String, KeyError>`](keys/fn.derive_pub_key_with_case.html)
+//!     - [`derive_subaddress(private_view_key: &str, public_spend_key: &str, major: u32, minor: u32, network: Network) -> Result<String, KeyError>`](keys/fn.derive_subaddress.html)
+//!     - [`derive_subaddress_range(private_view_key: &str, public_spend_key: &str, major: u32, minors: Range<u32>) -> Result<Vec<[u8; 32]>, KeyError>`](keys/fn.derive_subaddress_range.html)
+//!     - [`derive_subaddress_range_parallel(private_view_key: &str, public_spend_key: &str, major: u32, minors: Range<u32>) -> Result<Vec<[u8; 32]>, KeyError>`](keys/fn.derive_subaddress_range_parallel.html) - needs the `rayon` feature
+//!     - [`derive_view_tag(shared_secret: &[u8], output_index: u64) -> u8`](keys/fn.derive_view_tag.html)
+//!     - [`detect_language(mnemonic: &[String]) -> Result<Language, KeyError>`](keys/fn.detect_language.html)
+//!     - [`detect_polyseed_language(mnemonic: &[String]) -> Option<&'static str>`](keys/fn.detect_polyseed_language.html)
+//!     - [`encrypt_polyseed(mnemonic: &[String], passphrase: &str) -> Result<Vec<String>, KeyError>`](keys/fn.encrypt_polyseed.html)
+//!     - [`estimate_restore_height(creation_timestamp: u64) -> u64`](keys/fn.estimate_restore_height.html)
+//!     - [`expected_word_count(first_few_words: &[String]) -> Option<usize>`](keys/fn.expected_word_count.html)
 //!     - [`generate_seed(language: &str, seed_type: &str) -> Vec<String>`](keys/fn.generate_seed.html)
+//!     - [`generate_seed_from_entropy(language: &str, seed_type: &str, entropy: &[u8]) -> Result<Vec<String>, KeyError>`](keys/fn.generate_seed_from_entropy.html)
+//!     - [`generate_seed_in(language: Language, seed_type: &str) -> Result<Vec<String>, KeyError>`](keys/fn.generate_seed_in.html)
+//!     - [`generate_seed_iter(language: &str, seed_type: &str) -> Result<impl Iterator<Item = &'static str>, KeyError>`](keys/fn.generate_seed_iter.html)
+//!     - [`generate_seed_with_strength(language: &str, bits: u16) -> Result<Vec<String>, KeyError>`](keys/fn.generate_seed_with_strength.html)
+//!     - [`HexCase`](keys/enum.HexCase.html)
+//!     - [`import_from_wallet_json(json: &str) -> Result<Keys, KeyError>`](keys/fn.import_from_wallet_json.html)
+//!     - [`is_output_ours(tx_pub_key: &str, private_view_key: &str, public_spend_key: &str, output_pub_key: &str, output_index: u64) -> bool`](keys/fn.is_output_ours.html)
+//!     - [`is_polyseed_encrypted(mnemonic: &[String]) -> Result<bool, KeyError>`](keys/fn.is_polyseed_encrypted.html)
+//!     - [`is_valid_word(word: &str, language: &str) -> bool`](keys/fn.is_valid_word.html)
+//!     - [`join_polyseed_words(mnemonic: &[String], language: &str) -> Result<String, KeyError>`](keys/fn.join_polyseed_words.html)
+//!     - [`KeyError`](keys/enum.KeyError.html)
+//!     - [`Keys`](keys/struct.Keys.html)
+//!         - [`reveal(&self) -> String`](keys/struct.Keys.html#method.reveal)
+//!     - [`keys_from_spend_scalar(spend: [u8; 32]) -> Result<Keys, KeyError>`](keys/fn.keys_from_spend_scalar.html)
+//!     - [`Language`](keys/enum.Language.html)
+//!         - [`as_str(&self) -> &'static str`](keys/enum.Language.html#method.as_str)
+//!         - [`supports(&self, seed_type: &str) -> bool`](keys/enum.Language.html#method.supports)
+//!     - [`MAINNET_HEIGHT_ANCHORS`](keys/constant.MAINNET_HEIGHT_ANCHORS.html)
+//!     - [`mnemonic_matches_address(mnemonic: &[String], expected_address: &str, network: u8) -> Result<bool, KeyError>`](keys/fn.mnemonic_matches_address.html)
+//!     - [`MONERO_PREFIXES`](keys/constant.MONERO_PREFIXES.html)
+//!     - [`mymonero_hex_to_address(hex_seed: &str, network: u8) -> Result<String, KeyError>`](keys/fn.mymonero_hex_to_address.html)
+//!     - [`MyMoneroSeed`](keys/struct.MyMoneroSeed.html)
+//!     - [`Network`](keys/enum.Network.html)
+//!     - [`normalize_address(address: &str) -> Result<String, KeyError>`](keys/fn.normalize_address.html)
+//!     - [`normalize_hex_seed(hex_seed: &str) -> String`](keys/fn.normalize_hex_seed.html)
+//!     - [`OriginalSeed`](keys/struct.OriginalSeed.html)
+//!     - [`parse_hex_keys(input: &str) -> Result<Vec<[u8; 32]>, KeyError>`](keys/fn.parse_hex_keys.html)
+//!     - [`payment_id_encryption_key(derivation: &str) -> Result<[u8; 8], KeyError>`](keys/fn.payment_id_encryption_key.html)
+//!     - [`polyseed_birthday(mnemonic: &[String]) -> Result<u64, KeyError>`](keys/fn.polyseed_birthday.html)
+//!     - [`polyseed_checksum_valid(mnemonic: &[String]) -> bool`](keys/fn.polyseed_checksum_valid.html)
+//!     - [`polyseed_entropy(mnemonic: &[String]) -> Result<[u8; 19], KeyError>`](keys/fn.polyseed_entropy.html)
+//!     - [`POLYSEED_WORD_COUNT`](keys/constant.POLYSEED_WORD_COUNT.html)
+//!     - [`POLYSEED_WORD_COUNTS`](keys/constant.POLYSEED_WORD_COUNTS.html)
+//!     - [`PolyseedSeed`](keys/struct.PolyseedSeed.html)
+//!     - [`PrivKeyPair`](keys/struct.PrivKeyPair.html)
+//!         - [`reveal(&self) -> String`](keys/struct.PrivKeyPair.html#method.reveal)
+//!     - [`RestoreHeightEstimator`](keys/struct.RestoreHeightEstimator.html)
+//!         - [`add_anchor(self, height: u64, timestamp: u64) -> Result<RestoreHeightEstimator, KeyError>`](keys/struct.RestoreHeightEstimator.html#method.add_anchor)
+//!         - [`empty() -> RestoreHeightEstimator`](keys/struct.RestoreHeightEstimator.html#method.empty)
+//!         - [`estimate(&self, timestamp: u64) -> u64`](keys/struct.RestoreHeightEstimator.html#method.estimate)
+//!         - [`new() -> RestoreHeightEstimator`](keys/struct.RestoreHeightEstimator.html#method.new)
+//!     - [`scan_outputs(tx_pub_key: &str, private_view_key: &str, public_spend_key: &str, outputs: &[(String, u64)]) -> Vec<bool>`](keys/fn.scan_outputs.html)
+//!     - [`seed_entropy_bits(seed_type: &str) -> Result<u32, KeyError>`](keys/fn.seed_entropy_bits.html)
+//!     - [`seed_entropy_len(seed_type: &str) -> Result<usize, KeyError>`](keys/fn.seed_entropy_len.html)
+//!     - [`seed_from_dice(rolls: &[u8], language: &str, seed_type: &str) -> Result<Vec<String>, KeyError>`](keys/fn.seed_from_dice.html)
+//!     - [`SEED_WORD_COUNTS`](keys/constant.SEED_WORD_COUNTS.html)
+//!     - [`SeedBuilder`](keys/struct.SeedBuilder.html)
+//!         - [`birthday(self, timestamp: u64) -> SeedBuilder`](keys/struct.SeedBuilder.html#method.birthday)
+//!         - [`build(self) -> Result<Vec<String>, KeyError>`](keys/struct.SeedBuilder.html#method.build)
+//!         - [`features(self, features: u8) -> SeedBuilder`](keys/struct.SeedBuilder.html#method.features)
+//!         - [`language(self, language: &str) -> SeedBuilder`](keys/struct.SeedBuilder.html#method.language)
+//!         - [`new() -> SeedBuilder`](keys/struct.SeedBuilder.html#method.new)
+//!         - [`rng(self, rng: &mut dyn RngCore) -> SeedBuilder`](keys/struct.SeedBuilder.html#method.rng)
+//!         - [`seed_type(self, seed_type: &str) -> SeedBuilder`](keys/struct.SeedBuilder.html#method.seed_type)
+//!         - [`strength(self, bits: u16) -> SeedBuilder`](keys/struct.SeedBuilder.html#method.strength)
+//!         - [`word_count(self, word_count: usize) -> SeedBuilder`](keys/struct.SeedBuilder.html#method.word_count)
+//!     - [`SeedKind`](keys/enum.SeedKind.html)
+//!     - [`SeedScheme`](keys/trait.SeedScheme.html)
+//!         - [`derive_keys(&self, hex_seed: &str) -> Result<(String, String), KeyError>`](keys/trait.SeedScheme.html#tymethod.derive_keys)
+//!         - [`generate(&self, language: &str, rng: &mut dyn RngCore) -> Result<Vec<String>, KeyError>`](keys/trait.SeedScheme.html#tymethod.generate)
+//!         - [`to_hex_seed(&self, words: &[String]) -> Result<String, KeyError>`](keys/trait.SeedScheme.html#tymethod.to_hex_seed)
+//!     - [`self_test() -> Result<(), KeyError>`](keys/fn.self_test.html)
+//!     - [`sign_message(address: &str, private_key: &str, message: &[u8]) -> Result<String, KeyError>`](keys/fn.sign_message.html)
+//!     - [`spend_key_to_mnemonic(priv_spend_key: &str, language: &str) -> Result<Vec<String>, KeyError>`](keys/fn.spend_key_to_mnemonic.html)
+//!     - [`split_polyseed_phrase(phrase: &str) -> Vec<String>`](keys/fn.split_polyseed_phrase.html)
+//!     - [`split_seed(hex_seed: &str, threshold: u8, shares: u8) -> Result<Vec<String>, KeyError>`](keys/fn.split_seed.html)
+//!     - [`subaddress_label_key(private_view_key: &str, major: u32, minor: u32) -> Result<[u8; 32], KeyError>`](keys/fn.subaddress_label_key.html)
+//!     - [`SubaddressIndexCache`](keys/struct.SubaddressIndexCache.html)
+//!         - [`expand(&mut self, major: u32, minor: u32)`](keys/struct.SubaddressIndexCache.html#method.expand)
+//!         - [`find_output(&self, tx_pub_key: &str, output_pub_key: &str, output_index: u64) -> Result<Option<(u32, u32)>, KeyError>`](keys/struct.SubaddressIndexCache.html#method.find_output)
+//!         - [`is_empty(&self) -> bool`](keys/struct.SubaddressIndexCache.html#method.is_empty)
+//!         - [`len(&self) -> usize`](keys/struct.SubaddressIndexCache.html#method.len)
+//!         - [`lookup(&self, public_spend_key: &str) -> Option<(u32, u32)>`](keys/struct.SubaddressIndexCache.html#method.lookup)
+//!         - [`new(private_view_key: &str, public_spend_key: &str, major_lookahead: u32, minor_lookahead: u32) -> Result<SubaddressIndexCache, KeyError>`](keys/struct.SubaddressIndexCache.html#method.new)
+//!     - [`validate_mnemonic(mnemonic_seed: &[String]) -> Result<(), KeyError>`](keys/fn.validate_mnemonic.html)
+//!     - [`verify_keys_consistency(keys: &Keys) -> Result<(), KeyError>`](keys/fn.verify_keys_consistency.html)
+//!     - [`verify_message(address: &str, message: &[u8], signature: &str) -> bool`](keys/fn.verify_message.html)
+//!     - [`verify_wordset_integrity() -> Result<(), KeyError>`](keys/fn.verify_wordset_integrity.html)
+//!     - [`wallet_addresses(keys: &Keys, network: Network, majors: Range<u32>, minors: Range<u32>) -> Result<impl Iterator<Item = (u32, u32, String)>, KeyError>`](keys/fn.wallet_addresses.html)
+//!     - [`weak_seed_check(hex_seed: &str) -> bool`](keys/fn.weak_seed_check.html)
+//!     - [`with_checksum(words: &[String]) -> Result<Vec<String>, KeyError>`](keys/fn.with_checksum.html)
+//!     - [`without_checksum(words: &[String]) -> Result<Vec<String>, KeyError>`](keys/fn.without_checksum.html)
+//!     - [`word_matches_prefix(word: &str, language: &str) -> bool`](keys/fn.word_matches_prefix.html)
+//!     - [`wordset_info(language: &str) -> Option<WordsetInfo>`](keys/fn.wordset_info.html)
+//!     - [`WordsetInfo`](keys/struct.WordsetInfo.html)
 //! - Utils
 //! 
+//!     - [`atomic_to_xmr(atomic: u64) -> String`](utils/fn.atomic_to_xmr.html)
 //!     - [`is_valid_addr(address: &str) -> bool`](utils/fn.is_valid_addr.html)
+//!     - [`parse_payment_uri(uri: &str) -> Result<PaymentUri, KeyError>`](utils/fn.parse_payment_uri.html)
+//!     - [`PaymentUri`](utils/struct.PaymentUri.html)
+//!         - [`to_uri(&self) -> String`](utils/struct.PaymentUri.html#method.to_uri)
+//!     - [`xmr_to_atomic(amount: &str) -> Result<u64, KeyError>`](utils/fn.xmr_to_atomic.html)
+//! - Wallet
+//! 
+//!     - [`demo_wallet(label: &str) -> Wallet`](wallet/fn.demo_wallet.html) - needs the `demo` feature
+//!     - [`KeysFileFields`](wallet/struct.KeysFileFields.html)
+//!         - [`reveal(&self) -> String`](wallet/struct.KeysFileFields.html#method.reveal)
+//!         - [`to_json(&self) -> String`](wallet/struct.KeysFileFields.html#method.to_json)
+//!     - [`to_keys_file_fields(wallet: &Wallet) -> Result<KeysFileFields, KeyError>`](wallet/fn.to_keys_file_fields.html)
+//!     - [`Wallet`](wallet/struct.Wallet.html)
+//!         - [`from_secret_bytes(bytes: &[u8]) -> Result<Wallet, KeyError>`](wallet/struct.Wallet.html#method.from_secret_bytes)
+//!         - [`new(language: &str, seed_type: &str, network: u8) -> Result<Wallet, KeyError>`](wallet/struct.Wallet.html#method.new)
+//!         - [`open_wallet(mnemonic: Vec<String>, network: u8) -> Result<Wallet, KeyError>`](wallet/struct.Wallet.html#method.open_wallet)
+//!         - [`reveal(&self) -> String`](wallet/struct.Wallet.html#method.reveal)
+//!         - [`to_secret_bytes(&self) -> Result<[u8; 66], KeyError>`](wallet/struct.Wallet.html#method.to_secret_bytes)
 
 
 pub(crate) mod mnemonics {
@@ -66,11 +227,30 @@ pub(crate) mod mnemonics {
     pub mod polyseed {
         pub mod wordsets;
         pub mod languages {
+            pub mod chinese_simplified;
+            pub mod chinese_traditional;
+            pub mod czech;
             pub mod english;
+            pub mod french;
+            pub mod italian;
+            pub mod japanese;
+            pub mod korean;
+            pub mod portuguese;
+            pub mod spanish;
         }
     }
 }
 
+/// Logs a debug message through the `log` crate with the `logging` feature, does nothing without it
+///
+/// Never pass secrets (seeds, words, private keys) to it, only markers of how far the derivation went.
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "logging")]
+        log::debug!($($arg)*);
+    };
+}
+
 /// Cryptographic functions
 pub mod crypt;
 /// Block manipulation functions
@@ -80,6 +260,5 @@ pub mod keys;
 /// Utility functions like address validation
 pub mod utils;
 
-// Will be added in the future
-// pub mod wallet;
-// pub use wallet::*;
\ No newline at end of file
+/// Wallet functions
+pub mod wallet;
\ No newline at end of file
@@ -10,9 +10,13 @@
 
 use crate::mnemonics::polyseed::wordsets::WordsetPolyseed;
 
+// https://github.com/tevador/polyseed/blob/master/src/lang_en.c
 pub(crate) const ENGLISHPOLYSEED: WordsetPolyseed = WordsetPolyseed {
     name: "en",
-    prefix_length: 4,
+    prefix_len: 4,
+    has_accents: false,
+    compose: false,
+    separator: " ",
     words: [
         "abandon",
         "ability",
@@ -2063,4 +2067,4 @@ pub(crate) const ENGLISHPOLYSEED: WordsetPolyseed = WordsetPolyseed {
         "zone",
         "zoo",
     ],
-};
\ No newline at end of file
+};
@@ -8,14 +8,34 @@
  *
  */
 
-use super::languages::english::ENGLISHPOLYSEED;
+use super::languages::{chinese_simplified::CHINESESIMPLIFIEDPOLYSEED, chinese_traditional::CHINESETRADITIONALPOLYSEED, czech::CZECHPOLYSEED, english::ENGLISHPOLYSEED, french::FRENCHPOLYSEED, italian::ITALIANPOLYSEED, japanese::JAPANESEPOLYSEED, korean::KOREANPOLYSEED, portuguese::PORTUGUESEPOLYSEED, spanish::SPANISHPOLYSEED};
 
+// WordsetPolyseed is a struct that contains the name of the wordset, its matching rules and the words
+// Name is the ISO639 language code, zh_s and zh_t are simplified and traditional Chinese
+// Prefix length is the number of characters that identify a word, 0 if words must match fully
+// Has accents is true if words are matched with their accents removed
+// Compose is true if words are shown composed (NFC), they are stored decomposed (NFKD) like polyseed input
+// Separator is put between the words when showing a seed
+// Words is an array of 2048 words, the BIP-39 wordlist of the language
 pub(crate) struct WordsetPolyseed {
     pub name: &'static str,
-    pub prefix_length: u8,
+    pub prefix_len: usize,
+    pub has_accents: bool,
+    pub compose: bool,
+    pub separator: &'static str,
     pub words: [&'static str; 2048],
 }
 
-pub(crate) static WORDSETSPOLYSEED : [WordsetPolyseed; 1] = [
+// Wordsets of polyseed (16-word) mnemonics, in the order polyseed tries them
+pub(crate) static WORDSETSPOLYSEED : [WordsetPolyseed; 10] = [
     ENGLISHPOLYSEED,
-];
\ No newline at end of file
+    JAPANESEPOLYSEED,
+    KOREANPOLYSEED,
+    SPANISHPOLYSEED,
+    FRENCHPOLYSEED,
+    ITALIANPOLYSEED,
+    CZECHPOLYSEED,
+    PORTUGUESEPOLYSEED,
+    CHINESESIMPLIFIEDPOLYSEED,
+    CHINESETRADITIONALPOLYSEED,
+];
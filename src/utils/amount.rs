@@ -0,0 +1,64 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+use crate::keys::KeyError;
+
+/// Number of decimal places of XMR, 1 XMR is 10^12 piconero
+const XMR_DECIMALS: usize = 12;
+const PICONERO_PER_XMR: u64 = 1_000_000_000_000;
+
+/// Converts a decimal XMR amount to atomic units (piconero)
+///
+/// Example:
+/// ```
+/// use libmonero::utils::xmr_to_atomic;
+///
+/// assert_eq!(xmr_to_atomic("1.5").unwrap(), 1_500_000_000_000);
+/// assert_eq!(xmr_to_atomic("0.000000000001").unwrap(), 1);
+/// assert!(xmr_to_atomic("0.0000000000001").is_err());
+/// ```
+pub fn xmr_to_atomic(amount: &str) -> Result<u64, KeyError> {
+    let (whole, fraction) = match amount.split_once('.') {
+        Some((whole, fraction)) => (whole, fraction),
+        None => (amount, "0"),
+    };
+    let is_digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+    if !is_digits(whole) || !is_digits(fraction) || fraction.len() > XMR_DECIMALS {
+        return Err(KeyError::InvalidAmount);
+    }
+    let whole: u64 = whole.parse().map_err(|_| KeyError::InvalidAmount)?;
+    // Pad the fraction to 12 digits, "5" is 500000000000 piconero
+    let fraction: u64 = format!("{:0<width$}", fraction, width = XMR_DECIMALS)
+        .parse()
+        .map_err(|_| KeyError::InvalidAmount)?;
+    whole
+        .checked_mul(PICONERO_PER_XMR)
+        .and_then(|atomic| atomic.checked_add(fraction))
+        .ok_or(KeyError::InvalidAmount)
+}
+
+/// Converts atomic units (piconero) to a decimal XMR amount, without trailing zeros
+///
+/// Example:
+/// ```
+/// use libmonero::utils::atomic_to_xmr;
+///
+/// assert_eq!(atomic_to_xmr(1_500_000_000_000), "1.5".to_string());
+/// assert_eq!(atomic_to_xmr(2_000_000_000_000), "2".to_string());
+/// ```
+pub fn atomic_to_xmr(atomic: u64) -> String {
+    let whole = atomic / PICONERO_PER_XMR;
+    let fraction = atomic % PICONERO_PER_XMR;
+    if fraction == 0 {
+        return whole.to_string();
+    }
+    let fraction = format!("{:0width$}", fraction, width = XMR_DECIMALS);
+    format!("{}.{}", whole, fraction.trim_end_matches('0'))
+}
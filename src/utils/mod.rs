@@ -9,5 +9,9 @@
  */
 
 pub(crate) mod utils;
+pub(crate) mod uri;
+pub(crate) mod amount;
 
-pub use utils::*;
\ No newline at end of file
+pub use utils::*;
+pub use uri::*;
+pub use amount::*;
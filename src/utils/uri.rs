@@ -0,0 +1,113 @@
+/*
+ * This file is part of Monero Builders' library libmonero
+ *
+ * Copyright (c) 2023-2024, Monero Builders (monero.builders)
+ * All Rights Reserved
+ * The code is distributed under MIT license, see LICENSE file for details.
+ * Generated by Monero Builders
+ *
+ */
+
+use crate::keys::{decode_address, KeyError};
+use super::amount::xmr_to_atomic;
+use url::{form_urlencoded, Url};
+
+/// PaymentUri struct contains all the information of a `monero:` payment URI
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentUri {
+    pub address: String,
+    /// Amount in XMR as a decimal string, e.g. `0.1`
+    pub amount: Option<String>,
+    pub payment_id: Option<String>,
+    pub recipient_name: Option<String>,
+    pub description: Option<String>,
+}
+
+/// PaymentUri functions etc.
+impl PaymentUri {
+    /// Turns the payment back into a `monero:` URI
+    ///
+    /// Example:
+    /// ```
+    /// use libmonero::utils::parse_payment_uri;
+    ///
+    /// let uri: &str = "monero:4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J?tx_amount=0.1&tx_description=Coffee%20and%20cake";
+    /// assert_eq!(parse_payment_uri(uri).unwrap().to_uri(), uri);
+    /// ```
+    pub fn to_uri(&self) -> String {
+        let mut params: Vec<(&str, &str)> = Vec::new();
+        if let Some(amount) = &self.amount {
+            params.push(("tx_amount", amount));
+        }
+        if let Some(payment_id) = &self.payment_id {
+            params.push(("tx_payment_id", payment_id));
+        }
+        if let Some(recipient_name) = &self.recipient_name {
+            params.push(("recipient_name", recipient_name));
+        }
+        if let Some(description) = &self.description {
+            params.push(("tx_description", description));
+        }
+        if params.is_empty() {
+            return format!("monero:{}", self.address);
+        }
+        let query: Vec<String> = params
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, encode_uri_component(value)))
+            .collect();
+        format!("monero:{}?{}", self.address, query.join("&"))
+    }
+}
+
+/// Percent-encodes given URI component, using `%20` for spaces
+fn encode_uri_component(value: &str) -> String {
+    form_urlencoded::byte_serialize(value.as_bytes())
+        .collect::<String>()
+        .replace('+', "%20")
+}
+
+/// Parses given `monero:` payment URI, validating the address in it
+///
+/// Example:
+/// ```
+/// use libmonero::utils::parse_payment_uri;
+///
+/// let uri = parse_payment_uri("monero:4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J?tx_amount=0.1&tx_description=Coffee%20and%20cake").unwrap();
+/// assert_eq!(uri.amount, Some("0.1".to_string()));
+/// assert_eq!(uri.description, Some("Coffee and cake".to_string()));
+/// ```
+pub fn parse_payment_uri(uri: &str) -> Result<PaymentUri, KeyError> {
+    let parsed = Url::parse(uri.trim()).map_err(|_| KeyError::InvalidUri)?;
+    if parsed.scheme() != "monero" {
+        return Err(KeyError::InvalidUri);
+    }
+    let address = parsed.path().to_string();
+    decode_address(&address)?;
+    let mut payment_uri = PaymentUri {
+        address,
+        amount: None,
+        payment_id: None,
+        recipient_name: None,
+        description: None,
+    };
+    for (key, value) in parsed.query_pairs() {
+        match key.as_ref() {
+            "tx_amount" => {
+                xmr_to_atomic(&value).map_err(|_| KeyError::InvalidUri)?;
+                payment_uri.amount = Some(value.to_string())
+            }
+            "tx_payment_id" => {
+                // Payment IDs are either 8 (short) or 32 (long) bytes
+                if !matches!(value.len(), 16 | 64) || hex::decode(value.as_ref()).is_err() {
+                    return Err(KeyError::InvalidUri);
+                }
+                payment_uri.payment_id = Some(value.to_string())
+            }
+            "recipient_name" => payment_uri.recipient_name = Some(value.to_string()),
+            "tx_description" => payment_uri.description = Some(value.to_string()),
+            // Unknown parameters are ignored, as the reference wallet does
+            _ => continue,
+        }
+    }
+    Ok(payment_uri)
+}
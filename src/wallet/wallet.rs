@@ -9,75 +9,315 @@
  */
 
 //! # Wallet
-//! 
+//!
 //! This module contains everything about wallets
 
-use std::collections::HashMap;
-use crate::keys;
+use crate::keys::{self, KeyError, Language, PolyseedSeed, SeedScheme};
+use curve25519_dalek::scalar::Scalar;
+use std::fmt;
 
-/// Wallet struct contains all the information about a wallet
+/// Version of the layout written by [`Wallet::to_secret_bytes`]
+const SECRET_BYTES_VERSION: u8 = 1;
+/// Length of the blob written by [`Wallet::to_secret_bytes`]: version, network and both private keys
+const SECRET_BYTES_LEN: usize = 66;
+
+/// Wallet struct contains the keys and the main address of a wallet
+///
+/// Its `Debug` output redacts the seeds and private keys, so they do not end up in logs. Use [`Wallet::reveal`] to
+/// print them.
+#[derive(Clone, PartialEq, Eq)]
 pub struct Wallet {
+    /// Mnemonic seed of the wallet, empty if the wallet was restored from its private keys
     pub mnemonic: Vec<String>,
+    /// Hex seed of the wallet, empty if the wallet was restored from its private keys
     pub hex_seed: String,
+    /// Network of the wallet, `0` for mainnet, `1` for testnet and `2` for stagenet
+    pub network: u8,
     pub priv_sk: String,
     pub priv_vk: String,
     pub pub_sk: String,
     pub pub_vk: String,
     pub main_address: String,
-    pub sub_adresses: Vec<String>,
-    pub transactions: Vec<Transaction>,
-    pub main_node: DaemonNode,
-    pub processes: HashMap<String, String>
 }
 
 /// Wallet implementation
 impl Wallet {
-    /// Creates a new wallet with given parameters
-    pub fn new(language: &str, seed_type: &str, network: u8, default_node: DaemonNode) -> Wallet {
-        let mnemonic = keys::generate_seed(language, seed_type);
-        let hex_seed = keys::derive_hex_seed(mnemonic.clone());
-        let priv_keys = keys::derive_priv_keys(hex_seed.clone());
-        let priv_sk = &priv_keys[0];
-        let priv_vk = &priv_keys[1];
-        let pub_sk = keys::derive_pub_key(priv_sk.to_string());
-        let pub_vk = keys::derive_pub_key(priv_vk.to_string());
-        let address = keys::derive_address(pub_sk.clone(), pub_vk.clone(), network);
-        Wallet {
-            mnemonic,
-            hex_seed,
-            priv_sk: priv_sk.to_string(),
-            priv_vk: priv_vk.to_string(),
-            pub_sk,
-            pub_vk,
-            main_address: address,
-            sub_adresses: Vec::new(),
-            transactions: Vec::new(),
-            main_node: default_node,
-            processes: HashMap::new()
-        }
+    /// Creates a new wallet with a new seed of given language and type, on given network
+    ///
+    /// The seed is generated like [`generate_seed_in`](keys::generate_seed_in): an unknown language gives
+    /// [`KeyError::UnknownLanguage`], an unknown seed type [`KeyError::UnknownSeedType`] and a language without a
+    /// wordset for the seed type [`KeyError::UnknownWordset`].
+    ///
+    /// Example:
+    /// ```
+    /// use libmonero::keys::KeyError;
+    /// use libmonero::wallet::Wallet;
+    ///
+    /// let wallet: Wallet = Wallet::new("en", "original", 0).unwrap();
+    /// assert_eq!(wallet.mnemonic.len(), 25);
+    /// assert!(wallet.main_address.starts_with('4'));
+    /// assert_eq!(Wallet::new("en", "bogus", 0), Err(KeyError::UnknownSeedType("bogus".to_string())));
+    /// ```
+    pub fn new(language: &str, seed_type: &str, network: u8) -> Result<Wallet, KeyError> {
+        let mnemonic = keys::generate_seed_in(language.parse::<Language>()?, seed_type)?;
+        Wallet::open_wallet(mnemonic, network)
     }
 
     /// Opens a wallet with given mnemonic and network
-    pub fn open_wallet(mnemonic: Vec<String>, network: u8, default_node: DaemonNode) -> Wallet {
-        let hex_seed = keys::derive_hex_seed(mnemonic.clone());
-        let priv_keys = keys::derive_priv_keys(hex_seed.clone());
-        let priv_sk = &priv_keys[0];
-        let priv_vk = &priv_keys[1];
-        let pub_sk = keys::derive_pub_key(priv_sk.to_string());
-        let pub_vk = keys::derive_pub_key(priv_vk.to_string());
-        let address = keys::derive_address(pub_sk.clone(), pub_vk.clone(), network);
-        Wallet {
-            mnemonic,
-            hex_seed,
-            priv_sk: priv_sk.to_string(),
-            priv_vk: priv_vk.to_string(),
+    ///
+    /// Original (25-word), MyMonero (13-word) and polyseed (16-word) seeds are accepted, polyseed seeds through
+    /// [`PolyseedSeed`], whose hex seed is the polyseed key.
+    ///
+    /// Example:
+    /// ```
+    /// use libmonero::wallet::Wallet;
+    ///
+    /// let mnemonic: Vec<String> = vec!["tissue", "raking", "haunted", "huts", "afraid", "volcano", "howls", "liar", "egotistic", "befit", "rounded", "older", "bluntly", "imbalance", "pivot", "exotic", "tuxedo", "amaze", "mostly", "lukewarm", "macro", "vocal", "hounded", "biplane", "rounded"].iter().map(|s| s.to_string()).collect();
+    /// let wallet: Wallet = Wallet::open_wallet(mnemonic, 0).unwrap();
+    /// assert_eq!(wallet.main_address, "4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J".to_string());
+    /// ```
+    pub fn open_wallet(mnemonic: Vec<String>, network: u8) -> Result<Wallet, KeyError> {
+        let (hex_seed, priv_sk, priv_vk) = if mnemonic.len() == keys::POLYSEED_WORD_COUNT {
+            let hex_seed = PolyseedSeed.to_hex_seed(&mnemonic)?;
+            let (priv_sk, priv_vk) = PolyseedSeed.derive_keys(&hex_seed)?;
+            (hex_seed, priv_sk, priv_vk)
+        } else {
+            let hex_seed = keys::derive_hex_seed(mnemonic.clone())?;
+            let priv_keys = keys::derive_priv_keypair(hex_seed.clone())?;
+            (hex_seed, priv_keys.spend, priv_keys.view)
+        };
+        let mut wallet = Wallet::from_priv_keys(priv_sk, priv_vk, network)?;
+        wallet.mnemonic = mnemonic;
+        wallet.hex_seed = hex_seed;
+        Ok(wallet)
+    }
+
+    /// Builds a wallet without seed from its private keys, deriving its public keys and main address
+    fn from_priv_keys(priv_sk: String, priv_vk: String, network: u8) -> Result<Wallet, KeyError> {
+        let pub_sk = keys::derive_pub_key(priv_sk.clone())?;
+        let pub_vk = keys::derive_pub_key(priv_vk.clone())?;
+        let main_address = keys::derive_address(pub_sk.clone(), pub_vk.clone(), network)?;
+        Ok(Wallet {
+            mnemonic: Vec::new(),
+            hex_seed: String::new(),
+            network,
+            priv_sk,
+            priv_vk,
             pub_sk,
             pub_vk,
-            main_address: address,
-            sub_adresses: Vec::new(),
-            transactions: Vec::new(),
-            main_node: default_node,
-            processes: HashMap::new()
+            main_address,
+        })
+    }
+
+    /// Serializes the secret material of the wallet into a fixed-layout blob
+    ///
+    /// Layout: version byte (`1`), network byte, 32-byte private spend key and 32-byte private view key. The seed is
+    /// not part of it, see [`Wallet::from_secret_bytes`] for loading it back.
+    ///
+    /// The private keys are public fields, keys replaced with something else than 32-byte hex strings give the error of
+    /// [`decode_hex32`](keys::decode_hex32).
+    ///
+    /// Example:
+    /// ```
+    /// use libmonero::wallet::Wallet;
+    ///
+    /// let mnemonic: Vec<String> = vec!["tissue", "raking", "haunted", "huts", "afraid", "volcano", "howls", "liar", "egotistic", "befit", "rounded", "older", "bluntly", "imbalance", "pivot", "exotic", "tuxedo", "amaze", "mostly", "lukewarm", "macro", "vocal", "hounded", "biplane", "rounded"].iter().map(|s| s.to_string()).collect();
+    /// let wallet: Wallet = Wallet::open_wallet(mnemonic, 0).unwrap();
+    /// let blob: [u8; 66] = wallet.to_secret_bytes().unwrap();
+    /// assert_eq!(blob[..2], [1, 0]);
+    /// assert_eq!(hex::encode(&blob[2..34]), wallet.priv_sk);
+    /// ```
+    pub fn to_secret_bytes(&self) -> Result<[u8; 66], KeyError> {
+        let mut blob = [0u8; SECRET_BYTES_LEN];
+        blob[0] = SECRET_BYTES_VERSION;
+        blob[1] = self.network;
+        blob[2..34].copy_from_slice(&keys::decode_hex32(&self.priv_sk)?);
+        blob[34..66].copy_from_slice(&keys::decode_hex32(&self.priv_vk)?);
+        Ok(blob)
+    }
+
+    /// Loads a wallet from a blob made by [`Wallet::to_secret_bytes`], re-deriving its public keys and main address
+    ///
+    /// The loaded wallet has no mnemonic nor hex seed. Blobs of the wrong length or holding non-reduced private keys
+    /// give [`KeyError::InvalidSecretBytes`], blobs of another version [`KeyError::UnsupportedVersion`].
+    ///
+    /// Example:
+    /// ```
+    /// use libmonero::keys::KeyError;
+    /// use libmonero::wallet::Wallet;
+    ///
+    /// let mnemonic: Vec<String> = vec!["tissue", "raking", "haunted", "huts", "afraid", "volcano", "howls", "liar", "egotistic", "befit", "rounded", "older", "bluntly", "imbalance", "pivot", "exotic", "tuxedo", "amaze", "mostly", "lukewarm", "macro", "vocal", "hounded", "biplane", "rounded"].iter().map(|s| s.to_string()).collect();
+    /// let wallet: Wallet = Wallet::open_wallet(mnemonic, 0).unwrap();
+    /// let loaded: Wallet = Wallet::from_secret_bytes(&wallet.to_secret_bytes().unwrap()).unwrap();
+    /// assert_eq!(loaded.main_address, wallet.main_address);
+    /// assert!(loaded.mnemonic.is_empty());
+    /// assert_eq!(Wallet::from_secret_bytes(&[1, 0]), Err(KeyError::InvalidSecretBytes));
+    /// ```
+    pub fn from_secret_bytes(bytes: &[u8]) -> Result<Wallet, KeyError> {
+        if bytes.len() != SECRET_BYTES_LEN {
+            return Err(KeyError::InvalidSecretBytes);
+        }
+        if bytes[0] != SECRET_BYTES_VERSION {
+            return Err(KeyError::UnsupportedVersion(bytes[0]));
+        }
+        // Private keys written by to_secret_bytes are always reduced scalars
+        for key in [&bytes[2..34], &bytes[34..66]] {
+            let key: [u8; 32] = key.try_into().expect("slice is 32 bytes");
+            if Option::<Scalar>::from(Scalar::from_canonical_bytes(key)).is_none() {
+                return Err(KeyError::InvalidSecretBytes);
+            }
+        }
+        Wallet::from_priv_keys(hex::encode(&bytes[2..34]), hex::encode(&bytes[34..66]), bytes[1])
+    }
+
+    /// Formats the wallet like `Debug`, with the seeds and private keys redacted or not
+    fn fmt_wallet(&self, f: &mut fmt::Formatter, redact: bool) -> fmt::Result {
+        f.debug_struct("Wallet")
+            .field("mnemonic", keys::secret(&self.mnemonic, redact))
+            .field("hex_seed", keys::secret(&self.hex_seed, redact))
+            .field("network", &self.network)
+            .field("priv_sk", keys::secret(&self.priv_sk, redact))
+            .field("priv_vk", keys::secret(&self.priv_vk, redact))
+            .field("pub_sk", &self.pub_sk)
+            .field("pub_vk", &self.pub_vk)
+            .field("main_address", &self.main_address)
+            .finish()
+    }
+
+    /// Returns the `Debug` output of the wallet with the seeds and private keys shown
+    ///
+    /// > WARNING: The output holds the seed and private keys, anyone who gets it can spend the funds of the wallet
+    ///
+    /// Example:
+    /// ```
+    /// use libmonero::wallet::Wallet;
+    ///
+    /// let wallet: Wallet = Wallet::new("en", "original", 0).unwrap();
+    /// assert!(!format!("{:?}", wallet).contains(&wallet.priv_sk));
+    /// assert!(format!("{:?}", wallet).contains(&wallet.main_address));
+    /// assert!(wallet.reveal().contains(&wallet.priv_sk));
+    /// ```
+    pub fn reveal(&self) -> String {
+        format!("{:?}", keys::DebugWith(|f| self.fmt_wallet(f, false)))
+    }
+}
+
+impl fmt::Debug for Wallet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_wallet(f, true)
+    }
+}
+
+/// KeysFileFields contains the fields of the JSON file `monero-wallet-cli --generate-from-json` restores a wallet from,
+/// named as in the file
+///
+/// Its `Debug` output redacts the private keys and the seed, use [`KeysFileFields::reveal`] to print them.
+#[derive(Clone, PartialEq, Eq)]
+pub struct KeysFileFields {
+    /// Version of the file format, always `1`
+    pub version: u8,
+    pub address: String,
+    /// Private view key
+    pub viewkey: String,
+    /// Private spend key
+    pub spendkey: String,
+    /// Original (25-word) mnemonic seed, empty for wallets without one (`monero-wallet-cli` only restores those)
+    pub seed: String,
+}
+
+impl KeysFileFields {
+    /// Serializes the fields into the JSON taken by `monero-wallet-cli --generate-from-json`, without an empty seed
+    ///
+    /// The file also needs a `filename` and a `password` for the new wallet, which are up to the user to add.
+    pub fn to_json(&self) -> String {
+        let mut json = serde_json::json!({
+            "version": self.version,
+            "address": self.address,
+            "viewkey": self.viewkey,
+            "spendkey": self.spendkey,
+        });
+        if !self.seed.is_empty() {
+            json["seed"] = serde_json::Value::String(self.seed.clone());
         }
+        json.to_string()
+    }
+
+    /// Formats the fields like `Debug`, with the private keys and the seed redacted or not
+    fn fmt_fields(&self, f: &mut fmt::Formatter, redact: bool) -> fmt::Result {
+        f.debug_struct("KeysFileFields")
+            .field("version", &self.version)
+            .field("address", &self.address)
+            .field("viewkey", keys::secret(&self.viewkey, redact))
+            .field("spendkey", keys::secret(&self.spendkey, redact))
+            .field("seed", keys::secret(&self.seed, redact))
+            .finish()
+    }
+
+    /// Returns the `Debug` output of the fields with the private keys and the seed shown
+    ///
+    /// > WARNING: The output holds the private keys, anyone who gets it can spend the funds of the wallet
+    pub fn reveal(&self) -> String {
+        format!("{:?}", keys::DebugWith(|f| self.fmt_fields(f, false)))
     }
-}
\ No newline at end of file
+}
+
+impl fmt::Debug for KeysFileFields {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_fields(f, true)
+    }
+}
+
+/// Produces the fields of a `monero-wallet-cli --generate-from-json` file for given wallet
+///
+/// The main address of the wallet must be the one derived from its private keys, else [`KeyError::AddressMismatch`] is
+/// given. Only original (25-word) mnemonic seeds are included, `monero-wallet-cli` restores other wallets from their keys.
+///
+/// Example:
+/// ```
+/// use libmonero::keys::import_from_wallet_json;
+/// use libmonero::wallet::{to_keys_file_fields, KeysFileFields, Wallet};
+///
+/// let mnemonic: Vec<String> = vec!["tissue", "raking", "haunted", "huts", "afraid", "volcano", "howls", "liar", "egotistic", "befit", "rounded", "older", "bluntly", "imbalance", "pivot", "exotic", "tuxedo", "amaze", "mostly", "lukewarm", "macro", "vocal", "hounded", "biplane", "rounded"].iter().map(|s| s.to_string()).collect();
+/// let wallet: Wallet = Wallet::open_wallet(mnemonic, 0).unwrap();
+/// let fields: KeysFileFields = to_keys_file_fields(&wallet).unwrap();
+/// assert_eq!(fields.spendkey, "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08".to_string());
+/// assert_eq!(import_from_wallet_json(&fields.to_json()).unwrap().pub_view, wallet.pub_vk);
+/// ```
+pub fn to_keys_file_fields(wallet: &Wallet) -> Result<KeysFileFields, KeyError> {
+    if keys::address_from_priv_keys(&wallet.priv_sk, &wallet.priv_vk, wallet.network)? != wallet.main_address {
+        return Err(KeyError::AddressMismatch);
+    }
+    Ok(KeysFileFields {
+        version: 1,
+        address: wallet.main_address.clone(),
+        viewkey: wallet.priv_vk.clone(),
+        spendkey: wallet.priv_sk.clone(),
+        seed: match wallet.mnemonic.len() {
+            25 => wallet.mnemonic.join(" "),
+            _ => String::new(),
+        },
+    })
+}
+
+/// Derives a throwaway stagenet wallet from given label, for documentation, demos and tests
+///
+/// The hex seed is `Keccak(label)`, so the same label always gives the same wallet and anyone knowing the label can
+/// spend from it. Requires the `demo` feature.
+///
+/// > WARNING: Demo wallets are NOT secure, never send real funds to them
+///
+/// Example:
+/// ```
+/// use libmonero::wallet::{demo_wallet, Wallet};
+///
+/// let wallet: Wallet = demo_wallet("alice");
+/// assert_eq!(wallet, demo_wallet("alice"));
+/// assert_ne!(wallet.main_address, demo_wallet("bob").main_address);
+/// assert!(wallet.main_address.starts_with('5'));
+/// ```
+#[cfg(feature = "demo")]
+pub fn demo_wallet(label: &str) -> Wallet {
+    let mnemonic = keys::generate_seed_from_entropy("en", "original", &crate::crypt::cn_fast_hash(label.as_bytes()))
+        .expect("32 bytes make an English original seed");
+    Wallet::open_wallet(mnemonic, 2).expect("any 32 bytes make a valid hex seed")
+}
@@ -0,0 +1,19 @@
+#![cfg(feature = "demo")]
+
+#[cfg(test)]
+mod demo {
+    use libmonero::keys::{derive_hex_seed, Network};
+    use libmonero::wallet::demo_wallet;
+
+    #[test]
+    fn demo_wallets() {
+        let wallet = demo_wallet("alice");
+        assert_eq!(wallet.hex_seed, "9c0257114eb9399a2985f8e75dad7600c5d89fe3824ffa99ec1c3eb8bf3b0501");
+        assert_eq!(wallet.priv_vk, "6810e88fbc8d0bdebd0e8eb5337b74d200310bfc1bc5dfd0f52533c3a4edfa07");
+        assert_eq!(wallet.main_address, "54Lz8Ejv1kGi9bVEbeKdvd5JS2uSLga9161ETQd6EnF3RE5g41tgqnGd6R9HjBeXmSA6P7MaEYijb82esbguLGmPUgo8pwQ");
+        assert_eq!(wallet.network, Network::Stagenet as u8);
+        assert_eq!(derive_hex_seed(wallet.mnemonic.clone()), Ok(wallet.hex_seed.clone()));
+        assert_ne!(demo_wallet("Alice").main_address, wallet.main_address);
+        assert_ne!(demo_wallet("").main_address, wallet.main_address);
+    }
+}
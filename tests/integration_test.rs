@@ -1,7 +1,17 @@
 #[cfg(test)]
 mod tests {
-    use libmonero::keys::{derive_address, derive_hex_seed, derive_priv_keys, derive_pub_key, generate_seed};
+    use libmonero::keys::{bip39_to_monero_keys, combine_seed, split_seed, BIP39_WORD_COUNTS};
+    use libmonero::keys::{derive_child_keys, derive_priv_keys_checked, derive_priv_keys_typed, derive_priv_keys_with_passphrase, derive_priv_keys_with_case, derive_pub_key_with_case, HexCase};
+    use libmonero::keys::{derive_address, derive_address_bytes, derive_address_for_coin, derive_all_network_addresses, derive_all_keys, derive_hex_seed, derive_hex_seed_ct, derive_hex_seeds_batch, derive_integrated_address, derive_integrated_address_full, derive_subaddress, derive_subaddress_range, derive_priv_keypair, derive_priv_keys, derive_priv_vk_from_priv_sk, derive_pub_key, derive_pub_key_bytes, derive_pub_key_checked, generate_seed, verify_keys_consistency, verify_wordset_integrity, wallet_addresses, wordset_info, Coin, KeyError, Keys};
+    use libmonero::keys::{address_from_priv_keys, catch_key_panic, address_public_spend_key, address_public_view_key, address_to_bytes, bytes_to_address, append_checksum_word, checksum_word_index, classify_seed, complete_word, decode_address, decode_hex32, decrypt_polyseed, detect_polyseed_language, encrypt_polyseed, expected_word_count, estimate_restore_height, is_valid_word, join_polyseed_words, split_polyseed_phrase, word_matches_prefix, generate_seed_from_entropy, generate_seed_iter, generate_seed_with_strength, polyseed_birthday, polyseed_checksum_valid, polyseed_entropy, POLYSEED_WORD_COUNTS, spend_key_to_mnemonic, derive_key_derivation, derive_output_public_key, derive_output_scalar, derive_view_tag, import_from_wallet_json, keys_from_spend_scalar, is_output_ours, is_polyseed_encrypted, scan_outputs, self_test, seed_from_dice, sign_message, verify_message, mnemonic_matches_address, normalize_address, normalize_hex_seed, parse_hex_keys, payment_id_encryption_key, validate_mnemonic, weak_seed_check, AddressType, Network, SeedKind};
+    use libmonero::keys::{detect_language, generate_seed_in, subaddress_label_key, SubaddressIndexCache, Language, PrivKeyPair, RestoreHeightEstimator, MyMoneroSeed, OriginalSeed, PolyseedSeed, SeedBuilder, SeedScheme};
+    use libmonero::wallet::{to_keys_file_fields, Wallet};
+    use rand::{rngs::StdRng, SeedableRng};
+    use libmonero::utils::{atomic_to_xmr, parse_payment_uri, xmr_to_atomic};
+    use unicode_normalization::UnicodeNormalization;
     use libmonero::crypt::cryptonight::cn_slow_hash_v0;
+    use libmonero::crypt::{cn_fast_hash, keccak_prf, mul8, needed_reduction, reduce_scalar};
+    use curve25519_dalek::{edwards::CompressedEdwardsY, traits::IsIdentity, Scalar};
 
     #[test]
     fn seed_generation() {
@@ -13,23 +23,1664 @@ mod tests {
     fn key_derivation() {
         // five saved himself oust taunts pebbles fibula organs koala copy dying vein damp dauntless code gags copy roster geek toolbox joyous apart unlikely warped taunts
         let mnemonic = ["five", "saved", "himself", "oust", "taunts", "pebbles", "fibula", "organs", "koala", "copy", "dying", "vein", "damp", "dauntless", "code", "gags", "copy", "roster", "geek", "toolbox", "joyous", "apart", "unlikely", "warped", "taunts"].to_vec().iter().map(|s| s.to_string()).collect::<Vec<String>>();
-        let hex_seed = derive_hex_seed(mnemonic);
+        let hex_seed = derive_hex_seed(mnemonic).unwrap();
         assert_eq!(hex_seed.clone(), "6bdaf7a0a8f3f1ce4767d6d9c38b72b48ccc3ffa4f60be91389b1b96403ff20e".to_string());
-        let priv_keys = derive_priv_keys(hex_seed);
+        let priv_keys = derive_priv_keys(hex_seed).unwrap();
         let priv_sk = &priv_keys[0];
         let priv_vk = &priv_keys[1];
         assert_eq!(priv_sk, &"6bdaf7a0a8f3f1ce4767d6d9c38b72b48ccc3ffa4f60be91389b1b96403ff20e".to_string());
         assert_eq!(priv_vk, &"490447bf98677377923b4da400fa2b7e6dff6dff0ca24f7ae533a8207fd27c00".to_string());
-        let pub_sk = derive_pub_key(priv_sk.clone());
+        let pub_sk = derive_pub_key(priv_sk.clone()).unwrap();
         assert_eq!(pub_sk.clone(), "03970285bf0724d75e0f50bca9a9ea0e8db5091b69403dc944465f8936bde787".to_string());
-        let pub_vk = derive_pub_key(priv_keys[1].clone());
+        let pub_vk = derive_pub_key(priv_keys[1].clone()).unwrap();
         assert_eq!(pub_vk.clone(), "528a736a5079dc9536edb5b6fa0a5209ce820b9734fc0785024670b3d3ba4c69".to_string());
-        let addr = derive_address(pub_sk, pub_vk, 0);
+        let addr = derive_address(pub_sk, pub_vk, 0).unwrap();
         assert_eq!(addr, "41kztevQ9HVd2LMni56Ka13SBt6k9qFH6afYGWyXfWnJPdoEE86mHddRxZxPtAwdZb2e8wsZdiFyxPFMTtaWp14PCxPF3wT".to_string());
     }
 
-    #[cfg(test)]
-    #[allow(warnings)]
+    #[test]
+    fn spend_key_recovery() {
+        // The hex seed of "tissue raking ..." is not reduced, so the recovered words differ but restore the same keys
+        let priv_keys = derive_priv_keys("f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8".to_string()).unwrap();
+        assert_eq!(priv_keys[0], "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08".to_string());
+        let mnemonic = spend_key_to_mnemonic(&priv_keys[0], "en").unwrap();
+        assert_eq!(mnemonic.len(), 25);
+        assert_ne!(mnemonic[0], "tissue".to_string());
+        assert_eq!(derive_priv_keys(derive_hex_seed(mnemonic).unwrap()).unwrap(), priv_keys);
+        assert_eq!(spend_key_to_mnemonic(&priv_keys[0], "xx"), Err(KeyError::UnknownWordset));
+        assert_eq!(spend_key_to_mnemonic("c898", "en"), Err(KeyError::InvalidHexLength { got: 4, expected: 64 }));
+    }
+
+    #[test]
+    fn hex_case() {
+        let hex_seed = "6bdaf7a0a8f3f1ce4767d6d9c38b72b48ccc3ffa4f60be91389b1b96403ff20e".to_string();
+        let lower = derive_priv_keys(hex_seed.clone()).unwrap();
+        assert_eq!(derive_priv_keys_with_case(hex_seed.clone(), HexCase::default()).unwrap(), lower);
+        let upper = derive_priv_keys_with_case(hex_seed.clone(), HexCase::Upper).unwrap();
+        assert_eq!(upper, vec!["6BDAF7A0A8F3F1CE4767D6D9C38B72B48CCC3FFA4F60BE91389B1B96403FF20E".to_string(), "490447BF98677377923B4DA400FA2B7E6DFF6DFF0CA24F7AE533A8207FD27C00".to_string()]);
+        // Uppercase keys are accepted as input too
+        assert_eq!(derive_priv_keys(hex_seed.to_uppercase()).unwrap(), lower);
+        assert_eq!(derive_pub_key_with_case(upper[1].clone(), HexCase::Lower).unwrap(), "528a736a5079dc9536edb5b6fa0a5209ce820b9734fc0785024670b3d3ba4c69".to_string());
+        assert_eq!(derive_pub_key_with_case(upper[1].clone(), HexCase::Upper).unwrap(), "528A736A5079DC9536EDB5B6FA0A5209CE820B9734FC0785024670B3D3BA4C69".to_string());
+        assert_eq!(derive_priv_keys_with_case("6bda".to_string(), HexCase::Upper), Err(KeyError::InvalidHexSeed));
+    }
+
+    #[test]
+    fn seed_sharing() {
+        let hex_seed = "f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8";
+        let shares = split_seed(hex_seed, 3, 5).unwrap();
+        assert_eq!(shares.len(), 5);
+        assert!(shares.iter().all(|share| share.len() == 68));
+        // Every 3 of the 5 shares restore the seed, in any order
+        for a in 0..5 {
+            for b in 0..5 {
+                for c in 0..5 {
+                    let subset = [shares[a].clone(), shares[b].clone(), shares[c].clone()];
+                    if a != b && b != c && a != c {
+                        assert_eq!(combine_seed(&subset), Ok(hex_seed.to_string()));
+                    } else {
+                        assert_eq!(combine_seed(&subset), Err(KeyError::InvalidShares));
+                    }
+                }
+            }
+            assert_eq!(combine_seed(&[shares[a].clone(), shares[(a + 1) % 5].clone()]), Err(KeyError::NotEnoughShares { got: 2, threshold: 3 }));
+        }
+        assert_eq!(combine_seed(&shares), Ok(hex_seed.to_string()));
+        // Shares of different splits do not mix
+        let other = split_seed(hex_seed, 2, 2).unwrap();
+        assert_eq!(combine_seed(&[shares[0].clone(), shares[1].clone(), other[1].clone()]), Err(KeyError::InvalidShares));
+        // MyMonero hex seeds, and the 1-of-1 split which is the seed itself
+        let hex_seed = "dd9c6f9a4eb2e22a1ebbba79ca79db5a";
+        let shares = split_seed(hex_seed, 1, 1).unwrap();
+        assert_eq!(shares, vec![format!("0101{}", hex_seed)]);
+        assert_eq!(combine_seed(&shares), Ok(hex_seed.to_string()));
+        let shares = split_seed(hex_seed, 255, 255).unwrap();
+        assert_eq!(combine_seed(&shares), Ok(hex_seed.to_string()));
+        assert_eq!(combine_seed(&shares[1..]), Err(KeyError::NotEnoughShares { got: 254, threshold: 255 }));
+        // Malformed input
+        assert_eq!(split_seed(hex_seed, 0, 3), Err(KeyError::InvalidThreshold { threshold: 0, shares: 3 }));
+        assert_eq!(split_seed(hex_seed, 4, 3), Err(KeyError::InvalidThreshold { threshold: 4, shares: 3 }));
+        assert_eq!(split_seed("dd9c", 2, 3), Err(KeyError::InvalidHexSeed));
+        assert_eq!(combine_seed(&[]), Err(KeyError::NotEnoughShares { got: 0, threshold: 1 }));
+        assert_eq!(combine_seed(&["zz".to_string()]), Err(KeyError::InvalidShares));
+        assert_eq!(combine_seed(&["01".to_string()]), Err(KeyError::InvalidShares));
+        assert_eq!(combine_seed(&[format!("0100{}", hex_seed)]), Err(KeyError::InvalidShares));
+    }
+
+    #[test]
+    fn passphrase_keys() {
+        let hex_seed = "f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8".to_string();
+        // cn_slow_hash("hunter2") is subtracted from the seed, see hashing_cn_slow_hash_v0 for the hash's known answer
+        assert_eq!(cn_slow_hash_v0(b"hunter2"), "756751dcb6e17721570b6fdee1ea8cbba4887a675c0a52c8937dd9564ed413b4");
+        let priv_keys = derive_priv_keys_with_passphrase(hex_seed.clone(), "hunter2").unwrap();
+        assert_eq!(priv_keys, vec!["824c6dcf12dcf4cb2f3527e2a72f0224f053f7d91b964660e3dfe0aa650b8704".to_string(), "73f4c55f2206fef0bcd6282693d580641c98b6805db6e167bbd1031996460805".to_string()]);
+        assert_eq!(derive_priv_keys_with_passphrase(hex_seed.clone(), ""), derive_priv_keys(hex_seed));
+        let mymonero_hex_seed = "dd9c6f9a4eb2e22a1ebbba79ca79db5a".to_string();
+        assert_eq!(derive_priv_keys_with_passphrase(mymonero_hex_seed.clone(), ""), derive_priv_keys(mymonero_hex_seed.clone()));
+        assert_eq!(derive_priv_keys_with_passphrase(mymonero_hex_seed, "hunter2"), Err(KeyError::InvalidHexSeed));
+    }
+
+    #[test]
+    fn invalid_word_position() {
+        let mut mnemonic = ["tissue", "raking", "haunted", "huts", "afraid", "volcano", "howls", "liar", "egotistic", "befit", "rounded", "older", "bluntly", "imbalance", "pivot", "exotic", "tuxedo", "amaze", "mostly", "lukewarm", "macro", "vocal", "hounded", "biplane", "rounded"].iter().map(|s| s.to_string()).collect::<Vec<String>>();
+        mnemonic[5] = "volcanoe".to_string();
+        mnemonic[7] = "lier".to_string();
+        assert_eq!(derive_hex_seed(mnemonic), Err(KeyError::InvalidWord { position: 5, word: "volcanoe".to_string() }));
+    }
+
+    #[test]
+    fn weak_seeds() {
+        assert!(weak_seed_check("ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"));
+        assert!(weak_seed_check("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"));
+        assert!(weak_seed_check("0102030001020300010203000102030001020300010203000102030001020300"));
+        assert!(weak_seed_check("00000000000000000000000000000000000000000000000000000000000000ff"));
+        assert!(weak_seed_check("not hex"));
+        // Unusual but random-looking seeds are fine
+        assert!(!weak_seed_check("6bdaf7a0a8f3f1ce4767d6d9c38b72b48ccc3ffa4f60be91389b1b96403ff20e"));
+        assert!(!weak_seed_check("00000000a8f3f1ce4767d6d9c38b72b48ccc3ffa4f60be91389b1b96403ff20e"));
+        for _ in 0..100 {
+            let hex_seed = derive_hex_seed(generate_seed("en", "original")).unwrap();
+            assert!(!weak_seed_check(&hex_seed));
+        }
+    }
+
+    #[test]
+    fn seed_word_count() {
+        let mnemonic = ["five", "saved", "himself", "oust", "taunts", "pebbles", "fibula", "organs", "koala", "copy", "dying", "vein", "damp", "dauntless", "code", "gags", "copy", "roster", "geek", "toolbox", "joyous", "apart", "unlikely", "warped", "taunts"].iter().map(|s| s.to_string()).collect::<Vec<String>>();
+        // Without the checksum word, the hex seed is the same
+        assert_eq!(derive_hex_seed(mnemonic[..24].to_vec()), derive_hex_seed(mnemonic.clone()));
+        for count in [0, 16, 23, 26] {
+            let mut words = mnemonic.clone();
+            words.resize(count, "five".to_string());
+            match derive_hex_seed(words) {
+                Err(KeyError::WrongWordCount { got, expected }) => {
+                    assert_eq!(got, count);
+                    assert_eq!(expected, &[12, 13, 24, 25]);
+                }
+                other => panic!("Unexpected result for {} words: {:?}", count, other),
+            }
+        }
+    }
+
+    #[test]
+    fn checksum_word_errors() {
+        let mnemonic = ["five", "saved", "himself", "oust", "taunts", "pebbles", "fibula", "organs", "koala", "copy", "dying", "vein", "damp", "dauntless", "code", "gags", "copy", "roster", "geek", "toolbox", "joyous", "apart", "unlikely", "warped"].iter().map(|s| s.to_string()).collect::<Vec<String>>();
+        assert_eq!(append_checksum_word(mnemonic.clone(), "xx"), Err(KeyError::UnknownWordset));
+        assert_eq!(append_checksum_word(mnemonic[..23].to_vec(), "en"), Err(KeyError::WrongWordCount { got: 23, expected: &[12, 24] }));
+        assert_eq!(append_checksum_word(mnemonic.clone(), "fr"), Err(KeyError::InvalidWord { position: 0, word: "five".to_string() }));
+        assert_eq!(append_checksum_word(mnemonic, "en").unwrap()[24], "taunts".to_string());
+    }
+
+    #[test]
+    fn all_keys_derivation() {
+        let mnemonic = ["five", "saved", "himself", "oust", "taunts", "pebbles", "fibula", "organs", "koala", "copy", "dying", "vein", "damp", "dauntless", "code", "gags", "copy", "roster", "geek", "toolbox", "joyous", "apart", "unlikely", "warped", "taunts"].iter().map(|s| s.to_string()).collect::<Vec<String>>();
+        let keys = derive_all_keys(mnemonic.clone()).unwrap();
+        assert_eq!(keys.priv_spend, "6bdaf7a0a8f3f1ce4767d6d9c38b72b48ccc3ffa4f60be91389b1b96403ff20e".to_string());
+        assert_eq!(keys.priv_view, "490447bf98677377923b4da400fa2b7e6dff6dff0ca24f7ae533a8207fd27c00".to_string());
+        assert_eq!(keys.pub_spend, "03970285bf0724d75e0f50bca9a9ea0e8db5091b69403dc944465f8936bde787".to_string());
+        assert_eq!(keys.pub_view, "528a736a5079dc9536edb5b6fa0a5209ce820b9734fc0785024670b3d3ba4c69".to_string());
+        let mut invalid = mnemonic;
+        invalid[3] = "notaword".to_string();
+        assert_eq!(derive_all_keys(invalid), Err(KeyError::InvalidWord { position: 3, word: "notaword".to_string() }));
+        let unknown = vec!["notaword".to_string(); 25];
+        assert_eq!(derive_all_keys(unknown), Err(KeyError::UnknownWordset));
+    }
+
+    #[test]
+    fn address_from_private_keys() {
+        let priv_sk = "6bdaf7a0a8f3f1ce4767d6d9c38b72b48ccc3ffa4f60be91389b1b96403ff20e";
+        let priv_vk = "490447bf98677377923b4da400fa2b7e6dff6dff0ca24f7ae533a8207fd27c00";
+        let addr = address_from_priv_keys(priv_sk, priv_vk, 0).unwrap();
+        assert_eq!(addr, "41kztevQ9HVd2LMni56Ka13SBt6k9qFH6afYGWyXfWnJPdoEE86mHddRxZxPtAwdZb2e8wsZdiFyxPFMTtaWp14PCxPF3wT".to_string());
+        assert_eq!(address_from_priv_keys(priv_sk, priv_vk, 3), Err(KeyError::InvalidNetwork));
+        assert_eq!(address_from_priv_keys(priv_sk, "not hex", 0), Err(KeyError::InvalidHexLength { got: 7, expected: 64 }));
+    }
+
+    #[test]
+    fn wallet_json_import() {
+        let address = "41kztevQ9HVd2LMni56Ka13SBt6k9qFH6afYGWyXfWnJPdoEE86mHddRxZxPtAwdZb2e8wsZdiFyxPFMTtaWp14PCxPF3wT";
+        let view_key = "490447bf98677377923b4da400fa2b7e6dff6dff0ca24f7ae533a8207fd27c00";
+        let spend_key = "6bdaf7a0a8f3f1ce4767d6d9c38b72b48ccc3ffa4f60be91389b1b96403ff20e";
+        let full = import_from_wallet_json(&format!(r#"{{"address": "{}", "viewkey": "{}", "spendkey": "{}"}}"#, address, view_key, spend_key)).unwrap();
+        assert_eq!(full.priv_spend, spend_key.to_string());
+        assert_eq!(full.pub_view, "528a736a5079dc9536edb5b6fa0a5209ce820b9734fc0785024670b3d3ba4c69".to_string());
+        let view_only = import_from_wallet_json(&format!(r#"{{"address": "{}", "viewkey": "{}"}}"#, address, view_key)).unwrap();
+        assert_eq!(view_only.priv_spend, "".to_string());
+        assert_eq!(view_only.pub_spend, full.pub_spend);
+        // View key of another wallet
+        let other_view_key = "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908";
+        assert_eq!(import_from_wallet_json(&format!(r#"{{"address": "{}", "viewkey": "{}"}}"#, address, other_view_key)), Err(KeyError::AddressMismatch));
+        assert_eq!(import_from_wallet_json(&format!(r#"{{"viewkey": "{}"}}"#, view_key)), Err(KeyError::MissingField("address")));
+        assert_eq!(import_from_wallet_json("{"), Err(KeyError::InvalidJson));
+    }
+
+    #[test]
+    fn view_tags() {
+        // First two vectors are from Monero's tests/crypto/tests.txt, others check multi-byte varint indexes
+        let shared_secret = hex::decode("0fc47054f355ced4d67de73bfa12e4c78ff19089548fffa7d07a674741860f97").unwrap();
+        assert_eq!(derive_view_tag(&shared_secret, 0), 0x76);
+        assert_eq!(derive_view_tag(&shared_secret, 1), 0xd6);
+        assert_eq!(derive_view_tag(&shared_secret, 127), 0x2c);
+        assert_eq!(derive_view_tag(&shared_secret, 128), 0x11);
+        assert_eq!(derive_view_tag(&shared_secret, 65537), 0x48);
+    }
+
+    #[test]
+    fn key_derivation_and_output_scalar() {
+        let private_view_key = "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908";
+        let tx_pub_key = "10fccee43c0612968c2db809b140abbf1c799fe6158305be1c023509f3ae9a94";
+        let derivation = "7e78087373ccb7ee318352660e0daf3a24ebaa6af7b0f836b1f56f9e2e3dfa3e";
+        assert_eq!(derive_key_derivation(tx_pub_key, private_view_key).unwrap(), derivation.to_string());
+        // Sender side: 8 * r * A gives the same derivation
+        let tx_priv_key = "0172268a8aeda7e94ac5cda14ac05f4d2f7ad2524f397c395a89c940c01b0608";
+        let public_view_key = "157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47";
+        assert_eq!(derive_key_derivation(public_view_key, tx_priv_key).unwrap(), derivation.to_string());
+        // Transaction public key with an added order-2 component, cleared by the cofactor
+        let torsioned_tx_pub_key = "dd03311bc3f9ed6973d247f64ebf5440e3866019ea7cfa41e3fdcaf60c51656b";
+        assert_eq!(derive_key_derivation(torsioned_tx_pub_key, private_view_key).unwrap(), derivation.to_string());
+        assert_eq!(derive_output_scalar(derivation, 1).unwrap(), "1dbab0ed1c57e3e4a608923b8e2b477cc92ee3d15837007788dac31255c75807".to_string());
+        assert_eq!(derive_output_scalar(derivation, 200).unwrap(), "fb1a3bab8e2eefe5d9d0d9f85658109d469817d72ceac5110cd443a72890550c".to_string());
+        assert_eq!(derive_key_derivation("10fc", private_view_key), Err(KeyError::InvalidHexLength { got: 4, expected: 64 }));
+    }
+
+    #[test]
+    fn payment_id_encryption() {
+        let derivation = "7e78087373ccb7ee318352660e0daf3a24ebaa6af7b0f836b1f56f9e2e3dfa3e";
+        let key = payment_id_encryption_key(derivation).unwrap();
+        assert_eq!(key, [0x25, 0xb2, 0xd7, 0xde, 0x9b, 0x4b, 0x52, 0xe9]);
+        // The receiver decrypts with the same key the sender encrypted with
+        let payment_id = hex::decode("0123456789abcdef").unwrap();
+        let encrypted: Vec<u8> = payment_id.iter().zip(key).map(|(a, b)| a ^ b).collect();
+        assert_eq!(hex::encode(&encrypted), "249192b912e09f06");
+        let sender_derivation = derive_key_derivation("157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47", "0172268a8aeda7e94ac5cda14ac05f4d2f7ad2524f397c395a89c940c01b0608").unwrap();
+        let decrypted: Vec<u8> = encrypted.iter().zip(payment_id_encryption_key(&sender_derivation).unwrap()).map(|(a, b)| a ^ b).collect();
+        assert_eq!(decrypted, payment_id);
+        assert_eq!(hex::encode(payment_id_encryption_key("0fc47054f355ced4d67de73bfa12e4c78ff19089548fffa7d07a674741860f97").unwrap()), "7952099e267dc315");
+        assert_eq!(payment_id_encryption_key("7e78"), Err(KeyError::InvalidHexLength { got: 4, expected: 64 }));
+    }
+
+    #[test]
+    fn output_ownership() {
+        // From Monero's tests/crypto/tests.txt, generate_key_derivation of a public key and a secret key
+        assert_eq!(
+            derive_key_derivation("fdfd97d2ea9f1c25df773ff2c973d885653a3ee643157eb0ae2b6dd98f0b6984", "eb2bd1cf0c5e074f9dbf38ebbc99c316f54e21803048c687a3bb359f7a713b02"),
+            Ok("4e0bd2c41325a1b89a9f7413d4d05e0a5a4936f241dccc3c7d0c539ffe00ef67".to_string())
+        );
+        // The outputs of that vector's derivation to a wallet at indices 0 and 300, computed by
+        // tests/vectors/generate.py apart from the crate
+        let public_spend_key = "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95";
+        let (vector_tx_pub_key, vector_view_key) = ("fdfd97d2ea9f1c25df773ff2c973d885653a3ee643157eb0ae2b6dd98f0b6984", "eb2bd1cf0c5e074f9dbf38ebbc99c316f54e21803048c687a3bb359f7a713b02");
+        assert!(is_output_ours(vector_tx_pub_key, vector_view_key, public_spend_key, "3d4c5b454e01695291df43018424fd1865b2b081eaef5ad512914b2804b83cf8", 0));
+        assert!(is_output_ours(vector_tx_pub_key, vector_view_key, public_spend_key, "d826c493e06cb38615e7c11b4f9e6d2ac9330b19802eaca4fbd71766db301231", 300));
+        assert!(!is_output_ours(vector_tx_pub_key, vector_view_key, public_spend_key, "d826c493e06cb38615e7c11b4f9e6d2ac9330b19802eaca4fbd71766db301231", 1));
+        // Outputs to the wallet of the crate docs, also computed by tests/vectors/generate.py
+        let tx_pub_key = "10fccee43c0612968c2db809b140abbf1c799fe6158305be1c023509f3ae9a94";
+        let private_view_key = "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908";
+        let outputs = [
+            "30660d6645e727e6e6981a2956bc5590e7d2943c8de1f20f6a7fd3f57316e43f",
+            "6d0167a993c7bd6d0b48dc8fd6b603c95e82105b6383da45490c602f58268762",
+        ];
+        assert!(is_output_ours(tx_pub_key, private_view_key, public_spend_key, outputs[0], 0));
+        assert!(is_output_ours(tx_pub_key, private_view_key, public_spend_key, outputs[1], 1));
+        // Wrong index, wrong wallet and malformed keys
+        assert!(!is_output_ours(tx_pub_key, private_view_key, public_spend_key, outputs[1], 0));
+        let other_public_spend_key = "528a736a5079dc9536edb5b6fa0a5209ce820b9734fc0785024670b3d3ba4c69";
+        assert!(!is_output_ours(tx_pub_key, private_view_key, other_public_spend_key, outputs[0], 0));
+        assert!(!is_output_ours(tx_pub_key, private_view_key, public_spend_key, "3066", 0));
+    }
+
+    #[test]
+    fn output_scanning() {
+        let tx_pub_key = "10fccee43c0612968c2db809b140abbf1c799fe6158305be1c023509f3ae9a94";
+        let private_view_key = "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908";
+        let public_spend_key = "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95";
+        let decode_point = |hex_point: &str| CompressedEdwardsY(decode_hex32(hex_point).unwrap()).decompress().unwrap();
+        let derivation = derive_key_derivation(tx_pub_key, private_view_key).unwrap();
+        // A 16-output transaction paying the wallet at 3 indices and other wallets at the rest
+        let ours = [2u64, 9, 15];
+        let outputs: Vec<(String, u64)> = (0..16u64)
+            .map(|index| {
+                let point = if ours.contains(&index) {
+                    let output_scalar = derive_output_scalar(&derivation, index).unwrap();
+                    decode_point(&derive_pub_key(output_scalar).unwrap()) + decode_point(public_spend_key)
+                } else {
+                    decode_point(&derive_pub_key(hex::encode(reduce_scalar(cn_fast_hash(&index.to_le_bytes())))).unwrap())
+                };
+                (hex::encode(point.compress().to_bytes()), index)
+            })
+            .collect();
+        let found = scan_outputs(tx_pub_key, private_view_key, public_spend_key, &outputs);
+        assert_eq!(found, (0..16u64).map(|index| ours.contains(&index)).collect::<Vec<bool>>());
+        for ((output_pub_key, index), found) in outputs.iter().zip(found) {
+            assert_eq!(is_output_ours(tx_pub_key, private_view_key, public_spend_key, output_pub_key, *index), found);
+        }
+        // Malformed output keys are skipped, malformed wallet keys match nothing
+        let mut malformed = outputs.clone();
+        malformed[9].0 = "3066".to_string();
+        assert_eq!(scan_outputs(tx_pub_key, private_view_key, public_spend_key, &malformed).iter().filter(|&&found| found).count(), 2);
+        assert_eq!(scan_outputs(tx_pub_key, "0d13", public_spend_key, &outputs), vec![false; 16]);
+        assert!(scan_outputs(tx_pub_key, private_view_key, public_spend_key, &[]).is_empty());
+    }
+
+    #[test]
+    fn output_public_keys() {
+        let tx_secret_key = "729e239f0a1b4c74199be9dfa71e1fc2ef35b9bfd4f8013b9fa2795085496b04";
+        let tx_pub_key = derive_pub_key(tx_secret_key.to_string()).unwrap();
+        assert_eq!(tx_pub_key, "09de961b02af3df2f894231e61d3bab3e6ccd20fe9b07bdd3ff34ea7617d561f");
+        let public_view_key = "157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47";
+        let public_spend_key = "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95";
+        let private_view_key = "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908";
+        // Computed by tests/vectors/generate.py, apart from the crate
+        let expected = [
+            "d5b8cc74a28af0ec8e4d63c56a95ec2b7fbdd922a5cee4a241a8bdf89cc76ba3",
+            "d6b77b575fd9a06e57320b935ce9aa4dcf30770d6e54ba09d8b4886458c91f14",
+            "32317b1f11323c1100bc7529999d673141ae3b818a02f71dfd9e3f1e7654bf09",
+        ];
+        let mut outputs = Vec::new();
+        for (index, expected) in expected.iter().enumerate() {
+            let output_pub_key = derive_output_public_key(tx_secret_key, public_view_key, public_spend_key, index as u64).unwrap();
+            assert_eq!(output_pub_key, *expected);
+            outputs.push((output_pub_key, index as u64));
+        }
+        // The sender's 8rA is the recipient's 8aR
+        assert_eq!(derive_key_derivation(public_view_key, tx_secret_key), derive_key_derivation(&tx_pub_key, private_view_key));
+        assert_eq!(scan_outputs(&tx_pub_key, private_view_key, public_spend_key, &outputs), vec![true; 3]);
+        // The keys of the generate_key_derivation vector of Monero's tests/crypto/tests.txt, whose derivation is
+        // 4e0bd2c41325a1b89a9f7413d4d05e0a5a4936f241dccc3c7d0c539ffe00ef67, and the derive_public_key results of
+        // tests/vectors/generate.py for that derivation
+        let expected = [
+            (0u64, "3d4c5b454e01695291df43018424fd1865b2b081eaef5ad512914b2804b83cf8"),
+            (1, "49a2fbd300b1d642a5435011c06562f48a3c3e82320729ed78f80efc10bd9a14"),
+            (300, "d826c493e06cb38615e7c11b4f9e6d2ac9330b19802eaca4fbd71766db301231"),
+        ];
+        for (index, expected) in expected {
+            assert_eq!(
+                derive_output_public_key("eb2bd1cf0c5e074f9dbf38ebbc99c316f54e21803048c687a3bb359f7a713b02", "fdfd97d2ea9f1c25df773ff2c973d885653a3ee643157eb0ae2b6dd98f0b6984", public_spend_key, index),
+                Ok(expected.to_string())
+            );
+        }
+        assert_eq!(derive_output_public_key(tx_secret_key, public_view_key, "0100", 0), Err(KeyError::InvalidHexLength { got: 4, expected: 64 }));
+        let not_a_point = "0200000000000000000000000000000000000000000000000000000000000000";
+        assert_eq!(derive_output_public_key(tx_secret_key, not_a_point, public_spend_key, 0), Err(KeyError::InvalidPoint));
+    }
+
+    #[test]
+    fn hex32_decoding() {
+        let hex = "00ff09af0AFf2b3c4d5e6f708192a3b4c5d6e7f8091a2b3c4d5e6f7a8b9cadbe";
+        assert_eq!(decode_hex32(hex).unwrap().to_vec(), hex::decode(hex).unwrap());
+        // Characters just outside the hex ranges
+        for bad in ["/", ":", "@", "G", "`", "g", " "] {
+            assert_eq!(decode_hex32(&format!("{}{}", bad, &hex[1..])), Err(KeyError::InvalidHex));
+        }
+        assert_eq!(decode_hex32(&hex[..62]), Err(KeyError::InvalidHexLength { got: 62, expected: 64 }));
+        assert_eq!(decode_hex32(&format!("{}00", hex)), Err(KeyError::InvalidHexLength { got: 66, expected: 64 }));
+        assert_eq!(derive_address(hex[2..].to_string(), hex.to_string(), 0), Err(KeyError::InvalidHexLength { got: 62, expected: 64 }));
+        assert_eq!(derive_address(hex.to_string(), hex.to_string(), 3), Err(KeyError::InvalidNetwork));
+    }
+
+    #[test]
+    fn restore_height_estimation() {
+        assert_eq!(estimate_restore_height(0), 0);
+        assert_eq!(estimate_restore_height(1397818193), 0);
+        assert_eq!(estimate_restore_height(1458748658), 1009827);
+        // 720 blocks a day after the fork, the average block time of 60.3 seconds before it
+        assert_eq!(estimate_restore_height(1458748658 + 86400), 1009827 + 720);
+        assert_eq!(estimate_restore_height(1397818193 + 86400), 1431);
+        // Estimates never go backwards in time
+        assert!(estimate_restore_height(1458748657) <= estimate_restore_height(1458748658));
+        assert!(estimate_restore_height(1458748658 - 86400) <= estimate_restore_height(1458748658));
+        // Later hard forks, and 1 July 2019 between the v10 and v12 ones
+        assert_eq!(estimate_restore_height(1552132800), 1788000);
+        assert_eq!(estimate_restore_height(1575115200), 1978433);
+        assert_eq!(estimate_restore_height(1561939200), 1869256);
+        // 2 minute blocks past the v15 hard fork
+        assert_eq!(estimate_restore_height(1704067200), 3052848);
+
+        // Anchors in between refine the estimate on both sides of them
+        let estimator = RestoreHeightEstimator::new().add_anchor(500000, 1428000000).unwrap();
+        assert_eq!(estimator.estimate(1428000000), 500000);
+        assert_eq!(estimate_restore_height(1413000000), 251614);
+        assert_eq!(estimator.estimate(1413000000), 251505);
+        assert_eq!(estimator.estimate(1458748658), 1009827);
+        assert_eq!(estimator.estimate(1704067200), estimate_restore_height(1704067200));
+        // Anchors going back in time are rejected, an anchor of a known height replaces it
+        assert_eq!(RestoreHeightEstimator::new().add_anchor(500000, 1460000000), Err(KeyError::InvalidAnchor { height: 500000, timestamp: 1460000000 }));
+        assert_eq!(RestoreHeightEstimator::new().add_anchor(1100000, 1400000000), Err(KeyError::InvalidAnchor { height: 1100000, timestamp: 1400000000 }));
+        let moved = RestoreHeightEstimator::new().add_anchor(0, 1397818200).unwrap();
+        assert_eq!(moved.estimate(1397818199), 0);
+        // Custom chains
+        let empty = RestoreHeightEstimator::empty();
+        assert_eq!(empty.estimate(1704067200), 0);
+        let custom = empty.add_anchor(100, 1600000000).unwrap();
+        assert_eq!(custom.estimate(1500000000), 100);
+        assert_eq!(custom.estimate(1600000000 + 1200), 110);
+    }
+
+    #[test]
+    fn seed_generation_with_strength() {
+        for (bits, words) in [(128, 13), (256, 25)] {
+            let mnemonic = generate_seed_with_strength("fr", bits).unwrap();
+            assert_eq!(mnemonic.len(), words);
+            assert_eq!(derive_hex_seed(mnemonic).unwrap().len(), bits as usize / 4);
+        }
+        assert_eq!(generate_seed_with_strength("en", 0), Err(KeyError::UnsupportedStrength(0)));
+        assert_eq!(generate_seed_with_strength("xx", 256), Err(KeyError::UnknownWordset));
+    }
+
+    #[test]
+    fn normalized_seed_input() {
+        for language in ["ja", "ru", "fr"] {
+            let mnemonic = generate_seed(language, "original");
+            let decomposed: Vec<String> = mnemonic.iter().map(|word| word.nfd().collect()).collect();
+            assert_eq!(derive_hex_seed(decomposed.clone()), derive_hex_seed(mnemonic.clone()), "{}", language);
+            assert_eq!(validate_mnemonic(&decomposed), validate_mnemonic(&mnemonic), "{}", language);
+        }
+    }
+
+    #[test]
+    fn polyseed_language_detection() {
+        let to_words = |phrase: &str| phrase.split(' ').map(|s| s.to_string()).collect::<Vec<String>>();
+        assert_eq!(detect_polyseed_language(&to_words("raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language")), Some("en"));
+        // Accents are optional in Spanish
+        assert_eq!(detect_polyseed_language(&to_words("eje fin parte celebre tabu pestaña lienzo puma prision hora regalo lengua existir lapiz lote sonoro")), Some("es"));
+        assert_eq!(detect_polyseed_language(&to_words("とうむぎ こうない ひくい うりきれ おばさん きおん ねんし えんげき ずいぶん うくらいな かのう ほそい ふへん おたく せんむ かんそう")), Some("ja"));
+        assert_eq!(detect_polyseed_language(&to_words("阔 摇 头 挤 温 职 雾 辞 绪 级 详 悬 阵 问 占 条")), Some("zh_s"));
+        // Words shared by English and French
+        assert_eq!(detect_polyseed_language(&to_words("abandon amateur angle animal aspect badge bicycle bonus brave canal capable caution civil client concert correct")), None);
+        // Original seeds are not polyseeds
+        assert_eq!(detect_polyseed_language(&to_words("tissue raking haunted huts afraid volcano howls liar egotistic befit rounded older bluntly imbalance pivot exotic tuxedo amaze mostly lukewarm macro vocal hounded biplane rounded")), None);
+        assert_eq!(detect_polyseed_language(&to_words("raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport")), None);
+    }
+
+    #[test]
+    fn seed_classification() {
+        let to_words = |s: &str| s.split(' ').map(|s| s.to_string()).collect::<Vec<String>>();
+        let original = to_words("tissue raking haunted huts afraid volcano howls liar egotistic befit rounded older bluntly imbalance pivot exotic tuxedo amaze mostly lukewarm macro vocal hounded biplane rounded");
+        assert_eq!(classify_seed(&original), Ok(SeedKind::Original));
+        assert_eq!(classify_seed(&original[..24]), Ok(SeedKind::Original));
+        let mymonero = generate_seed("ru", "mymonero");
+        assert_eq!(classify_seed(&mymonero), Ok(SeedKind::MyMonero));
+        assert_eq!(classify_seed(&mymonero[..12]), Ok(SeedKind::MyMonero));
+        let mut polyseed = to_words("raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language");
+        assert_eq!(classify_seed(&polyseed), Ok(SeedKind::Polyseed));
+        // Polyseed words are matched by their prefix, the checksum is not checked
+        polyseed[1] = "tailor".to_string();
+        polyseed[0] = "tail".to_string();
+        assert_eq!(classify_seed(&polyseed), Ok(SeedKind::Polyseed));
+        polyseed[3] = "xylophonx".to_string();
+        assert_eq!(classify_seed(&polyseed), Err(KeyError::InvalidWord { position: 3, word: "xylophonx".to_string() }));
+        let mut original = original;
+        original[2] = "raven".to_string();
+        assert_eq!(classify_seed(&original), Err(KeyError::InvalidWord { position: 2, word: "raven".to_string() }));
+        // "tissue" is also a polyseed word
+        assert_eq!(classify_seed(&original[..16]), Err(KeyError::InvalidWord { position: 1, word: "raking".to_string() }));
+        assert_eq!(classify_seed(&[]), Err(KeyError::WrongWordCount { got: 0, expected: &[12, 13, 16, 24, 25] }));
+    }
+
+    #[test]
+    fn amount_conversion() {
+        assert_eq!(xmr_to_atomic("0.000000000001"), Ok(1));
+        assert_eq!(xmr_to_atomic("12"), Ok(12_000_000_000_000));
+        assert_eq!(xmr_to_atomic("18446744.073709551615"), Ok(u64::MAX));
+        assert_eq!(atomic_to_xmr(u64::MAX), "18446744.073709551615".to_string());
+        assert_eq!(atomic_to_xmr(1), "0.000000000001".to_string());
+        assert_eq!(atomic_to_xmr(0), "0".to_string());
+        assert_eq!(atomic_to_xmr(10_100_000_000_000), "10.1".to_string());
+        for invalid in ["", ".", "1.", ".5", "-1", "+1", "1.2.3", "1e3", " 1", "0.0000000000001", "18446744.073709551616", "99999999999999999999"] {
+            assert_eq!(xmr_to_atomic(invalid), Err(KeyError::InvalidAmount), "{}", invalid);
+        }
+        for atomic in [1, 999_999_999_999, 1_000_000_000_000, 123_456_789_012_345] {
+            assert_eq!(xmr_to_atomic(&atomic_to_xmr(atomic)), Ok(atomic));
+        }
+    }
+
+    #[test]
+    fn polyseed_checksum() {
+        let english: Vec<String> = "raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language".split(' ').map(|s| s.to_string()).collect();
+        assert!(polyseed_checksum_valid(&english));
+        // Any single replaced word breaks the checksum
+        let other = generate_seed("en", "polyseed");
+        assert!(polyseed_checksum_valid(&other));
+        for position in 0..16 {
+            let mut corrupted = english.clone();
+            corrupted[position] = if other[position] != english[position] { other[position].clone() } else { "zoo".to_string() };
+            assert!(!polyseed_checksum_valid(&corrupted), "{}", position);
+        }
+        for language in ["ja", "ko", "es", "fr", "it", "cs", "pt", "zh_s", "zh_t"] {
+            assert!(polyseed_checksum_valid(&generate_seed(language, "polyseed")), "{}", language);
+        }
+        assert!(!polyseed_checksum_valid(&english[..15]));
+        assert!(!polyseed_checksum_valid(&generate_seed("en", "original")));
+    }
+
+    #[test]
+    fn polyseed_entropy_extraction() {
+        let to_words = |phrase: &str, separator: char| phrase.split(separator).map(|s| s.to_string()).collect::<Vec<String>>();
+        let secret = "ac8d0df79011c9a429ece800c9b835d541d230";
+        let english = to_words("ahead prosper cross tent tomato balcony charge dream kitchen trend add oppose ask step also club", ' ');
+        assert_eq!(hex::encode(polyseed_entropy(&english).unwrap()), secret);
+        let spanish = to_words("afirmar paso clima tabú tema arduo café deuda lápiz tinta acto nómina ángulo señal ajeno casco", ' ');
+        assert_eq!(hex::encode(polyseed_entropy(&spanish).unwrap()), secret);
+        let japanese_phrase = "あらすじ　なこうど　きつつき　ほんい　みけん　いんげんまめ　おんがく　げこくじょう　ぜんぽう　むける　あてな　つもる　いどう　ふとる　いがく　がはく";
+        let japanese = to_words(japanese_phrase, '\u{3000}');
+        assert_eq!(hex::encode(polyseed_entropy(&japanese).unwrap()), secret);
+        // Phrases are split on any separator and joined with the one of their language
+        assert_eq!(split_polyseed_phrase(japanese_phrase), japanese);
+        assert_eq!(split_polyseed_phrase(&japanese.join(" ")), japanese);
+        assert_eq!(join_polyseed_words(&japanese, "ja").unwrap(), japanese_phrase);
+        assert_eq!(join_polyseed_words(&english, "en").unwrap(), english.join(" "));
+        assert_eq!(join_polyseed_words(&english, "xx"), Err(KeyError::UnknownWordset));
+        // Swapped words, a misspelled word and a wrong word count
+        let mut swapped = english.clone();
+        swapped.swap(1, 2);
+        assert_eq!(polyseed_entropy(&swapped), Err(KeyError::InvalidSeedChecksum));
+        let mut misspelled = english.clone();
+        misspelled[3] = "tnet".to_string();
+        assert_eq!(polyseed_entropy(&misspelled), Err(KeyError::InvalidWord { position: 3, word: "tnet".to_string() }));
+        assert_eq!(polyseed_entropy(&english[..15]), Err(KeyError::WrongWordCount { got: 15, expected: &[16] }));
+    }
+
+    #[test]
+    fn polyseed_reserved_features() {
+        let to_words = |phrase: &str| phrase.split(' ').map(|s| s.to_string()).collect::<Vec<String>>();
+        // The secret and birthday of "raven tail swear ...", with the reserved feature bit set, and with the encrypted
+        // feature bit set too
+        let reserved = to_words("real tail sweet infant grief assist regular lamp duck valid someone little harsh puppy airport language");
+        let encrypted_reserved = to_words("razor talent sweet infant grief assist regular lamp duck valid someone little harsh puppy airport language");
+        for (mnemonic, features) in [(reserved, 0b01000), (encrypted_reserved, 0b11000)] {
+            assert!(!polyseed_checksum_valid(&mnemonic));
+            assert_eq!(polyseed_entropy(&mnemonic), Err(KeyError::UnsupportedPolyseedFeatures(features)));
+            assert_eq!(PolyseedSeed.to_hex_seed(&mnemonic), Err(KeyError::UnsupportedPolyseedFeatures(features)));
+            assert_eq!(decrypt_polyseed(&mnemonic, "passphrase"), Err(KeyError::UnsupportedPolyseedFeatures(features)));
+            assert_eq!(Wallet::open_wallet(mnemonic, 0), Err(KeyError::UnsupportedPolyseedFeatures(features)));
+        }
+    }
+
+    #[test]
+    fn cofactor_clearing() {
+        let decode_point = |hex_point: &str| CompressedEdwardsY(decode_hex32(hex_point).unwrap()).decompress().unwrap();
+        let tx_pub_key = decode_point("10fccee43c0612968c2db809b140abbf1c799fe6158305be1c023509f3ae9a94");
+        let view_scalar = Scalar::from_bytes_mod_order(decode_hex32("0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908").unwrap());
+        let shared_point = view_scalar * tx_pub_key;
+        assert_eq!(mul8(&shared_point), shared_point * Scalar::from(8u8));
+        assert_eq!(hex::encode(mul8(&shared_point).compress().to_bytes()), "7e78087373ccb7ee318352660e0daf3a24ebaa6af7b0f836b1f56f9e2e3dfa3e");
+        // The same transaction public key with an added order-2 component gives the same derivation
+        let torsioned = decode_point("dd03311bc3f9ed6973d247f64ebf5440e3866019ea7cfa41e3fdcaf60c51656b");
+        assert_ne!(torsioned, tx_pub_key);
+        assert_eq!(mul8(&(view_scalar * torsioned)), mul8(&shared_point));
+        // Small-order points are cleared completely
+        let order_two = decode_point("ecffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f");
+        assert!(!order_two.is_identity());
+        assert!(mul8(&order_two).is_identity());
+    }
+
+    #[test]
+    fn scalar_reduction() {
+        // The group order l reduces to zero, l - 1 is the largest canonical scalar
+        let order: [u8; 32] = hex::decode("edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010").unwrap().try_into().unwrap();
+        let mut order_minus_one = order;
+        order_minus_one[0] -= 1;
+        assert!(needed_reduction(&order));
+        assert_eq!(reduce_scalar(order), [0u8; 32]);
+        assert!(!needed_reduction(&order_minus_one));
+        assert_eq!(reduce_scalar(order_minus_one), order_minus_one);
+        // Every private key of a seed is canonical
+        let keys = derive_all_keys(generate_seed("en", "original")).unwrap();
+        assert!(!needed_reduction(&hex::decode(keys.priv_spend).unwrap().try_into().unwrap()));
+    }
+
+    #[test]
+    fn address_for_coin() {
+        let pub_sk = "03970285bf0724d75e0f50bca9a9ea0e8db5091b69403dc944465f8936bde787".to_string();
+        let pub_vk = "528a736a5079dc9536edb5b6fa0a5209ce820b9734fc0785024670b3d3ba4c69".to_string();
+        let monero = derive_address(pub_sk.clone(), pub_vk.clone(), 0).unwrap();
+        let custom = derive_address_for_coin(pub_sk.clone(), pub_vk.clone(), 0, Coin::Custom { mainnet: 0x12, testnet: 0x35 }).unwrap();
+        assert_eq!(monero, custom);
+        let aeon = derive_address_for_coin(pub_sk, pub_vk, 0, Coin::Aeon).unwrap();
+        assert!(aeon.starts_with("Wm"));
+        assert_eq!(aeon.len(), 97);
+    }
+
+    #[test]
+    fn mnemonic_address_confirmation() {
+        let mut mnemonic = ["five", "saved", "himself", "oust", "taunts", "pebbles", "fibula", "organs", "koala", "copy", "dying", "vein", "damp", "dauntless", "code", "gags", "copy", "roster", "geek", "toolbox", "joyous", "apart", "unlikely", "warped", "taunts"].iter().map(|s| s.to_string()).collect::<Vec<String>>();
+        let address = "41kztevQ9HVd2LMni56Ka13SBt6k9qFH6afYGWyXfWnJPdoEE86mHddRxZxPtAwdZb2e8wsZdiFyxPFMTtaWp14PCxPF3wT";
+        assert_eq!(mnemonic_matches_address(&mnemonic, address, 0), Ok(true));
+        // Valid seeds restoring other addresses
+        assert_eq!(mnemonic_matches_address(&mnemonic, &address.replace('T', "U"), 0), Ok(false));
+        assert_eq!(mnemonic_matches_address(&mnemonic, &address[..94], 0), Ok(false));
+        assert_eq!(mnemonic_matches_address(&generate_seed("en", "original"), address, 0), Ok(false));
+        // Invalid seeds and networks are errors
+        assert_eq!(mnemonic_matches_address(&mnemonic, address, 3), Err(KeyError::InvalidNetwork));
+        mnemonic[24] = "five".to_string();
+        assert_eq!(mnemonic_matches_address(&mnemonic, address, 0), Err(KeyError::InvalidSeedChecksum));
+        assert!(matches!(mnemonic_matches_address(&mnemonic[..20], address, 0), Err(KeyError::WrongWordCount { got: 20, .. })));
+    }
+
+    #[test]
+    fn address_key_checks() {
+        let pub_sk = "03970285bf0724d75e0f50bca9a9ea0e8db5091b69403dc944465f8936bde787".to_string();
+        let pub_vk = "528a736a5079dc9536edb5b6fa0a5209ce820b9734fc0785024670b3d3ba4c69".to_string();
+        assert_eq!(derive_address(pub_sk.clone(), pub_sk.clone(), 0), Err(KeyError::DuplicateKeys));
+        assert_eq!(derive_all_network_addresses(&pub_vk, &pub_vk), Err(KeyError::DuplicateKeys));
+        // y = 2 is not on the curve
+        let not_a_point = "0200000000000000000000000000000000000000000000000000000000000000".to_string();
+        assert_eq!(derive_address(not_a_point.clone(), pub_vk.clone(), 0), Err(KeyError::InvalidPoint));
+        assert_eq!(derive_address(pub_sk.clone(), not_a_point.clone(), 1), Err(KeyError::InvalidPoint));
+        assert_eq!(derive_address_for_coin(not_a_point.clone(), not_a_point, 0, Coin::Aeon), Err(KeyError::InvalidPoint));
+        assert!(derive_address(pub_sk, pub_vk, 0).is_ok());
+    }
+
+    #[test]
+    fn all_network_addresses() {
+        let pub_sk = "03970285bf0724d75e0f50bca9a9ea0e8db5091b69403dc944465f8936bde787";
+        let pub_vk = "528a736a5079dc9536edb5b6fa0a5209ce820b9734fc0785024670b3d3ba4c69";
+        let addresses = derive_all_network_addresses(pub_sk, pub_vk).unwrap();
+        assert_eq!(addresses.len(), 3);
+        for (network, address) in addresses {
+            assert_eq!(address, derive_address(pub_sk.to_string(), pub_vk.to_string(), network as u8).unwrap());
+            assert_eq!(decode_address(&address).unwrap().network, network);
+        }
+        assert_eq!(derive_all_network_addresses(pub_sk, "528a"), Err(KeyError::InvalidHexLength { got: 4, expected: 64 }));
+    }
+
+    #[test]
+    fn byte_addresses() {
+        for seed in 0..8u64 {
+            let priv_spend: [u8; 32] = cn_fast_hash(&seed.to_le_bytes());
+            let priv_view: [u8; 32] = cn_fast_hash(&priv_spend);
+            let (pub_spend, pub_view) = (derive_pub_key_bytes(priv_spend), derive_pub_key_bytes(priv_view));
+            assert_eq!(hex::encode(pub_spend), derive_pub_key(hex::encode(priv_spend)).unwrap());
+            for (network, code) in [(Network::Mainnet, 0), (Network::Testnet, 1), (Network::Stagenet, 2)] {
+                assert_eq!(derive_address_bytes(pub_spend, pub_view, network), derive_address(hex::encode(pub_spend), hex::encode(pub_view), code));
+            }
+        }
+        let pub_spend = derive_pub_key_bytes([1; 32]);
+        assert_eq!(derive_address_bytes(pub_spend, pub_spend, Network::Mainnet), Err(KeyError::DuplicateKeys));
+    }
+
+    #[test]
+    fn subaddress_keys() {
+        // Subaddress (0, 1) of the wallet of private view key a and public spend key B: D = B + Hs("SubAddr" || a || 0 || 1) G, C = a D
+        let decoded = decode_address("86iB6SuaTL46ns1jzTGqeqaEpjCuNXpPzNVsVugBaFndMUGLdU3xXRbS35t7YGRhryCDc64kdVLJZYB4PMsnXSRGSpnNvUz").unwrap();
+        assert_eq!(decoded.network, Network::Mainnet);
+        assert_eq!(decoded.address_type, AddressType::Subaddress);
+        assert_eq!(decoded.public_spend_key, "7057bcd5980c41229ed992e0510a6ac6b80e1fb6fe59118086da5c076298767a");
+        assert_eq!(decoded.public_view_key, "61f19ee4a875e295ae18f5104088024310634a68064066ba5faaefd488aac3e4");
+        assert_ne!(decoded.public_spend_key, "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95");
+        // An output sent to the subaddress, with tx public key r D, is found with its public spend key
+        let tx_secret_key = "729e239f8e8d8e08cbba5d8e2cbd6ca0a0ba9d2a71d04e4a4c0ec5f9b2b86b04";
+        let r = Scalar::from_bytes_mod_order(decode_hex32(tx_secret_key).unwrap());
+        let spend_point = CompressedEdwardsY(decode_hex32(&decoded.public_spend_key).unwrap()).decompress().unwrap();
+        let tx_pub_key = hex::encode((r * spend_point).compress().to_bytes());
+        let output = derive_output_public_key(tx_secret_key, &decoded.public_view_key, &decoded.public_spend_key, 3).unwrap();
+        let private_view_key = "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908";
+        assert_eq!(scan_outputs(&tx_pub_key, private_view_key, &decoded.public_spend_key, &[(output.clone(), 3)]), vec![true]);
+        assert_eq!(scan_outputs(&tx_pub_key, private_view_key, "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95", &[(output, 3)]), vec![false]);
+    }
+
+    #[test]
+    fn address_decoding() {
+        let decoded = decode_address("41kztevQ9HVd2LMni56Ka13SBt6k9qFH6afYGWyXfWnJPdoEE86mHddRxZxPtAwdZb2e8wsZdiFyxPFMTtaWp14PCxPF3wT").unwrap();
+        assert_eq!(decoded.network, Network::Mainnet);
+        assert_eq!(decoded.address_type, AddressType::Standard);
+        assert_eq!(decoded.public_spend_key, "03970285bf0724d75e0f50bca9a9ea0e8db5091b69403dc944465f8936bde787".to_string());
+        assert_eq!(decoded.public_view_key, "528a736a5079dc9536edb5b6fa0a5209ce820b9734fc0785024670b3d3ba4c69".to_string());
+        let stagenet = derive_address(decoded.public_spend_key.clone(), decoded.public_view_key.clone(), 2).unwrap();
+        assert_eq!(decode_address(&stagenet).unwrap().network, Network::Stagenet);
+        // Last character changed, so the checksum does not match anymore
+        assert_eq!(decode_address("41kztevQ9HVd2LMni56Ka13SBt6k9qFH6afYGWyXfWnJPdoEE86mHddRxZxPtAwdZb2e8wsZdiFyxPFMTtaWp14PCxPF3wU"), Err(KeyError::InvalidChecksum));
+        assert_eq!(decode_address("41kztevQ9HVd2LMni56Ka13SBt6k9qFH6afYGWyXf"), Err(KeyError::Base58("Invalid block size error for length 41".to_string())));
+        // Single keys of an address
+        assert_eq!(address_public_spend_key(&stagenet), Ok(decoded.public_spend_key.clone()));
+        assert_eq!(address_public_view_key(&stagenet), Ok(decoded.public_view_key.clone()));
+        assert_eq!(address_public_spend_key("41kztevQ9HVd2LMni56Ka13SBt6k9qFH6afYGWyXfWnJPdoEE86mHddRxZxPtAwdZb2e8wsZdiFyxPFMTtaWp14PCxPF3wU"), Err(KeyError::InvalidChecksum));
+        assert!(address_public_view_key("41kztevQ9HVd2LMni56Ka13SBt6k9qFH6afYGWyXf").is_err());
+        assert!(address_public_view_key("").is_err());
+    }
+
+    #[test]
+    fn address_bytes() {
+        let address = "41kztevQ9HVd2LMni56Ka13SBt6k9qFH6afYGWyXfWnJPdoEE86mHddRxZxPtAwdZb2e8wsZdiFyxPFMTtaWp14PCxPF3wT";
+        let bytes = address_to_bytes(address).unwrap();
+        assert_eq!(bytes.len(), 69);
+        assert_eq!(hex::encode(&bytes[..33]), "1203970285bf0724d75e0f50bca9a9ea0e8db5091b69403dc944465f8936bde787");
+        assert_eq!(bytes_to_address(&bytes).unwrap(), address);
+        // The same keys as an integrated address
+        let mut integrated = [&[0x13], &bytes[1..65], &[0xab; 8][..]].concat();
+        integrated.extend_from_slice(&cn_fast_hash(&integrated)[..4]);
+        let integrated_address = bytes_to_address(&integrated).unwrap();
+        assert_eq!(decode_address(&integrated_address).unwrap().payment_id, Some("abababababababab".to_string()));
+        assert_eq!(address_to_bytes(&integrated_address).unwrap(), integrated);
+        // Checksum, length and prefix are checked both ways
+        let mut tampered = bytes.clone();
+        tampered[68] ^= 1;
+        assert_eq!(bytes_to_address(&tampered), Err(KeyError::InvalidChecksum));
+        assert_eq!(bytes_to_address(&bytes[..68]), Err(KeyError::InvalidAddress));
+        assert_eq!(bytes_to_address(&[]), Err(KeyError::InvalidAddress));
+        assert_eq!(bytes_to_address(&[&[0x14], &bytes[1..]].concat()), Err(KeyError::UnknownPrefix(0x14)));
+        assert_eq!(address_to_bytes(&address[..90]), decode_address(&address[..90]).map(|_| Vec::new()));
+    }
+
+    #[test]
+    fn corrupted_address_decoding() {
+        let address = "41kztevQ9HVd2LMni56Ka13SBt6k9qFH6afYGWyXfWnJPdoEE86mHddRxZxPtAwdZb2e8wsZdiFyxPFMTtaWp14PCxPF3wT";
+        // 'l', '0', 'O' and 'I' are not in the base58 alphabet
+        let mistyped = address.replacen('1', "l", 1);
+        assert_eq!(decode_address(&mistyped), Err(KeyError::Base58("Invalid symbol 'l' at position 1".to_string())));
+        assert_eq!(decode_address(&address.replace('P', "0")), Err(KeyError::Base58("Invalid symbol '0' at position 44".to_string())));
+        assert_eq!(decode_address(&format!("{} ", address)), Err(KeyError::Base58("Invalid symbol ' ' at position 95".to_string())));
+        assert_eq!(decode_address("41kztévQ9"), Err(KeyError::Base58("Invalid symbol 'é' at position 5".to_string())));
+        // A last block of 11 characters can hold more than 8 bytes
+        assert_eq!(decode_address("zzzzzzzzzzz"), Err(KeyError::Base58("Overflow error for length 11".to_string())));
+        // Truncated at every length, none of them panics
+        for length in 0..address.len() {
+            assert!(decode_address(&address[..length]).is_err());
+        }
+        assert_eq!(KeyError::Base58("Overflow error for length 11".to_string()).to_string(), "Invalid base58: Overflow error for length 11");
+    }
+
+    #[test]
+    fn payment_uri_parsing() {
+        let uri = parse_payment_uri("monero:41kztevQ9HVd2LMni56Ka13SBt6k9qFH6afYGWyXfWnJPdoEE86mHddRxZxPtAwdZb2e8wsZdiFyxPFMTtaWp14PCxPF3wT?tx_payment_id=0123456789abcdef&recipient_name=Alice&tx_amount=1.5").unwrap();
+        assert_eq!(uri.amount, Some("1.5".to_string()));
+        assert_eq!(uri.payment_id, Some("0123456789abcdef".to_string()));
+        assert_eq!(uri.recipient_name, Some("Alice".to_string()));
+        assert_eq!(uri.description, None);
+        assert_eq!(parse_payment_uri(&uri.to_uri()).unwrap(), uri);
+        assert_eq!(parse_payment_uri("bitcoin:41kztevQ9HVd2LMni56Ka13SBt6k9qFH6afYGWyXfWnJPdoEE86mHddRxZxPtAwdZb2e8wsZdiFyxPFMTtaWp14PCxPF3wT"), Err(KeyError::InvalidUri));
+        assert_eq!(parse_payment_uri("monero:41kztevQ9HVd2LMni56Ka13SBt6k9qFH6afYGWyXfWnJPdoEE86mHddRxZxPtAwdZb2e8wsZdiFyxPFMTtaWp14PCxPF3wT?tx_payment_id=xyz"), Err(KeyError::InvalidUri));
+        assert_eq!(parse_payment_uri("monero:41kztevQ9HVd2LMni56Ka13SBt6k9qFH6afYGWyXfWnJPdoEE86mHddRxZxPtAwdZb2e8wsZdiFyxPFMTtaWp14PCxPF3wT?tx_amount=1,5"), Err(KeyError::InvalidUri));
+    }
+
+    #[test]
+    fn overflowing_words() {
+        // The first three words decode to 1625 * 1626^2, more than 4 bytes can hold
+        let mnemonic: Vec<String> = "abbey abbey zoom abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey".split(' ').map(|s| s.to_string()).collect();
+        assert_eq!(derive_hex_seed(mnemonic.clone()), Err(KeyError::CorruptSeed));
+        assert_eq!(derive_hex_seed(mnemonic[..24].to_vec()), Err(KeyError::CorruptSeed));
+        // Generated seeds never contain such words
+        for _ in 0..300 {
+            let mnemonic = generate_seed("en", "original");
+            assert_eq!(derive_hex_seed(mnemonic).map(|hex_seed| hex_seed.len()), Ok(64));
+        }
+    }
+
+    #[test]
+    fn word_validation() {
+        assert!(is_valid_word("raking", "en") && word_matches_prefix("raking", "en"));
+        assert!(!is_valid_word("rakign", "en") && word_matches_prefix("rakign", "en"));
+        assert!(!word_matches_prefix("ra", "en") && !word_matches_prefix("", "en"));
+        assert!(!is_valid_word("raking", "xx") && !word_matches_prefix("raking", "xx"));
+        // Prefixes are counted in characters, and decomposed words are matched like in seeds
+        assert!(is_valid_word("абрикос", "ru") && word_matches_prefix("абрикоз", "ru") && !word_matches_prefix("абр", "ru"));
+        assert!(is_valid_word("あけか\u{3099}た", "ja") && word_matches_prefix("あけがみ", "ja"));
+    }
+
+    #[test]
+    fn word_completion() {
+        assert_eq!(complete_word("ab", "en"), ["abbey", "abducts", "ability", "ablaze", "abnormal", "abort", "abrasive", "absorb", "abyss"]);
+        // Past the 3-character prefix the whole word is matched
+        assert_eq!(complete_word("abduct", "en"), ["abducts"]);
+        assert!(complete_word("abductx", "en").is_empty());
+        assert_eq!(complete_word("", "en").len(), 1626);
+        assert!(complete_word("ab", "xx").is_empty());
+        assert_eq!(complete_word("あけ", "ja"), ["あけがた", "あける"]);
+        assert_eq!(complete_word("あけか\u{3099}", "ja"), ["あけがた"]);
+        for word in complete_word("ra", "en") {
+            assert!(is_valid_word(&word, "en"));
+        }
+    }
+
+    #[test]
+    fn seed_schemes() {
+        let polyseed: Vec<String> = "raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language".split(' ').map(|s| s.to_string()).collect();
+        let hex_seed = PolyseedSeed.to_hex_seed(&polyseed).unwrap();
+        assert_eq!(hex_seed, "21268a76048a3b25a4a9ac179d86b12fab5800b8d858da9facf4b0a778dc2840");
+        let (priv_spend, priv_view) = PolyseedSeed.derive_keys(&hex_seed).unwrap();
+        assert_eq!(priv_spend, "6dd6b2029bfdf1c44a36ce8b229f35dcaa5800b8d858da9facf4b0a778dc2800");
+        assert_eq!(priv_view, "3c56a3cc3e7f94dc428ffe3b856adb6054552dfa14360d4cdec3f7730b999107");
+        let address = derive_address(derive_pub_key(priv_spend).unwrap(), derive_pub_key(priv_view).unwrap(), 0).unwrap();
+        assert_eq!(address, "47AjPj7DVPQVGGXJXbbTMZWcKQDejGHYZChVkeujy8qPLjKkgdsxge4DzvkRMgU4sDUigGLuBN9stKBMowhuXH2HJHWAuRf");
+
+        let schemes: [(&dyn SeedScheme, usize, SeedKind); 3] = [(&OriginalSeed, 25, SeedKind::Original), (&MyMoneroSeed, 13, SeedKind::MyMonero), (&PolyseedSeed, 16, SeedKind::Polyseed)];
+        for (i, (scheme, word_count, kind)) in schemes.into_iter().enumerate() {
+            let mut rng = StdRng::seed_from_u64(i as u64);
+            let mnemonic = scheme.generate("en", &mut rng).unwrap();
+            assert_eq!(mnemonic.len(), word_count);
+            assert_eq!(classify_seed(&mnemonic), Ok(kind));
+            let hex_seed = scheme.to_hex_seed(&mnemonic).unwrap();
+            let (priv_spend, priv_view) = scheme.derive_keys(&hex_seed).unwrap();
+            assert_eq!(vec![priv_spend, priv_view], derive_priv_keys(hex_seed).unwrap());
+            assert_eq!(scheme.generate("xx", &mut rng), Err(KeyError::UnknownWordset));
+        }
+        assert_eq!(OriginalSeed.to_hex_seed(&polyseed[..13]), Err(KeyError::WrongWordCount { got: 13, expected: &[24, 25] }));
+        assert_eq!(MyMoneroSeed.derive_keys("f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8"), Err(KeyError::InvalidHexSeed));
+
+        // Every polyseed language decodes its own seeds, born now
+        for language in ["cs", "en", "es", "fr", "it", "ja", "ko", "pt", "zh_s", "zh_t"] {
+            let mnemonic = generate_seed(language, "polyseed");
+            assert_eq!(mnemonic.len(), 16);
+            assert!(PolyseedSeed.to_hex_seed(&mnemonic).is_ok());
+        }
+    }
+
+    #[test]
+    fn seed_builder() {
+        let build = |features: u8| SeedBuilder::new().seed_type("polyseed").language("es").birthday(1700000000).features(features).rng(&mut StdRng::seed_from_u64(7)).build().unwrap();
+        let (plain, featured) = (build(0), build(5));
+        assert_eq!(polyseed_entropy(&plain), polyseed_entropy(&featured));
+        assert_ne!(PolyseedSeed.to_hex_seed(&plain).unwrap(), PolyseedSeed.to_hex_seed(&featured).unwrap());
+        assert_eq!(build(0), plain);
+
+        let original = SeedBuilder::new().strength(256).rng(&mut StdRng::seed_from_u64(7)).build().unwrap();
+        assert_eq!(original, OriginalSeed.generate("en", &mut StdRng::seed_from_u64(7)).unwrap());
+        assert_eq!(SeedBuilder::new().seed_type("mymonero").strength(128).build().map(|seed| seed.len()), Ok(13));
+        assert_eq!(SeedBuilder::new().seed_type("mymonero").strength(256).build(), Err(KeyError::UnsupportedStrength(256)));
+        assert_eq!(SeedBuilder::new().strength(192).build(), Err(KeyError::UnsupportedStrength(192)));
+        assert_eq!(SeedBuilder::new().seed_type("electrum").build(), Err(KeyError::UnknownSeedType("electrum".to_string())));
+        assert_eq!(SeedBuilder::new().seed_type("polyseed").features(8).build(), Err(KeyError::InvalidSeedOption("features")));
+        assert_eq!(SeedBuilder::new().seed_type("mymonero").features(0).build(), Err(KeyError::InvalidSeedOption("features")));
+        assert_eq!(SeedBuilder::new().seed_type("polyseed").language("ru").build(), Err(KeyError::UnknownWordset));
+        // Only spec word counts are accepted, for generation and decoding
+        let sixteen = SeedBuilder::new().seed_type("polyseed").language("es").birthday(1700000000).word_count(16).rng(&mut StdRng::seed_from_u64(7)).build().unwrap();
+        assert_eq!(sixteen, plain);
+        assert_eq!(POLYSEED_WORD_COUNTS, [16]);
+        assert_eq!(SeedBuilder::new().seed_type("polyseed").word_count(12).build(), Err(KeyError::WrongWordCount { got: 12, expected: &POLYSEED_WORD_COUNTS }));
+        assert_eq!(polyseed_entropy(&plain[..12]), Err(KeyError::WrongWordCount { got: 12, expected: &POLYSEED_WORD_COUNTS }));
+        assert_eq!(SeedBuilder::new().seed_type("original").word_count(16).build(), Err(KeyError::InvalidSeedOption("word_count")));
+    }
+
+    #[test]
+    fn child_keys() {
+        let parent = "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08";
+        // Same parent and index always give the same child, other indexes and parents give other children
+        assert_eq!(derive_child_keys(parent, 1), derive_child_keys(parent, 1));
+        let (priv_spend, priv_view) = derive_child_keys(parent, 1).unwrap();
+        assert_eq!(priv_spend, "0fc34272894ac99193b0eb987da142dbbcdca37c945a320f5aff4897661f1e09");
+        assert_eq!(priv_view, "fce6404a04e0d7a4c30a353f1d5031916c5336052939fd02cab9c25561bd8e00");
+        assert_eq!(derive_child_keys(parent, u32::MAX).unwrap().0, "e55b2edd1de3e380f864d58b599b2948215265adab888892d18b57de1b831b09");
+        assert_ne!(derive_child_keys(parent, 0), derive_child_keys(&priv_spend, 0));
+        // Children are regular wallets
+        assert!(derive_address(derive_pub_key(priv_spend).unwrap(), derive_pub_key(priv_view).unwrap(), 0).is_ok());
+        assert_eq!(derive_child_keys("c8982e", 0), Err(KeyError::InvalidHexLength { got: 6, expected: 64 }));
+    }
+
+    #[test]
+    fn degenerate_seed_rejection() {
+        // The group order reduces to a zero private spend key
+        let order = "edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010".to_string();
+        for hex_seed in ["00".repeat(32), "FF".repeat(32), "00".repeat(16), "ff".repeat(16), order] {
+            assert!(derive_priv_keys(hex_seed.clone()).is_ok());
+            assert_eq!(derive_priv_keys_checked(hex_seed), Err(KeyError::WeakSeed));
+        }
+        assert_eq!(derive_priv_keys_checked("00".repeat(31)), Err(KeyError::InvalidHexSeed));
+        assert!(derive_priv_keys_checked("01".repeat(32)).is_ok());
+    }
+
+    #[test]
+    fn wallet_secret_bytes() {
+        let mnemonic: Vec<String> = "tissue raking haunted huts afraid volcano howls liar egotistic befit rounded older bluntly imbalance pivot exotic tuxedo amaze mostly lukewarm macro vocal hounded biplane rounded".split(' ').map(|s| s.to_string()).collect();
+        let wallet = Wallet::open_wallet(mnemonic, 1).unwrap();
+        let blob = wallet.to_secret_bytes().unwrap();
+        assert_eq!(hex::encode(&blob[..2]), "0101");
+        assert_eq!(hex::encode(&blob[2..34]), "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08");
+        assert_eq!(hex::encode(&blob[34..]), "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908");
+        let loaded = Wallet::from_secret_bytes(&blob).unwrap();
+        assert_eq!((loaded.network, loaded.pub_sk.as_str(), loaded.pub_vk.as_str()), (1, wallet.pub_sk.as_str(), wallet.pub_vk.as_str()));
+        assert_eq!(loaded.main_address, wallet.main_address);
+        assert!(loaded.mnemonic.is_empty() && loaded.hex_seed.is_empty());
+        assert_eq!(Wallet::from_secret_bytes(&blob[..65]), Err(KeyError::InvalidSecretBytes));
+        assert_eq!(Wallet::from_secret_bytes(&[&blob[..], &[0]].concat()), Err(KeyError::InvalidSecretBytes));
+        let mut other = blob;
+        other[0] = 2;
+        assert_eq!(Wallet::from_secret_bytes(&other), Err(KeyError::UnsupportedVersion(2)));
+        other = blob;
+        other[1] = 3;
+        assert_eq!(Wallet::from_secret_bytes(&other), Err(KeyError::InvalidNetwork));
+        other = blob;
+        other[34..].fill(0xff);
+        assert_eq!(Wallet::from_secret_bytes(&other), Err(KeyError::InvalidSecretBytes));
+        // Edited private keys give an error instead of a panic
+        let mut edited = wallet.clone();
+        edited.priv_sk = "c898".to_string();
+        assert_eq!(catch_key_panic(|| edited.to_secret_bytes()), Ok(Err(KeyError::InvalidHexLength { got: 4, expected: 64 })));
+        edited.priv_sk = "zz".repeat(32);
+        assert_eq!(edited.to_secret_bytes(), Err(KeyError::InvalidHex));
+    }
+
+    #[test]
+    fn typed_key_derivation() {
+        let mymonero_hex_seed = "fddb58a226ba0a61cc48e2a393739378".to_string();
+        assert_eq!(derive_priv_keys_typed(mymonero_hex_seed.clone(), SeedKind::MyMonero), derive_priv_keys(mymonero_hex_seed.clone()));
+        assert_eq!(derive_priv_keys_typed(mymonero_hex_seed.clone(), SeedKind::Original), Err(KeyError::InvalidHexLength { got: 32, expected: 64 }));
+        assert_eq!(derive_priv_keys_typed(mymonero_hex_seed.repeat(2), SeedKind::MyMonero), Err(KeyError::InvalidHexLength { got: 64, expected: 32 }));
+        // Polyseed keys are derived from the polyseed key like original ones
+        let polyseed_key = "21268a76048a3b25a4a9ac179d86b12fab5800b8d858da9facf4b0a778dc2840".to_string();
+        let priv_keys = derive_priv_keys_typed(polyseed_key.clone(), SeedKind::Polyseed).unwrap();
+        assert_eq!(priv_keys[0], "6dd6b2029bfdf1c44a36ce8b229f35dcaa5800b8d858da9facf4b0a778dc2800");
+        assert_eq!(derive_priv_keys_typed(polyseed_key, SeedKind::Original), Ok(priv_keys));
+        assert_eq!(derive_priv_keys_typed("zz".repeat(16), SeedKind::MyMonero), Err(KeyError::InvalidHex));
+    }
+
+    #[test]
+    fn keys_consistency() {
+        let mnemonic: Vec<String> = vec!["tissue", "raking", "haunted", "huts", "afraid", "volcano", "howls", "liar", "egotistic", "befit", "rounded", "older", "bluntly", "imbalance", "pivot", "exotic", "tuxedo", "amaze", "mostly", "lukewarm", "macro", "vocal", "hounded", "biplane", "rounded"].iter().map(|s| s.to_string()).collect();
+        let keys = derive_all_keys(mnemonic).unwrap();
+        assert_eq!(verify_keys_consistency(&keys), Ok(()));
+        let upper = Keys { pub_spend: keys.pub_spend.to_uppercase(), ..keys.clone() };
+        assert_eq!(verify_keys_consistency(&upper), Ok(()));
+        // View keys of another wallet
+        let other = derive_all_keys(generate_seed("en", "original")).unwrap();
+        let pasted = Keys { priv_view: other.priv_view.clone(), pub_view: other.pub_view.clone(), ..keys.clone() };
+        assert_eq!(verify_keys_consistency(&pasted), Err(KeyError::InconsistentKeys("priv_view")));
+        let pasted = Keys { pub_spend: other.pub_spend.clone(), ..keys.clone() };
+        assert_eq!(verify_keys_consistency(&pasted), Err(KeyError::InconsistentKeys("pub_spend")));
+        let pasted = Keys { pub_view: other.pub_view, ..keys.clone() };
+        assert_eq!(verify_keys_consistency(&pasted), Err(KeyError::InconsistentKeys("pub_view")));
+        let truncated = Keys { priv_spend: keys.priv_spend[..62].to_string(), ..keys };
+        assert_eq!(verify_keys_consistency(&truncated), Err(KeyError::InvalidHexLength { got: 62, expected: 64 }));
+    }
+
+    #[test]
+    fn languages() {
+        for language in Language::ALL {
+            assert_eq!(language.as_str().parse(), Ok(language));
+            assert_eq!(language.to_string(), language.as_str());
+            for seed_type in ["original", "mymonero", "polyseed"] {
+                match generate_seed_in(language, seed_type) {
+                    Ok(mnemonic) => {
+                        assert!(language.supports(seed_type), "{} {}", language, seed_type);
+                        assert_eq!(detect_language(&mnemonic), Ok(language), "{} {}", language, seed_type);
+                    }
+                    Err(error) => {
+                        assert!(!language.supports(seed_type), "{} {}", language, seed_type);
+                        assert_eq!(error, KeyError::UnknownWordset);
+                    }
+                }
+            }
+        }
+        assert_eq!("jp".parse::<Language>(), Err(KeyError::UnknownLanguage("jp".to_string())));
+        assert_eq!(generate_seed_in(Language::En, "electrum"), Err(KeyError::UnknownSeedType("electrum".to_string())));
+        assert_eq!(detect_language(&vec!["xyzzy".to_string(); 25]), Err(KeyError::UnknownWordset));
+    }
+
+    #[test]
+    fn address_normalization() {
+        let address = "4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J";
+        assert_eq!(normalize_address(address), Ok(address.to_string()));
+        for pasted in [format!("  {}\r\n", address), format!("\u{feff}{}", address), format!("{}\u{200b}", address), format!("{} {}", &address[..47], &address[47..])] {
+            assert_eq!(normalize_address(&pasted), Ok(address.to_string()), "{:?}", pasted);
+        }
+        // Case is significant in base58
+        let lowered = address.replacen('Q', "q", 1);
+        assert!(normalize_address(&lowered).is_err());
+        assert!(normalize_address(&address[..94]).is_err());
+        assert!(matches!(normalize_address(&format!("{}.", address)), Err(KeyError::Base58(_))));
+        let integrated = "4L6ikG8AkHVNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U6CsqG2trjYYkU4Bb6wW";
+        assert_eq!(normalize_address(&format!("\t{}", integrated)), Ok(integrated.to_string()));
+    }
+
+    #[test]
+    fn keys_file_fields() {
+        let wallet = Wallet::new("en", "original", 2).unwrap();
+        let fields = to_keys_file_fields(&wallet).unwrap();
+        assert_eq!(fields.version, 1);
+        assert_eq!(fields.address, wallet.main_address);
+        assert_eq!((fields.viewkey.as_str(), fields.spendkey.as_str()), (wallet.priv_vk.as_str(), wallet.priv_sk.as_str()));
+        assert_eq!(fields.seed.split(' ').collect::<Vec<&str>>(), wallet.mnemonic);
+        let json: serde_json::Value = serde_json::from_str(&fields.to_json()).unwrap();
+        assert_eq!(json["seed"], fields.seed.as_str());
+        assert_eq!(json["viewkey"], wallet.priv_vk.as_str());
+        let imported = import_from_wallet_json(&fields.to_json()).unwrap();
+        assert_eq!((imported.pub_spend, imported.pub_view), (wallet.pub_sk.clone(), wallet.pub_vk.clone()));
+        // monero-wallet-cli only restores original seeds, other wallets are restored from their keys
+        let mymonero_wallet = Wallet::new("en", "mymonero", 0).unwrap();
+        let fields = to_keys_file_fields(&mymonero_wallet).unwrap();
+        assert!(fields.seed.is_empty() && !fields.to_json().contains("seed"));
+        let loaded = Wallet::from_secret_bytes(&wallet.to_secret_bytes().unwrap()).unwrap();
+        assert!(to_keys_file_fields(&loaded).unwrap().seed.is_empty());
+        // The address must belong to the keys
+        let mut tampered = wallet.clone();
+        tampered.main_address = mymonero_wallet.main_address;
+        assert_eq!(to_keys_file_fields(&tampered), Err(KeyError::AddressMismatch));
+    }
+
+    #[test]
+    fn mixed_language_seeds() {
+        let mut mnemonic: Vec<String> = vec!["tissue", "raking", "haunted", "huts", "afraid", "volcano", "howls", "liar", "egotistic", "befit", "rounded", "older", "bluntly", "imbalance", "pivot", "exotic", "tuxedo", "amaze", "mostly", "lukewarm", "macro", "vocal", "hounded", "biplane", "rounded"].iter().map(|s| s.to_string()).collect();
+        mnemonic[4] = "банк".to_string();
+        assert_eq!(derive_hex_seed(mnemonic.clone()), Err(KeyError::MixedLanguages { detected: vec!["en", "ru"] }));
+        mnemonic[9] = "tissu".to_string();
+        assert_eq!(derive_hex_seed(mnemonic.clone()), Err(KeyError::MixedLanguages { detected: vec!["en", "ru", "fr"] }));
+        assert_eq!(derive_hex_seeds_batch(vec![mnemonic.clone()]), vec![Err(KeyError::MixedLanguages { detected: vec!["en", "ru", "fr"] })]);
+        // A word of no wordset is still an invalid word
+        mnemonic[12] = "notaword".to_string();
+        assert_eq!(derive_hex_seed(mnemonic), Err(KeyError::InvalidWord { position: 12, word: "notaword".to_string() }));
+        assert_eq!(KeyError::MixedLanguages { detected: vec!["en", "ru"] }.to_string(), "Seed mixes words of several languages (en, ru), please check your seed");
+    }
+
+    #[test]
+    fn hex_seed_batches() {
+        let mut seeds: Vec<Vec<String>> = Vec::new();
+        for language in ["en", "eo", "fr", "it", "ja", "lj", "pt", "ru"] {
+            seeds.push(generate_seed(language, "original"));
+            seeds.push(generate_seed(language, "mymonero"));
+        }
+        let english: Vec<String> = generate_seed("en", "original");
+        // Without checksum word
+        seeds.push(english[..24].to_vec());
+        // Wrong word count, unknown word, word of another wordset, overflowing words
+        seeds.push(english[..20].to_vec());
+        let mut unknown = english.clone();
+        unknown[3] = "notaword".to_string();
+        seeds.push(unknown);
+        let mut mixed = english.clone();
+        mixed[5] = generate_seed("ru", "original")[0].clone();
+        seeds.push(mixed);
+        seeds.push("abbey abbey zoom abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey".split(' ').map(|s| s.to_string()).collect());
+        // Japanese words in another Unicode normalization form
+        seeds.push(generate_seed("ja", "original").iter().map(|word| word.nfkd().collect()).collect());
+        let expected: Vec<Result<String, KeyError>> = seeds.iter().map(|seed| derive_hex_seed(seed.clone())).collect();
+        assert_eq!(derive_hex_seeds_batch(seeds), expected);
+        assert!(expected[..17].iter().all(|hex_seed| hex_seed.is_ok()));
+        assert!(expected[17..21].iter().all(|hex_seed| hex_seed.is_err()));
+        assert!(expected[21].is_ok());
+        assert!(derive_hex_seeds_batch(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn crypto_self_test() {
+        assert_eq!(self_test(), Ok(()));
+        assert_eq!(KeyError::SelfTestFailed("address").to_string(), "Self test failed at step 'address', key derivation is broken on this build");
+    }
+
+    #[test]
+    fn bip39_keys() {
+        let abandon = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let keys = bip39_to_monero_keys(abandon, "").unwrap();
+        assert_eq!(keys.priv_spend, "4db69027d03125919d64de03380986867e50fbdd9fb1ea6e99685c6b795a350e");
+        assert_eq!(keys.priv_view, "4c342623060fdfe1e909390e282e7985971d473ea90bbb8e4f0d4b05a25cd009");
+        assert_eq!(keys.pub_spend, "159a42a3e82d73587c551dd62cca7f754f3bb704606af83a4b289c4f2ed600fc");
+        assert_eq!(keys.pub_view, "72db09ba6da7b8b01adcc4bbe496d4446416858a3894da4c0cd2b411cf98c48b");
+        assert_eq!(verify_keys_consistency(&keys), Ok(()));
+        // The passphrase and the spacing of the words
+        let trezor = bip39_to_monero_keys(abandon, "TREZOR").unwrap();
+        assert_eq!(trezor.priv_view, "ba42b7ffb43c27c16ad80666313abf0107a7e0e040cc4072fcfcc5d2f6af550e");
+        assert_eq!(trezor.pub_view, "0d1857883ddf18d8e04d2bc2c5be3b08d4216ba119a6f11bc352a146c6036589");
+        assert_eq!(bip39_to_monero_keys(&format!("  {}\n", abandon.replace(' ', "  ")), ""), Ok(keys));
+        // 24 words with valid checksum
+        let zoo = format!("{} vote", "zoo ".repeat(23));
+        assert!(bip39_to_monero_keys(&zoo, "").is_ok());
+        assert_eq!(bip39_to_monero_keys(&abandon.replace("about", "abandon"), ""), Err(KeyError::InvalidSeedChecksum));
+        assert_eq!(bip39_to_monero_keys(&abandon.replace("about", "xylophonx"), ""), Err(KeyError::InvalidWord { position: 11, word: "xylophonx".to_string() }));
+        assert_eq!(bip39_to_monero_keys("abandon about", ""), Err(KeyError::WrongWordCount { got: 2, expected: &BIP39_WORD_COUNTS }));
+    }
+
+    #[test]
+    fn seed_iterators() {
+        for language in ["en", "eo", "fr", "it", "ja", "lj", "pt", "ru"] {
+            let original: Vec<String> = generate_seed_iter(language, "original").unwrap().map(|word| word.to_string()).collect();
+            assert_eq!(original.len(), 25);
+            assert_eq!(validate_mnemonic(&original), Ok(()));
+            let mymonero: Vec<String> = generate_seed_iter(language, "mymonero").unwrap().map(|word| word.to_string()).collect();
+            assert_eq!(mymonero.len(), 13);
+            assert_eq!(validate_mnemonic(&mymonero), Ok(()));
+        }
+        for language in ["en", "fr", "ko", "zh_t"] {
+            let polyseed: Vec<String> = generate_seed_iter(language, "polyseed").unwrap().map(|word| word.to_string()).collect();
+            assert!(polyseed_entropy(&polyseed).is_ok());
+            assert_eq!(detect_polyseed_language(&polyseed), Some(language));
+        }
+        assert!(generate_seed_iter("ko", "original").is_err());
+        assert!(matches!(generate_seed_iter("en", "electrum"), Err(KeyError::UnknownSeedType(_))));
+    }
+
+    #[test]
+    fn wordset_integrity() {
+        // Covers every original wordset, whatever languages are added
+        assert_eq!(verify_wordset_integrity(), Ok(()));
+        let languages: Vec<Language> = Language::ALL.iter().copied().filter(|language| language.supports("original")).collect();
+        assert_eq!(languages.len(), 8);
+        for language in languages {
+            assert_eq!(wordset_info(language.as_str()).map(|info| info.word_count), Some(1626));
+        }
+        let error = KeyError::CorruptWordset { language: "en", word: "tissue", problem: "duplicate prefix" };
+        assert_eq!(error.to_string(), "Wordset en is corrupt: duplicate prefix 'tissue'");
+    }
+
+    #[test]
+    fn seeds_from_entropy() {
+        let mymonero = generate_seed_from_entropy("en", "mymonero", &hex::decode("fddb58a226ba0a61cc48e2a393739378").unwrap()).unwrap();
+        assert_eq!(mymonero.join(" "), "dwarf itinerary army dehydrate yahoo hairy eels dexterity tamper wedge begun nestle hairy");
+        let secret = hex::decode("dd76e7359a0ded37cd0ff0f3c829a5ae016733").unwrap();
+        let polyseed = generate_seed_from_entropy("fr", "polyseed", &secret).unwrap();
+        assert_eq!(polyseed_entropy(&polyseed).unwrap().to_vec(), secret);
+        // The 2 high bits of the last polyseed byte are not part of the secret
+        let mut high_bits = secret.clone();
+        high_bits[18] |= 0xc0;
+        assert_eq!(polyseed_entropy(&generate_seed_from_entropy("en", "polyseed", &high_bits).unwrap()).unwrap().to_vec(), secret);
+        // Short, empty or long entropy is never padded or cut
+        assert_eq!(generate_seed_from_entropy("en", "original", &[]), Err(KeyError::InsufficientEntropy { got: 0, need: 32 }));
+        assert_eq!(generate_seed_from_entropy("en", "mymonero", &[7; 15]), Err(KeyError::InsufficientEntropy { got: 15, need: 16 }));
+        assert_eq!(generate_seed_from_entropy("en", "polyseed", &[7; 18]), Err(KeyError::InsufficientEntropy { got: 18, need: 19 }));
+        assert_eq!(generate_seed_from_entropy("en", "mymonero", &[7; 32]), Err(KeyError::InsufficientEntropy { got: 32, need: 16 }));
+        assert_eq!(generate_seed_from_entropy("ko", "original", &[7; 32]), Err(KeyError::UnknownWordset));
+        assert_eq!(generate_seed_from_entropy("en", "electrum", &[7; 32]), Err(KeyError::UnknownSeedType("electrum".to_string())));
+        assert_eq!(KeyError::InsufficientEntropy { got: 16, need: 32 }.to_string(), "Got 16 bytes of entropy, the seed type needs exactly 32");
+    }
+
+    #[test]
+    fn secret_redaction() {
+        let mnemonic: Vec<String> = "tissue raking haunted huts afraid volcano howls liar egotistic befit rounded older bluntly imbalance pivot exotic tuxedo amaze mostly lukewarm macro vocal hounded biplane rounded".split(' ').map(|s| s.to_string()).collect();
+        let wallet = Wallet::open_wallet(mnemonic.clone(), 0).unwrap();
+        let keys = derive_all_keys(mnemonic).unwrap();
+        let fields = to_keys_file_fields(&wallet).unwrap();
+        let secrets = [wallet.priv_sk.clone(), wallet.priv_vk.clone(), wallet.hex_seed.clone(), wallet.mnemonic[0].clone()];
+        for debug in [format!("{:?}", wallet), format!("{:#?}", wallet), format!("{:?}", keys), format!("{:?}", fields)] {
+            assert!(secrets.iter().all(|secret| !debug.contains(secret.as_str())), "{}", debug);
+            assert!(debug.contains("[REDACTED]"));
+        }
+        assert!(format!("{:?}", wallet).contains("priv_sk: [REDACTED]"));
+        assert!(format!("{:?}", wallet).contains(&wallet.main_address));
+        assert!(format!("{:?}", keys).contains(&keys.pub_spend));
+        assert!(format!("{:?}", fields).contains(&fields.address));
+        // Revealing gives the derived Debug output
+        assert_eq!(keys.reveal(), format!("Keys {{ priv_spend: {:?}, pub_spend: {:?}, priv_view: {:?}, pub_view: {:?} }}", keys.priv_spend, keys.pub_spend, keys.priv_view, keys.pub_view));
+        for secret in &secrets {
+            assert!(wallet.reveal().contains(secret.as_str()));
+        }
+        assert!(fields.reveal().contains(&fields.spendkey));
+    }
+
+    #[test]
+    fn hex_key_lists() {
+        let spend = "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95";
+        let view = "157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47";
+        let expected = vec![decode_hex32(spend).unwrap(), decode_hex32(view).unwrap()];
+        for input in [format!("{} {}", spend, view), format!("{},{}", spend, view), format!("\n\n{} ,\t{},\n\n", spend, view), format!(",{}\r\n{},", spend, view)] {
+            assert_eq!(parse_hex_keys(&input), Ok(expected.clone()));
+        }
+        assert_eq!(parse_hex_keys(""), Ok(Vec::new()));
+        assert_eq!(parse_hex_keys(" ,\n, "), Ok(Vec::new()));
+        // The first malformed entry is reported, by its position among the entries
+        assert_eq!(parse_hex_keys(&format!("{}, {}, {}zz", spend, view, &spend[..62])), Err(KeyError::InvalidHexKey { position: 2, entry: format!("{}zz", &spend[..62]) }));
+        assert_eq!(parse_hex_keys(&format!("{},,{} {}0", spend, &view[1..], view)), Err(KeyError::InvalidHexKey { position: 1, entry: view[1..].to_string() }));
+        assert_eq!(KeyError::InvalidHexKey { position: 1, entry: "abc".to_string() }.to_string(), "Invalid hex key 'abc' at position 1, expected 64 hexadecimal characters");
+    }
+
+    #[test]
+    fn constant_time_hex_seeds() {
+        for language in ["en", "eo", "fr", "it", "ja", "lj", "pt", "ru"] {
+            for seed_type in ["original", "mymonero"] {
+                let mnemonic = generate_seed(language, seed_type);
+                assert_eq!(derive_hex_seed_ct(mnemonic.clone()), derive_hex_seed(mnemonic.clone()));
+                assert_eq!(derive_hex_seed_ct(mnemonic[..mnemonic.len() - 1].to_vec()), derive_hex_seed(mnemonic[..mnemonic.len() - 1].to_vec()));
+            }
+        }
+        // Japanese words in another normalization form, and the errors of derive_hex_seed
+        let japanese: Vec<String> = generate_seed("ja", "original").iter().map(|word| word.nfkd().collect()).collect();
+        assert_eq!(derive_hex_seed_ct(japanese.clone()), derive_hex_seed(japanese));
+        let mut mnemonic = generate_seed("en", "original");
+        assert!(matches!(derive_hex_seed_ct(mnemonic[..20].to_vec()), Err(KeyError::WrongWordCount { got: 20, .. })));
+        mnemonic[7] = "tissu".to_string();
+        assert_eq!(derive_hex_seed_ct(mnemonic.clone()), derive_hex_seed(mnemonic.clone()));
+        mnemonic[7] = "a".repeat(40);
+        assert_eq!(derive_hex_seed_ct(mnemonic.clone()), Err(KeyError::InvalidWord { position: 7, word: "a".repeat(40) }));
+        let overflowing: Vec<String> = "abbey abbey zoom abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey abbey".split(' ').map(|s| s.to_string()).collect();
+        assert_eq!(derive_hex_seed_ct(overflowing), Err(KeyError::CorruptSeed));
+        assert_eq!(derive_hex_seed_ct(vec!["xyz".to_string(); 25]), Err(KeyError::UnknownWordset));
+    }
+
+    #[test]
+    fn polyseed_encryption() {
+        let mnemonic: Vec<String> = "raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language".split(' ').map(|s| s.to_string()).collect();
+        let encrypted = encrypt_polyseed(&mnemonic, "correct horse battery staple").unwrap();
+        assert_ne!(encrypted, mnemonic);
+        assert!(is_polyseed_encrypted(&encrypted).unwrap());
+        assert!(!is_polyseed_encrypted(&mnemonic).unwrap());
+        // The secret is masked with PBKDF2-HMAC-SHA256 of the passphrase, salted with "POLYSEED mask"
+        let mask: Vec<u8> = polyseed_entropy(&encrypted).unwrap().iter().zip(polyseed_entropy(&mnemonic).unwrap()).map(|(a, b)| a ^ b).collect();
+        assert_eq!(hex::encode(mask), "6675aa0888f3ec07a5756e9fac60dd767cbb1a");
+        assert_eq!(decrypt_polyseed(&encrypted, "correct horse battery staple").unwrap(), mnemonic);
+        // A wrong passphrase gives another valid seed
+        let wrong = decrypt_polyseed(&encrypted, "correct horse battery").unwrap();
+        assert_ne!(wrong, mnemonic);
+        assert!(polyseed_checksum_valid(&wrong));
+        assert_eq!(encrypt_polyseed(&encrypted, "correct horse battery staple"), Err(KeyError::EncryptedPolyseed));
+        assert_eq!(decrypt_polyseed(&mnemonic, "correct horse battery staple"), Err(KeyError::UnencryptedPolyseed));
+        assert_eq!(PolyseedSeed.to_hex_seed(&encrypted), Err(KeyError::EncryptedPolyseed));
+        // Seeds keep their language, birthday and user features
+        let mut rng = StdRng::seed_from_u64(400);
+        let seed = SeedBuilder::new().language("es").seed_type("polyseed").features(5).rng(&mut rng).build().unwrap();
+        let encrypted = encrypt_polyseed(&seed, "contraseña").unwrap();
+        assert_eq!(detect_polyseed_language(&encrypted), detect_polyseed_language(&seed));
+        // Passphrases are NFKD normalized
+        assert_eq!(decrypt_polyseed(&encrypted, "contrasen\u{0303}a").unwrap(), seed);
+        assert_eq!(KeyError::EncryptedPolyseed.to_string(), "Polyseed seed is encrypted, please decrypt it with its passphrase");
+    }
+
+    #[test]
+    fn subaddress_index_cache() {
+        let private_view_key = "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908";
+        let public_spend_key = "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95";
+        let mut cache = SubaddressIndexCache::new(private_view_key, public_spend_key, 2, 2).unwrap();
+        assert_eq!(cache.len(), 4);
+        assert_eq!(cache.lookup(public_spend_key), Some((0, 0)));
+        assert_eq!(cache.lookup("7057bcd5980c41229ed992e0510a6ac6b80e1fb6fe59118086da5c076298767a"), Some((0, 1)));
+        assert_eq!(cache.lookup("25a88a944d18f85bab88eb0c4092dba72db6bfab10f6a3fa120279ce944fd322"), Some((1, 0)));
+        assert_eq!(cache.lookup("0506eb5e4a4eedfe60f1ce3e066c192120c3d75f774415c6658ded96d57b4c89"), Some((1, 1)));
+        let far = "be184cfbdd9cbd6957b8cf336d547d5fcfe339e816558b28279639620c40a12d";
+        assert_eq!(cache.lookup(far), None);
+        assert_eq!(cache.lookup("not hex"), None);
+        // Using (1, 1) keeps 2 accounts and 2 indexes ahead of it
+        cache.expand(1, 1);
+        assert_eq!(cache.len(), 9);
+        assert_eq!(cache.lookup(far), None);
+        cache.expand(0, 1);
+        assert_eq!(cache.len(), 9);
+        cache.expand(0, 2);
+        assert_eq!(cache.len(), 12);
+        assert_eq!(cache.lookup(far), Some((2, 3)));
+        // Lookaheads of 0 keep the main address
+        let empty = SubaddressIndexCache::new(private_view_key, public_spend_key, 0, 0).unwrap();
+        assert_eq!(empty.len(), 1);
+        assert!(!empty.is_empty());
+        assert!(SubaddressIndexCache::new(private_view_key, "00", 1, 1).is_err());
+        // An output sent to subaddress (0, 1), like in subaddress_keys
+        let tx_secret_key = "729e239f8e8d8e08cbba5d8e2cbd6ca0a0ba9d2a71d04e4a4c0ec5f9b2b86b04";
+        let decoded = decode_address("86iB6SuaTL46ns1jzTGqeqaEpjCuNXpPzNVsVugBaFndMUGLdU3xXRbS35t7YGRhryCDc64kdVLJZYB4PMsnXSRGSpnNvUz").unwrap();
+        let r = Scalar::from_bytes_mod_order(decode_hex32(tx_secret_key).unwrap());
+        let spend_point = CompressedEdwardsY(decode_hex32(&decoded.public_spend_key).unwrap()).decompress().unwrap();
+        let tx_pub_key = hex::encode((r * spend_point).compress().to_bytes());
+        let output = derive_output_public_key(tx_secret_key, &decoded.public_view_key, &decoded.public_spend_key, 3).unwrap();
+        assert_eq!(cache.find_output(&tx_pub_key, &output, 3), Ok(Some((0, 1))));
+        assert_eq!(cache.find_output(&tx_pub_key, &output, 2), Ok(None));
+        assert_eq!(cache.find_output("zz", &output, 3), Err(KeyError::InvalidHexLength { got: 2, expected: 64 }));
+    }
+
+    #[test]
+    fn canonical_addresses() {
+        let addresses = [
+            "4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J",
+            "86iB6SuaTL46ns1jzTGqeqaEpjCuNXpPzNVsVugBaFndMUGLdU3xXRbS35t7YGRhryCDc64kdVLJZYB4PMsnXSRGSpnNvUz",
+            "4L6ikG8AkHVNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U6CsqG2trjYYkU4Bb6wW",
+        ];
+        let symbols = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+        for address in addresses {
+            let decoded = decode_address(address).unwrap();
+            // No other string with one symbol changed decodes to an address, let alone the same one
+            for position in 0..address.len() {
+                for symbol in symbols.chars() {
+                    let mut changed = address.to_string();
+                    changed.replace_range(position..position + 1, &symbol.to_string());
+                    if let Ok(other) = decode_address(&changed) {
+                        assert_eq!(changed, address);
+                        assert_eq!(other, decoded);
+                    }
+                }
+            }
+        }
+        assert_eq!(KeyError::NonCanonicalEncoding.to_string(), "Address is not in canonical base58 encoding");
+    }
+
+    #[test]
+    fn integrated_addresses() {
+        let public_spend_key = "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95";
+        let public_view_key = "157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47";
+        let payment_id = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef];
+        let expected = "4L6ikG8AkHVNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U6CsqG2trjYYkU4Bb6wW".to_string();
+        assert_eq!(derive_integrated_address_full(public_spend_key, public_view_key, Network::Mainnet, Some(payment_id)), Ok((expected, payment_id)));
+        // Random payment IDs are the ones embedded, and differ between addresses
+        let (first, first_id) = derive_integrated_address_full(public_spend_key, public_view_key, Network::Stagenet, None).unwrap();
+        let (second, second_id) = derive_integrated_address_full(public_spend_key, public_view_key, Network::Stagenet, None).unwrap();
+        assert_ne!(first_id, second_id);
+        for (address, id) in [(first, first_id), (second, second_id)] {
+            let decoded = decode_address(&address).unwrap();
+            assert_eq!(decoded.network, Network::Stagenet);
+            assert_eq!(decoded.address_type, AddressType::Integrated);
+            assert_eq!(decoded.public_spend_key, public_spend_key);
+            assert_eq!(decoded.payment_id, Some(hex::encode(id)));
+            assert_eq!(derive_integrated_address(public_spend_key, public_view_key, Network::Stagenet, id), Ok(address));
+        }
+        assert_eq!(derive_integrated_address(public_spend_key, public_spend_key, Network::Mainnet, payment_id), Err(KeyError::DuplicateKeys));
+    }
+
+    #[test]
+    fn polyseed_birthdays() {
+        // The test vector of the polyseed reference, generated at 1638446400 (2 December 2021)
+        let mnemonic: Vec<String> = "raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language".split(' ').map(|s| s.to_string()).collect();
+        assert_eq!(polyseed_birthday(&mnemonic), Ok(1635768000 + 2629746));
+        // Generated birthdays come back rounded down to their time step, whatever the feature bits before them
+        let time_step = 2629746;
+        for (timestamp, features) in [(1635768000, 0), (1638446400, 7), (1700000000, 1), (1704067200, 4), (1635768000 + 1023 * time_step + 5, 2)] {
+            let mut rng = StdRng::seed_from_u64(timestamp);
+            let seed = SeedBuilder::new().seed_type("polyseed").birthday(timestamp).features(features).rng(&mut rng).build().unwrap();
+            let birthday = polyseed_birthday(&seed).unwrap();
+            assert!(birthday <= timestamp && timestamp - birthday < time_step, "{} {}", timestamp, birthday);
+        }
+        // Before the epoch is the epoch
+        let seed = SeedBuilder::new().seed_type("polyseed").birthday(1500000000).build().unwrap();
+        assert_eq!(polyseed_birthday(&seed), Ok(1635768000));
+        assert!(polyseed_birthday(&mnemonic[..15]).is_err());
+    }
+
+    #[test]
+    fn expected_word_counts() {
+        let words = |phrase: &str| -> Vec<String> { phrase.split(' ').map(|s| s.to_string()).collect() };
+        let original = words("tissue raking haunted huts afraid volcano howls liar egotistic befit rounded older bluntly imbalance pivot exotic tuxedo amaze mostly lukewarm macro vocal hounded biplane rounded");
+        for count in 1..=25 {
+            assert_eq!(expected_word_count(&original[..count]), Some(25));
+        }
+        let mymonero = words("dwarf itinerary army dehydrate yahoo hairy eels dexterity tamper wedge begun nestle hairy");
+        assert_eq!(expected_word_count(&mymonero), Some(13));
+        assert_eq!(expected_word_count(&mymonero[..12]), Some(25));
+        // Polyseed words that are not original words give 16 from the first one
+        let polyseed = words("able about above absent abstract absurd abuse access accident account accuse achieve acid acoustic acquire across");
+        for count in 1..=16 {
+            assert_eq!(expected_word_count(&polyseed[..count]), Some(16));
+        }
+        // Words in both kinds of wordsets, only a valid polyseed checksum makes 16 of them a polyseed seed
+        let raven = words("raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language");
+        assert_eq!(expected_word_count(&raven), Some(16));
+        assert_eq!(expected_word_count(&generate_seed("ja", "polyseed")), Some(16));
+        assert_eq!(expected_word_count(&generate_seed("ja", "original")[..3]), Some(25));
+        assert_eq!(expected_word_count(&[]), None);
+        assert_eq!(expected_word_count(&words("tissue xyz")), None);
+        assert_eq!(expected_word_count(&words("tissue банк")), None);
+        assert_eq!(expected_word_count(&[original.clone(), vec!["tissue".to_string()]].concat()), None);
+        assert_eq!(expected_word_count(&[polyseed.clone(), vec!["able".to_string()]].concat()), None);
+    }
+
+    #[test]
+    fn spend_scalar_keys() {
+        let mnemonic: Vec<String> = "tissue raking haunted huts afraid volcano howls liar egotistic befit rounded older bluntly imbalance pivot exotic tuxedo amaze mostly lukewarm macro vocal hounded biplane rounded".split(' ').map(|s| s.to_string()).collect();
+        let keys = derive_all_keys(mnemonic).unwrap();
+        assert_eq!(keys_from_spend_scalar(decode_hex32(&keys.priv_spend).unwrap()), Ok(keys.clone()));
+        // The order of the group is the first scalar that is not canonical
+        let order_minus_one = decode_hex32("ecd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010").unwrap();
+        let boundary = keys_from_spend_scalar(order_minus_one).unwrap();
+        assert_eq!(verify_keys_consistency(&boundary), Ok(()));
+        let order = decode_hex32("edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010").unwrap();
+        assert_eq!(keys_from_spend_scalar(order), Err(KeyError::NonCanonicalScalar));
+        // derive_priv_keys reduces the hex seed of the wallet, keys_from_spend_scalar refuses it
+        let hex_seed = decode_hex32("f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8").unwrap();
+        assert_eq!(keys_from_spend_scalar(hex_seed), Err(KeyError::NonCanonicalScalar));
+        assert_eq!(keys_from_spend_scalar([0; 32]).unwrap().priv_spend, "00".repeat(32));
+    }
+
+    #[test]
+    fn unknown_address_prefixes() {
+        let bytes = address_to_bytes("4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J").unwrap();
+        let with_prefix = |prefix: &[u8]| -> String {
+            let mut data = [prefix, &bytes[1..65]].concat();
+            data.extend_from_slice(&cn_fast_hash(&data)[..4]);
+            base58_monero::encode(&data).unwrap()
+        };
+        assert_eq!(decode_address(&with_prefix(&[0x42])), Err(KeyError::UnknownPrefix(0x42)));
+        // Prefixes of several varint bytes, like Aeon's 0xb2 (0xb2 0x01), are reported by their first byte
+        let aeon = derive_address_for_coin(hex::encode(&bytes[1..33]), hex::encode(&bytes[33..65]), 0, Coin::Aeon).unwrap();
+        assert_eq!(decode_address(&aeon), Err(KeyError::UnknownPrefix(0xb2)));
+        assert_eq!(decode_address(&with_prefix(&[0x12])).unwrap().public_spend_key, hex::encode(&bytes[1..33]));
+        assert_eq!(KeyError::UnknownPrefix(0x42).to_string(), "Unknown address prefix 0x42");
+    }
+
+    #[test]
+    fn subaddress_ranges() {
+        let private_view_key = "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908";
+        let public_spend_key = "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95";
+        let keys = derive_subaddress_range(private_view_key, public_spend_key, 2, 0..4).unwrap();
+        assert_eq!(hex::encode(keys[3]), "be184cfbdd9cbd6957b8cf336d547d5fcfe339e816558b28279639620c40a12d");
+        // Ranges not starting at 0 give the same keys
+        assert_eq!(derive_subaddress_range(private_view_key, public_spend_key, 2, 2..4).unwrap(), keys[2..]);
+        let cache = SubaddressIndexCache::new(private_view_key, public_spend_key, 3, 4).unwrap();
+        for (minor, key) in keys.iter().enumerate() {
+            assert_eq!(cache.lookup(&hex::encode(key)), Some((2, minor as u32)));
+        }
+        assert_eq!(derive_subaddress_range(private_view_key, public_spend_key, 0, 7..7), Ok(Vec::new()));
+        assert_eq!(derive_subaddress_range(private_view_key, private_view_key, 0, 0..1), Err(KeyError::InvalidPoint));
+    }
+
+    #[test]
+    fn checksum_word_indexes() {
+        for language in ["en", "eo", "fr", "it", "ja", "lj", "pt", "ru"] {
+            for seed_type in ["original", "mymonero"] {
+                let mnemonic = generate_seed(language, seed_type);
+                let index = checksum_word_index(&mnemonic, language).unwrap();
+                // The checksum word of a generated seed is the word at the index
+                assert_eq!(mnemonic[index], mnemonic[mnemonic.len() - 1]);
+                assert_eq!(checksum_word_index(&mnemonic[..mnemonic.len() - 1], language), Ok(index));
+            }
+        }
+        let mnemonic: Vec<String> = "dwarf itinerary army dehydrate yahoo hairy eels dexterity tamper wedge begun nestle hairy".split(' ').map(|s| s.to_string()).collect();
+        assert_eq!(checksum_word_index(&mnemonic, "en"), Ok(5));
+        // The checksum word is not checked
+        let mut wrong_checksum = mnemonic.clone();
+        wrong_checksum[12] = "dwarf".to_string();
+        assert_eq!(checksum_word_index(&wrong_checksum, "en"), Ok(5));
+        assert_eq!(checksum_word_index(&mnemonic, "fr"), Err(KeyError::InvalidWord { position: 0, word: "dwarf".to_string() }));
+        assert_eq!(checksum_word_index(&mnemonic, "xx"), Err(KeyError::UnknownWordset));
+        assert!(matches!(checksum_word_index(&mnemonic[..11], "en"), Err(KeyError::WrongWordCount { got: 11, .. })));
+    }
+
+    #[test]
+    fn malformed_address_payloads() {
+        let bytes = address_to_bytes("4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J").unwrap();
+        // Payloads of every length up to twice an integrated address, with a valid checksum or not, never panic
+        for len in 0..=154 {
+            let mut payload: Vec<u8> = (0..len).map(|i| bytes[i % bytes.len()]).collect();
+            assert!(bytes_to_address(&payload).is_err() || len == 69, "{}", len);
+            if len > 4 {
+                let checksum = cn_fast_hash(&payload[..len - 4]);
+                payload[len - 4..].copy_from_slice(&checksum[..4]);
+                let result = bytes_to_address(&payload);
+                assert_eq!(result.is_ok(), len == 69, "{}", len);
+            }
+        }
+        // Keys that cannot make an address are errors before anything is encoded
+        let public_view_key = "157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47".to_string();
+        for public_spend_key in ["", "zz", &"00".repeat(33), "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908"] {
+            assert!(derive_address(public_spend_key.to_string(), public_view_key.clone(), 0).is_err());
+        }
+        assert_eq!(derive_address(public_view_key.clone(), public_view_key.clone(), 7), Err(KeyError::InvalidNetwork));
+    }
+
+    #[test]
+    fn keccak_prf_domains() {
+        let view_key = decode_hex32("0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908").unwrap();
+        let inputs: [&[u8]; 3] = [&view_key, &0u32.to_le_bytes(), &1u32.to_le_bytes()];
+        // The label key of a subaddress is its secret under another domain
+        let label_key = subaddress_label_key("0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908", 0, 1).unwrap();
+        assert_eq!(reduce_scalar(keccak_prf(b"SubAddrLabel\0", &inputs)), label_key);
+        assert_ne!(reduce_scalar(keccak_prf(b"SubAddr\0", &inputs)), label_key);
+        assert_eq!(keccak_prf(b"SubAddr\0", &inputs), cn_fast_hash(&[&b"SubAddr\0"[..], &inputs.concat()].concat()));
+        // Only the concatenation is hashed, however it is split
+        assert_eq!(keccak_prf(b"view_tag", &[]), cn_fast_hash(b"view_tag"));
+        assert_eq!(keccak_prf(b"", &[b"view_", b"tag"]), cn_fast_hash(b"view_tag"));
+        assert_eq!(derive_view_tag(&view_key, 3), keccak_prf(b"view_tag", &[&view_key, &[3]])[0]);
+    }
+
+    #[test]
+    fn generated_seeds_round_trip() {
+        for language in ["en", "eo", "fr", "it", "ja", "lj", "pt", "ru"] {
+            for _ in 0..50 {
+                for seed_type in ["original", "mymonero"] {
+                    let mnemonic = generate_seed(language, seed_type);
+                    assert_eq!(validate_mnemonic(&mnemonic), Ok(()), "{} {}", language, seed_type);
+                }
+            }
+        }
+        assert_eq!(KeyError::GenerationFailed.to_string(), "Generated seed does not round-trip");
+    }
+
+    #[test]
+    fn main_subaddress() {
+        let keys = [
+            ("0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908", "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08"),
+            ("502167754302403657cf241bc2f3e5d5e262a05e35eb93f0695cc7b18e98f602", "5d2aa8349968ed30f4688ab684813ec22a8a5bf21a957e72bc10539642892802"),
+        ];
+        for (priv_view, priv_spend) in keys {
+            let pub_spend = derive_pub_key(priv_spend.to_string()).unwrap();
+            let pub_view = derive_pub_key(priv_view.to_string()).unwrap();
+            for (index, network) in [Network::Mainnet, Network::Testnet, Network::Stagenet].into_iter().enumerate() {
+                // Index (0, 0) is the main address, not a subaddress of the main keys
+                let main_address = derive_subaddress(priv_view, &pub_spend, 0, 0, network).unwrap();
+                assert_eq!(main_address, derive_address(pub_spend.clone(), pub_view.clone(), index as u8).unwrap());
+                assert_eq!(decode_address(&main_address).unwrap().address_type, AddressType::Standard);
+                for (major, minor) in [(0, 1), (1, 0), (1, 1)] {
+                    let subaddress = derive_subaddress(priv_view, &pub_spend, major, minor, network).unwrap();
+                    let decoded = decode_address(&subaddress).unwrap();
+                    assert_eq!(decoded.address_type, AddressType::Subaddress);
+                    assert_eq!(decoded.network, network);
+                    assert_eq!(hex::encode(derive_subaddress_range(priv_view, &pub_spend, major, minor..minor + 1).unwrap()[0]), decoded.public_spend_key);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn priv_keypairs() {
+        for hex_seed in ["f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8", "fddb58a226ba0a61cc48e2a393739378"] {
+            let priv_keys = derive_priv_keys(hex_seed.to_string()).unwrap();
+            let keypair: PrivKeyPair = derive_priv_keypair(hex_seed.to_string()).unwrap();
+            assert_eq!(keypair.spend, priv_keys[0]);
+            assert_eq!(keypair.view, priv_keys[1]);
+            assert!(!format!("{:?}", keypair).contains(&keypair.spend));
+        }
+        assert_eq!(derive_priv_keypair("f7b3".to_string()), Err(KeyError::InvalidHexSeed));
+    }
+
+    #[test]
+    fn pasted_hex_seeds() {
+        for hex_seed in ["f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8", "fddb58a226ba0a61cc48e2a393739378"] {
+            let priv_keys = derive_priv_keys(hex_seed.to_string()).unwrap();
+            for pasted in [format!("0x{}", hex_seed), format!("  {}\n", hex_seed), format!("\t0X{}\r\n", hex_seed.to_uppercase())] {
+                assert_eq!(normalize_hex_seed(&pasted), hex_seed);
+                assert_eq!(derive_priv_keys(pasted.clone()), Ok(priv_keys.clone()));
+                assert_eq!(derive_priv_keys_checked(pasted.clone()), Ok(priv_keys.clone()));
+                let kind = if hex_seed.len() == 64 { SeedKind::Original } else { SeedKind::MyMonero };
+                assert_eq!(derive_priv_keys_typed(pasted.clone(), kind), Ok(priv_keys.clone()));
+                assert_eq!(derive_priv_keys_with_passphrase(pasted.clone(), ""), Ok(priv_keys.clone()));
+                if hex_seed.len() == 64 {
+                    let with_passphrase = derive_priv_keys_with_passphrase(hex_seed.to_string(), "pw").unwrap();
+                    assert_eq!(derive_priv_keys_with_passphrase(pasted, "pw"), Ok(with_passphrase));
+                }
+            }
+        }
+        assert_eq!(derive_priv_keys_checked(format!("0x{}", "00".repeat(32))), Err(KeyError::WeakSeed));
+        assert_eq!(derive_priv_keys("0x".to_string()), Err(KeyError::InvalidHexSeed));
+        assert_eq!(derive_priv_keys(format!("0x{}", "zz".repeat(32))), Err(KeyError::InvalidHex));
+    }
+
+    #[test]
+    fn wallet_address_stream() {
+        let mnemonic: Vec<String> = "tissue raking haunted huts afraid volcano howls liar egotistic befit rounded older bluntly imbalance pivot exotic tuxedo amaze mostly lukewarm macro vocal hounded biplane rounded".split(' ').map(|word| word.to_string()).collect();
+        let keys = derive_all_keys(mnemonic).unwrap();
+        let addresses: Vec<(u32, u32, String)> = wallet_addresses(&keys, Network::Stagenet, 0..3, 0..4).unwrap().collect();
+        assert_eq!(addresses.len(), 12);
+        assert_eq!(addresses[0], (0, 0, derive_address(keys.pub_spend.clone(), keys.pub_view.clone(), 2).unwrap()));
+        for (major, minor, address) in &addresses {
+            assert_eq!(*address, derive_subaddress(&keys.priv_view, &keys.pub_spend, *major, *minor, Network::Stagenet).unwrap());
+        }
+        assert_eq!(addresses[5].0, 1);
+        assert_eq!(addresses[5].1, 1);
+        assert_eq!(wallet_addresses(&keys, Network::Mainnet, 5..5, 0..10).unwrap().count(), 0);
+        // A huge range costs nothing until it is walked
+        assert_eq!(wallet_addresses(&keys, Network::Mainnet, 0..u32::MAX, 0..u32::MAX).unwrap().nth(2).unwrap().1, 2);
+        let mut bad_keys = keys.clone();
+        bad_keys.priv_view = format!("01{}", "00".repeat(31));
+        assert!(matches!(wallet_addresses(&bad_keys, Network::Mainnet, 0..1, 0..1), Err(KeyError::DuplicateKeys)));
+        bad_keys.priv_view = "zz".to_string();
+        assert!(matches!(wallet_addresses(&bad_keys, Network::Mainnet, 0..1, 0..1), Err(KeyError::InvalidHexLength { got: 2, expected: 64 })));
+    }
+
+    #[test]
+    fn degenerate_pub_keys() {
+        for private_key in ["c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08", "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908"] {
+            assert_eq!(derive_pub_key_checked(private_key.to_string()), derive_pub_key(private_key.to_string()));
+        }
+        // Zero and every multiple of the group order l give the identity
+        let order = "edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010";
+        for private_key in ["00".repeat(32), order.to_string()] {
+            assert_eq!(derive_pub_key_checked(private_key.clone()), Err(KeyError::DegeneratePubKey));
+            assert_eq!(derive_pub_key(private_key).unwrap(), format!("01{}", "00".repeat(31)));
+        }
+        // One is the base point, not degenerate
+        assert_eq!(derive_pub_key_checked(format!("01{}", "00".repeat(31))).unwrap(), format!("58{}", "66".repeat(31)));
+        assert_eq!(derive_pub_key_checked("zz".to_string()), Err(KeyError::InvalidHexLength { got: 2, expected: 64 }));
+        assert_eq!(KeyError::DegeneratePubKey.to_string(), "Degenerate public key");
+    }
+
+    #[test]
+    fn message_signatures() {
+        let address = "4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J";
+        let (priv_spend, priv_view) = ("c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08", "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908");
+        let other_address = "472WVCejhFENH2oFCMcMdp2V7o1AEQy2w7eExArqhTZ8QBNauqKUidSSFwZ8GjWZxjARFdLyExGc8jNHg4DeT9cgTfMmSoB";
+        // Made by tests/vectors/generate.py with a fixed nonce, a SigV1 and SigV2 signatures with the spend and view keys
+        let v1 = "SigV1RP7a84ataBoHj35TeqT3ST3732igZgrMW7cYF56Fd5ypQPny97RMX4yRBbHSVQTTu59wAgmp2DFnTFgsJ7cH4vPy";
+        let v2_spend = "SigV2Xa8E3uUC8n6djh8cun3QgsfCdmfRGohxiPpSmsnEhzFe2urfLNnJWj5Dyjqf9te1ugQTcynEU3qDXYgs5ZA9hYro";
+        let v2_view = "SigV2fJHuonMhzoJfpWL3aVsnZWAbbKFWVrfZAbYAUctvckzjfD6ZcAph4GwJ3wZfmMnRuhJKB4tKCcLanQYpfptT52qd";
+        for vector in [v1, v2_spend, v2_view] {
+            assert!(verify_message(address, b"Hello, world!", vector));
+            assert!(!verify_message(address, b"Hello, world", vector));
+            assert!(!verify_message(other_address, b"Hello, world!", vector));
+            assert!(!verify_message(address, b"Hello, world!", &vector[..vector.len() - 11]));
+            // The vector with its challenge zeroed
+            assert!(!verify_message(address, b"Hello, world!", &format!("{}{}{}", &vector[..5], "1".repeat(44), &vector[49..])));
+        }
+        // The versions hash differently
+        assert!(!verify_message(address, b"Hello, world!", &v1.replacen("SigV1", "SigV2", 1)));
+        assert!(!verify_message(address, b"Hello, world!", &v2_spend.replacen("SigV2", "SigV1", 1)));
+        for message in [&b""[..], b"Hello, world!", &[0xffu8; 300]] {
+            for private_key in [priv_spend, priv_view] {
+                let signature = sign_message(address, private_key, message).unwrap();
+                assert!(signature.starts_with("SigV2"));
+                assert_eq!(signature.len(), 5 + 88);
+                assert!(verify_message(address, message, &signature));
+                // Fresh nonces, even with the deterministic-rng feature
+                assert_ne!(signature, sign_message(address, private_key, message).unwrap());
+                // Another address or a changed symbol do not verify
+                assert!(!verify_message(other_address, message, &signature));
+                let last = if signature.ends_with('2') { "3" } else { "2" };
+                assert!(!verify_message(address, message, &format!("{}{}", &signature[..signature.len() - 1], last)));
+            }
+        }
+        assert!(!verify_message("zz", b"Hello, world!", v2_spend));
+        assert_eq!(sign_message(address, "zz", b"Hello, world!"), Err(KeyError::InvalidHexLength { got: 2, expected: 64 }));
+        assert_eq!(sign_message(other_address, priv_spend, b"Hello, world!"), Err(KeyError::AddressMismatch));
+        assert!(sign_message("4AQ3", priv_spend, b"Hello, world!").is_err());
+    }
+
+    #[test]
+    fn caught_key_panics() {
+        assert_eq!(catch_key_panic(|| derive_hex_seed(vec!["tissue".to_string()])), Ok(Err(KeyError::WrongWordCount { got: 1, expected: &[12, 13, 24, 25] })));
+        assert_eq!(catch_key_panic(|| generate_seed("xx", "original")), Err(KeyError::Panicked("Seed generation failed: UnknownWordset".to_string())));
+        assert_eq!(catch_key_panic(|| -> u8 { panic!("{} {}", "formatted", 1) }), Err(KeyError::Panicked("formatted 1".to_string())));
+        assert_eq!(catch_key_panic(|| std::panic::panic_any(7u8)), Err::<(), _>(KeyError::Panicked("unknown panic payload".to_string())));
+        assert_eq!(KeyError::Panicked("oops".to_string()).to_string(), "Panicked: oops");
+    }
+
+    #[test]
+    fn dice_seeds() {
+        let rolls: Vec<u8> = "5363666514261213424515216243242125542211222233256622624313422313355156631333463244621316341543451416".bytes().map(|digit| digit - b'0').collect();
+        // The rolls as a base-6 number modulo 2^256
+        let hex_seed = "37f391cea9e7df0e601c8de0eb9fde9fa989cbff629d0a0ff0d63588c55f9cd1";
+        let mnemonic = seed_from_dice(&rolls, "en", "original").unwrap();
+        assert_eq!(mnemonic, generate_seed_from_entropy("en", "original", &hex::decode(hex_seed).unwrap()).unwrap());
+        assert_eq!(derive_hex_seed(mnemonic).unwrap(), hex_seed);
+        // Extra rolls are used too
+        let mut more_rolls = rolls.clone();
+        more_rolls.push(4);
+        assert_ne!(seed_from_dice(&more_rolls, "en", "original"), seed_from_dice(&rolls, "en", "original"));
+        assert_eq!(seed_from_dice(&rolls[..50], "en", "mymonero").unwrap().len(), 13);
+        assert_eq!(seed_from_dice(&rolls[..49], "en", "mymonero"), Err(KeyError::NotEnoughRolls { got: 49, need: 50 }));
+        assert_eq!(seed_from_dice(&rolls[..59], "en", "polyseed").unwrap().len(), 16);
+        assert_eq!(seed_from_dice(&rolls[..58], "en", "polyseed"), Err(KeyError::NotEnoughRolls { got: 58, need: 59 }));
+        let mut bad_rolls = rolls.clone();
+        bad_rolls[7] = 0;
+        assert_eq!(seed_from_dice(&bad_rolls, "en", "original"), Err(KeyError::InvalidDiceRoll { position: 7, roll: 0 }));
+        bad_rolls[7] = 7;
+        assert_eq!(seed_from_dice(&bad_rolls, "en", "original"), Err(KeyError::InvalidDiceRoll { position: 7, roll: 7 }));
+        // One face, two faces or a die loaded on six
+        for biased in [vec![1u8; 100], [1u8, 2].repeat(50), [6u8, 6, 6, 1, 2, 3].repeat(17)] {
+            assert_eq!(seed_from_dice(&biased, "en", "original"), Err(KeyError::BiasedDiceRolls));
+        }
+        assert_eq!(seed_from_dice(&[1, 2, 3, 4, 5, 6].repeat(17), "en", "original").unwrap().len(), 25);
+        assert_eq!(seed_from_dice(&rolls, "en", "bip39"), Err(KeyError::UnknownSeedType("bip39".to_string())));
+        assert_eq!(seed_from_dice(&rolls, "xx", "original"), Err(KeyError::UnknownWordset));
+    }
+
+    #[test]
+    fn new_wallet_errors() {
+        assert_eq!(catch_key_panic(|| Wallet::new("en", "bogus", 0)), Ok(Err(KeyError::UnknownSeedType("bogus".to_string()))));
+        assert_eq!(catch_key_panic(|| Wallet::new("xx", "original", 0)), Ok(Err(KeyError::UnknownLanguage("xx".to_string()))));
+        assert_eq!(Wallet::new("ko", "original", 0), Err(KeyError::UnknownWordset));
+        // Polyseed wallets are opened through the polyseed key
+        let wallet = Wallet::new("en", "polyseed", 0).unwrap();
+        assert_eq!(wallet.mnemonic.len(), 16);
+        assert_eq!(wallet.hex_seed, PolyseedSeed.to_hex_seed(&wallet.mnemonic).unwrap());
+        assert_eq!(wallet.main_address, address_from_priv_keys(&wallet.priv_sk, &wallet.priv_vk, 0).unwrap());
+        let mnemonic: Vec<String> = "raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language".split(' ').map(|word| word.to_string()).collect();
+        let wallet = Wallet::open_wallet(mnemonic.clone(), 0).unwrap();
+        assert_eq!(wallet.hex_seed, "21268a76048a3b25a4a9ac179d86b12fab5800b8d858da9facf4b0a778dc2840");
+        assert_eq!(wallet.priv_sk, derive_priv_keys(wallet.hex_seed.clone()).unwrap()[0]);
+        assert!(to_keys_file_fields(&wallet).unwrap().seed.is_empty());
+        let encrypted = encrypt_polyseed(&mnemonic, "pw").unwrap();
+        assert_eq!(Wallet::open_wallet(encrypted, 0), Err(KeyError::EncryptedPolyseed));
+    }
+
+    #[test]
+    fn wrong_length_hex() {
+        let priv_sk = "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08";
+        assert_eq!(derive_pub_key(priv_sk[..63].to_string()), Err(KeyError::InvalidHexLength { got: 63, expected: 64 }));
+        assert_eq!(derive_priv_vk_from_priv_sk(priv_sk[1..].to_string()), Err(KeyError::InvalidHexLength { got: 63, expected: 64 }));
+        assert_eq!(derive_priv_vk_from_priv_sk(format!("{}0", priv_sk)), Err(KeyError::InvalidHexLength { got: 65, expected: 64 }));
+        assert_eq!(derive_priv_keys(priv_sk[..63].to_string()), Err(KeyError::InvalidHexSeed));
+        assert_eq!(derive_priv_keys(priv_sk[..31].to_string()), Err(KeyError::InvalidHexSeed));
+        assert_eq!(KeyError::InvalidHexLength { got: 63, expected: 64 }.to_string(), "Hexadecimal string has 63 characters, expected 64");
+    }
+
+    #[test]
     fn hashing_cn_slow_hash_v0() {
 
         let input = b"This is a test";
@@ -0,0 +1,50 @@
+#![cfg(feature = "logging")]
+
+#[cfg(test)]
+mod logging {
+    use libmonero::keys::{derive_address, derive_all_keys, polyseed_entropy};
+    use log::{Level, LevelFilter, Log, Metadata, Record};
+    use std::sync::Mutex;
+
+    // Keeps every logged message so they can be checked
+    struct Recorder(Mutex<Vec<String>>);
+
+    impl Log for Recorder {
+        fn enabled(&self, metadata: &Metadata) -> bool {
+            metadata.level() <= Level::Debug
+        }
+
+        fn log(&self, record: &Record) {
+            self.0.lock().unwrap().push(record.args().to_string());
+        }
+
+        fn flush(&self) {}
+    }
+
+    static RECORDER: Recorder = Recorder(Mutex::new(Vec::new()));
+
+    #[test]
+    fn derivation_markers_without_secrets() {
+        log::set_logger(&RECORDER).unwrap();
+        log::set_max_level(LevelFilter::Debug);
+
+        let mnemonic: Vec<String> = "tissue raking haunted huts afraid volcano howls liar egotistic befit rounded older bluntly imbalance pivot exotic tuxedo amaze mostly lukewarm macro vocal hounded biplane rounded".split(' ').map(|s| s.to_string()).collect();
+        let keys = derive_all_keys(mnemonic.clone()).unwrap();
+        derive_address(keys.pub_spend.clone(), keys.pub_view.clone(), 0).unwrap();
+        let polyseed: Vec<String> = "raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language".split(' ').map(|s| s.to_string()).collect();
+        polyseed_entropy(&polyseed).unwrap();
+
+        let messages = RECORDER.0.lock().unwrap().clone();
+        assert_eq!(messages, vec![
+            "found en wordset for 25-word seed",
+            "derived 64-character hex seed",
+            "derived private keys from original hex seed",
+            "built address with prefix 0x12",
+            "decoded polyseed seed of en wordset",
+        ]);
+        let secrets = [&mnemonic[..], &polyseed[..], &[keys.priv_spend, keys.priv_view, "f7b3beabc9bd6ced864096c0891a8fdf94dc714178a09828775dba01b4df9ab8".to_string()]].concat();
+        for message in messages.iter() {
+            assert!(!secrets.iter().any(|secret| message.contains(&secret[..8.min(secret.len())])), "{}", message);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+#![cfg(feature = "rayon")]
+
+#[cfg(test)]
+mod rayon {
+    use libmonero::keys::{derive_subaddress_range, derive_subaddress_range_parallel, KeyError, SubaddressIndexCache};
+
+    const PRIVATE_VIEW_KEY: &str = "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908";
+    const PUBLIC_SPEND_KEY: &str = "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95";
+
+    #[test]
+    fn parallel_subaddress_ranges() {
+        // Same keys in the same order as the serial derivation, whatever the range
+        for (major, minors) in [(0, 0..1000), (3, 500..1500), (u32::MAX, u32::MAX - 10..u32::MAX), (1, 5..5)] {
+            let parallel = derive_subaddress_range_parallel(PRIVATE_VIEW_KEY, PUBLIC_SPEND_KEY, major, minors.clone()).unwrap();
+            assert_eq!(parallel.len(), minors.len());
+            assert_eq!(parallel, derive_subaddress_range(PRIVATE_VIEW_KEY, PUBLIC_SPEND_KEY, major, minors).unwrap());
+        }
+        let cache = SubaddressIndexCache::new(PRIVATE_VIEW_KEY, PUBLIC_SPEND_KEY, 2, 1000).unwrap();
+        let keys = derive_subaddress_range_parallel(PRIVATE_VIEW_KEY, PUBLIC_SPEND_KEY, 1, 0..1000).unwrap();
+        for (minor, key) in keys.iter().enumerate() {
+            assert_eq!(cache.lookup(&hex::encode(key)), Some((1, minor as u32)));
+        }
+        assert_eq!(derive_subaddress_range_parallel(PRIVATE_VIEW_KEY, &"00".repeat(31), 0, 0..10), Err(KeyError::InvalidHexLength { got: 62, expected: 64 }));
+    }
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "rpc")]
+
+#[cfg(test)]
+mod rpc {
+    use libmonero::blocks::validate_address_rpc;
+    use libmonero::keys::KeyError;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    // Serves one request with the given JSON body and returns the URL of the server and the received request
+    fn serve_once(body: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/json_rpc", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 4096];
+            // Read until the end of the JSON body
+            while !request.ends_with(b"}}") {
+                let read = stream.read(&mut buffer).unwrap();
+                if read == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buffer[..read]);
+            }
+            let response = format!("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body);
+            stream.write_all(response.as_bytes()).unwrap();
+            String::from_utf8_lossy(&request).to_string()
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn address_validation() {
+        let address = "4AQ3jTJg91yNGTXjo9iWr1ekjBGJ5mM6HEsxKqoKddHnRwJTVJYnyLXeerff6iTys5Eo8dyG87tfqZNS5CcSd7U694YiR8J";
+        let (url, handle) = serve_once(r#"{"id":"0","jsonrpc":"2.0","result":{"integrated":false,"nettype":"mainnet","openalias_address":"","subaddress":false,"valid":true}}"#);
+        assert_eq!(validate_address_rpc(address, &url), Ok(true));
+        let request = handle.join().unwrap();
+        assert!(request.contains(r#""method":"validate_address""#));
+        assert!(request.contains(address));
+
+        let (url, handle) = serve_once(r#"{"id":"0","jsonrpc":"2.0","result":{"valid":false}}"#);
+        assert_eq!(validate_address_rpc("4AQ3", &url), Ok(false));
+        handle.join().unwrap();
+
+        let (url, handle) = serve_once(r#"{"id":"0","jsonrpc":"2.0","error":{"code":-32601,"message":"Method not found"}}"#);
+        assert!(matches!(validate_address_rpc(address, &url), Err(KeyError::Rpc(_))));
+        handle.join().unwrap();
+
+        let (url, handle) = serve_once(r#"{"id":"0","jsonrpc":"2.0","result":{}}"#);
+        assert!(matches!(validate_address_rpc(address, &url), Err(KeyError::Rpc(_))));
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn unreachable_server() {
+        // Nothing listens on the port of a dropped listener
+        let url = {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            format!("http://{}/json_rpc", listener.local_addr().unwrap())
+        };
+        assert!(matches!(validate_address_rpc("4AQ3", &url), Err(KeyError::RpcUnreachable(_))));
+    }
+}
@@ -0,0 +1,461 @@
+#[cfg(test)]
+mod vectors {
+    use libmonero::crypt::cn_fast_hash;
+    use libmonero::keys::{append_checksum_word, derive_address, derive_hex_seed, derive_priv_keys, derive_pub_key, generate_seed, mymonero_hex_to_address, polyseed_entropy, spend_key_to_mnemonic, validate_mnemonic, with_checksum, without_checksum, KeyError, PolyseedSeed, SeedScheme};
+
+    // Known-answer vectors of original and MyMonero seeds for every language of the 1626-word wordsets. They were
+    // computed from random seeds by tests/vectors/generate.py, a Python implementation of Monero's word encoding
+    // (src/mnemonics/electrum-words.cpp), key derivation and base58 address encoding, written from Monero's sources
+    // apart from this crate. `python3 tests/vectors/generate.py` prints this table and the polyseed one below.
+    struct Vector {
+        mnemonic: &'static str,
+        language: &'static str,
+        seed_type: &'static str,
+        hex_seed: &'static str,
+        priv_spend: &'static str,
+        priv_view: &'static str,
+        pub_spend: &'static str,
+        pub_view: &'static str,
+        address: &'static str,
+    }
+
+    const VECTORS: [Vector; 16] = [
+        Vector {
+            mnemonic: "cowl certain gumball acumen juicy bakery tawny jive oaks sidekick tarnished psychic luxury cunning pastry diet plotting deepest thirsty urchins syringe abbey movement total cunning",
+            language: "en",
+            seed_type: "original",
+            hex_seed: "52a89335878254a36c54c2259191d9d8e0be57782a3e3c85550f07ea98bc5852",
+            priv_spend: "b184c6640393f8ea3c44ecf637b07e70e0be57782a3e3c85550f07ea98bc5802",
+            priv_view: "93a8957d34aec1afb7ffe6966c91948365e13a3ddf1341a1ffd0f23b327bf205",
+            pub_spend: "8e969d359b55197f340904418d853f08e07d040c8c6b5827b5dc32dd73ca8f8a",
+            pub_view: "93c17df69632dd97015b752e849268384eb0e2d7eee339fd4bbfc4b9fac655ec",
+            address: "472WVCejhFENH2oFCMcMdp2V7o1AEQy2w7eExArqhTZ8QBNauqKUidSSFwZ8GjWZxjARFdLyExGc8jNHg4DeT9cgTfMmSoB",
+        },
+        Vector {
+            mnemonic: "radiko erbio dupiedulo aferulo gamao kuko edelvejso hibrida smeraldo kirurgo psalmo utero papero nabo sopiri gavio knedi zenismo fasko norda adulto batilo besto budo dupiedulo",
+            language: "eo",
+            seed_type: "original",
+            hex_seed: "95d04cef69bf3a2e6947fa6b36296c2dc4788f3828bfa374c05dba56f4263208",
+            priv_spend: "95d04cef69bf3a2e6947fa6b36296c2dc4788f3828bfa374c05dba56f4263208",
+            priv_view: "a88e75c623a6d65cf901d92375fad037228cdf908666d1d2b6ecd78961031b03",
+            pub_spend: "f9fc85249980b31ea104efea81df13c4516bff81a7f0842a34bb17083372dbd2",
+            pub_view: "4a87bcce60e87132d649d660627fd6696b070fcb90c8919ff5713b45203e8374",
+            address: "4B6ZecE4bea6896EwRprygZqXoRwteWpK84T9jkYYSmxcB655LQ4xbz9WBdJD6kxFfJdgsPqtVvA4TkoRKyDRjopEC3g9fx",
+        },
+        Vector {
+            mnemonic: "groupe pire geste dieu nourrir aucun brouter histoire forger luge crampe orgueil manger tasse habiter fureur opter chez larve halte largeur cuve peser suffire manger",
+            language: "fr",
+            seed_type: "original",
+            hex_seed: "62cc44b5c758217b9519bcef48145567171eb57f74d6129024992a11727a9639",
+            priv_spend: "9b50639e782fea721243d506ad26b828171eb57f74d6129024992a11727a9609",
+            priv_view: "1f18d3249689e8a8655e6bd124fa095b88933db583a66d1d057f135be4106107",
+            pub_spend: "c35b76893d63d316bb8df7b604654c470f5bd615b90616c76a1c44f899d3a94d",
+            pub_view: "8e20638517faff62dad34fce099d79cf2a216507323cd6326e4bffcf5bed9df6",
+            address: "492VVumDHBp4oY2rsAQMBHCtNWvp3RCM3aMa5nNzbxfJDyPBSCxiH4SHY1tM1iEQhNbekqhAUiDLZ9SF4Q8gK5UGUrFMy35",
+        },
+        Vector {
+            mnemonic: "riflesso egiziano segare sedativo pecora invocare corrente opuscolo permesso idrante gufo mostro attrarre lastra scheggia stinco dividere pinguino mostro aurora udienza orchidea temere buono egiziano",
+            language: "it",
+            seed_type: "original",
+            hex_seed: "ee971c7ca7da65d7a9ce160b6fdc691e4eb8b648d337b95fe460d1d76bf1b342",
+            priv_spend: "3a4845083e4e1c77505b387ff4f4edca4db8b648d337b95fe460d1d76bf1b302",
+            priv_view: "98c9aff66666d4c52f59e780b7f2c7b7f4ded1a8e51ef13ac828c0b1c5c3fa08",
+            pub_spend: "031f687049be2b9a9a3ac310a63d7dd8662d351d864e04697bb36aff6d1d318a",
+            pub_view: "b0bb326dea02453ed3b9f2a0969da76cb722bdadb81f92b04cfde72c34515c9a",
+            address: "41jyLGyWeftSrqaQXW1oPzdCLsXqCEhPqJeKWupFJ6ACQCUGiFvUwMWBWW4rHowYUWKBgAjrL6geVWVLcQXFWsudJUp9L3R",
+        },
+        Vector {
+            mnemonic: "うちがわ してつ さます おぎなう いほう しいん おもいで こぐま ねくら ことがら あいこくしん こたえる がぞう てんごく そうご そっけつ せんえい こうさい ねくら せのび ていき うかぶ きひん せつめい そうご",
+            language: "ja",
+            seed_type: "original",
+            hex_seed: "34891af86fb99f6883269e83c541e56585cb1bd08f03f9c780a88a2fca4f2152",
+            priv_spend: "93654d27ecc943b05316c8546c608afd84cb1bd08f03f9c780a88a2fca4f2102",
+            priv_view: "3b3d8323a3ba6ac20b33a0e14e43c61298a30c162fffe1c353eafd7a05668c0b",
+            pub_spend: "714f9882c89df13f51228d56547c94d8ce5fba9229f67da1c4cc9ca1b5a39c20",
+            pub_view: "7a290ad14cdedc4393f98385377e5db9215b53d00c38e41b2226a3404abf75d9",
+            address: "45vABnf3JWkBbFi1JDZcWwdGHtfX4eXjAU4MrKDFoN5q6S52WQeMxodCJbJpZGufCxXxzkuoa7vAf5YEDxLHJt12RVmeZFp",
+        },
+        Vector {
+            mnemonic: "xadni cupra krefu kalci gerku cmeta sibli srera jubme catni lacni janco bapli mlana misno trina guzme cinri cinfo jilka vikmi favgau balvi jdima misno",
+            language: "lj",
+            seed_type: "original",
+            hex_seed: "5a383b42b64522dc9979de9872d083df0075afffcbc332cef42d5b854c2df54e",
+            priv_spend: "a6e863ce4cb9d87b4006000df8e8078c0075afffcbc332cef42d5b854c2df50e",
+            priv_view: "fc3f3fc8cdf0ef794c7220c7668e86286ade7a476054c28da24830a98f32a907",
+            pub_spend: "e7fadf43ce10c448fc46a8559b5266b1afeff33d58d013ec75e48e4a8c9a5f3a",
+            pub_view: "9c528905d957418f6269d09cf9818a76e28a8009c573b0d8400b6daa57220434",
+            address: "4AQzBLy96R1DD3vHCYLiiHWinm3W6WLTCgYxsKZ6j3jtAobbsQDLDqSQz1VmuSuw8mLtLJD9s8CZmdAu4T96k3Mm6tQPosU",
+        },
+        Vector {
+            mnemonic: "sucursal druso luvas envolvimento imovel ruivo rucula imputar adivinho ojeriza vigoroso harpista octogono pipocar amizades sogro pedunculo emulsao boazuda jantarada chuvoso saxofonista toar urano sucursal",
+            language: "pt",
+            seed_type: "original",
+            hex_seed: "ce797a52cc0ac058c2563f8ec240c572acd0f4515bccce8dafeb5aab77ec3408",
+            priv_spend: "ce797a52cc0ac058c2563f8ec240c572acd0f4515bccce8dafeb5aab77ec3408",
+            priv_view: "e7709c619bb8c3f32d6165ccfa1587e4afccd45a82d3a3b3370935c307c51406",
+            pub_spend: "3cfe97b2a695878b72a359afeb26629d7b3bda260a77d336ecff74a86001768b",
+            pub_view: "e1b55cb6ade7c266edada5c2be255789bf539afc493d7796d775d4f08f1e318c",
+            address: "43wAryKEx9LQKpU4MiUrqPTLmU6TJuCmYABrEN97dzWZQQ2bNBuCGd7JDY1mwxvjXkQ3KgKYQgUbGSEMU5ThzrvcGqv8v9u",
+        },
+        Vector {
+            mnemonic: "ария тюрьма изучать шанс никель пища клык костюм тетя войти рогатый мрачный опрос шрифт лихой пакет палец орган лимон декабрь ощущение зоопарк шляпа овца зоопарк",
+            language: "ru",
+            seed_type: "original",
+            hex_seed: "aa13747f92af901acc5a1b6c683d79cc17942675a39ad4f8327cf05f512c9996",
+            priv_spend: "55a0cf3aa533eb0143d766b19474a21017942675a39ad4f8327cf05f512c9906",
+            priv_view: "a461f21d1e689c377555ab1c66082e233819641b4be39f89864445d1f6045c0b",
+            pub_spend: "1b8cd5dd0fcd99345a9d4c55d8dca14fb0b8bd6cd8f902ff89c2ad0e53bdbbdc",
+            pub_view: "9aeb6141db30e2979cab5864db60ae5a9f7886d737a6343ba3078568bf9ac923",
+            address: "42ffMgTyvQp9ku9JSYqCDEEL6ZffdTFeRjk3KKmfBahQdu9VGwH8ptZSMpvPWcuPAVGAA3g8BLifVAyZ1tiiJAXa54TFtgy",
+        },
+        Vector {
+            mnemonic: "dwarf itinerary army dehydrate yahoo hairy eels dexterity tamper wedge begun nestle hairy",
+            language: "en",
+            seed_type: "mymonero",
+            hex_seed: "fddb58a226ba0a61cc48e2a393739378",
+            priv_spend: "5d2aa8349968ed30f4688ab684813ec22a8a5bf21a957e72bc10539642892802",
+            priv_view: "502167754302403657cf241bc2f3e5d5e262a05e35eb93f0695cc7b18e98f602",
+            pub_spend: "635a987fa7bbe6df277142ffff9254f3b593d4f93480090ed6e3b9ca07023423",
+            pub_view: "1d054fc8a8ddf35323d31ed67407c30f44e6f26617e3137aab2da3521c7bcfa0",
+            address: "45PUwJmgsiVeKshFDNaPWshmHYyQyvTb63UxjRoK8hGw6seL4uX68t2EuZa77D5Z1c3Z8Xta3PCW6MX3JbSUJsu8K7oWjFs",
+        },
+        Vector {
+            mnemonic: "animo fali hajlo nebulo angulo groto leciono kapitalo acida ekesti ukelelo fitingo animo",
+            language: "eo",
+            seed_type: "mymonero",
+            hex_seed: "a62bc818c733d7575e3e607ed75a016a",
+            priv_spend: "5bc0d5f5259e18e1799894827970925ff9539e32ee04a1f14a3036eb91e1ba07",
+            priv_view: "73eaa78c3bb177543d918652d8c51fca061602b374439b79a804fb17384d270e",
+            pub_spend: "90933706e9f7cf678ea33277fc202fb82390500e4557c0cb7b29f1a89cdfee42",
+            pub_view: "1af9c3573e5f539766bf93614f7f262002fb2164c66080f6deecb1e56949eea0",
+            address: "476skmSiP7cJKdnwF3CesGXoNwF5LWJtPb32FpMgzyimC4Jgne2KAZGSKnQWpAwkus6MZ5ToHpXy1iHxSy63DZSdK5rj2Sv",
+        },
+        Vector {
+            mnemonic: "citation mener borgne pondre doigt manger dune momie gaver organe broder kiwi manger",
+            language: "fr",
+            seed_type: "mymonero",
+            hex_seed: "7154cf97f9a56e4212bbf1d4bbfd5555",
+            priv_spend: "8c42fd303738a76b67d425a105136eb9d4a9e1b65a3ad3d9f9b9b48dfe13f60d",
+            priv_view: "1fa792d9f3f8d75dc873decdfe755b8c0afcb2e5719816f83775d6d4c8c27908",
+            pub_spend: "9a0e981128497a540e1286698a7706266e5e58adb2a000d7d6b44661f8471954",
+            pub_view: "5acb07c92881cc1f4c47f1e46f4924485ff6137e690398d305c5e8cb14972120",
+            address: "47TiX58RTARF4SS83B6CMB7Rq9NMWC3sVd6uXbZZEgYGF7M4X8BTLEX6EdWn73M26XD78MC3qZKmRcJBctkirkfa4gGm23o",
+        },
+        Vector {
+            mnemonic: "melone enigma jazz puntare labrador guidare crimine cenare rabbia spessore reattore ciuccio melone",
+            language: "it",
+            seed_type: "mymonero",
+            hex_seed: "8e39d42a8c2d1ef67384778526acd67e",
+            priv_spend: "a6e61d64d20c49080f1b961b2573c6040c5b3626686c162d14b6247b0df74f0e",
+            priv_view: "f3bab5255272ed2347294f25727e987f020fc7f118354debdccd18d5815d320c",
+            pub_spend: "4cb2c2230525c9d31b8960890d1a7a67b6218cbd0777ae94baadc8c6e9558e4e",
+            pub_view: "05eed8be3b85b6d7d19b7b6809b9ffd3a60c4dfb816f4d10a843f2f5923102ee",
+            address: "44XgndVVUGxcK1TKbECpW5JM8bbYLP2cVRsrryeJGUWhE3vWRkut1Emd6iKr8nLakvcQFtqBnwcZa3nbbnfBab2yTsj7ZGZ",
+        },
+        Vector {
+            mnemonic: "にりんしゃ してき はちみつ しゃこ こてん じゅうしょ かいぜん ていし さゆう ちたい きゃく てんぼうだい にりんしゃ",
+            language: "ja",
+            seed_type: "mymonero",
+            hex_seed: "00358c776086571f075e60b1a1bea889",
+            priv_spend: "5443be1a858c7e6385a0709fdfd9ece68ebb85bc8640b697b6101335446a380f",
+            priv_view: "67b7ccfc3bad898b5a8ac36dee1ffc5eb10b38ab263f1c2c3f54f59db1c30a08",
+            pub_spend: "80fd2eb26c641abcc3499488273045476f5df3c6ee42fe013d445f0808bbcc67",
+            pub_view: "41c3979a50e755e3addb560a284757b89504a4502088027e6759c97947d609b5",
+            address: "46WcqRgC5NZYaEgxe81FeUCx1Y7Uk1vJZ1D2LNckZeUjJGiq4sSeiYtf5mpWjwd5fkXsgJUH48TuBN9GuxcRnFkkMTyrhhU",
+        },
+        Vector {
+            mnemonic: "sumji rupnu patxu tolcando stura bangu cnegau cirla nunctu brife dekto lojbo cirla",
+            language: "lj",
+            seed_type: "mymonero",
+            hex_seed: "9e4728eaeb641c3d32b30ee1cd33b947",
+            priv_spend: "320429ae5d827ede0d973eacae21a78c6a06ce62d0af1bc288d39e0abe979002",
+            priv_view: "846063eca2e9989668bfd10a20353f7ef14847e23364392182b53893be18f90c",
+            pub_spend: "cd36e12fef71bd1ed1b197d3f78f8da218e366418e0ce9d254115b9cc155e5df",
+            pub_view: "b5a1f05daab95fd39b6a197f1e3791eb555dd50fa76a7eba180f31a812625a03",
+            address: "49QA6125ysS69z5HR6giQkU7Yg2Ujij6xcBT2vxh4aUCeRGDiiHS4rJcPrXFdQ5pDngN2hqmZmP9wY8LzgsMxErM1M8R4YG",
+        },
+        Vector {
+            mnemonic: "bourbon enevoar daiquiri bajular bcrepuscular junto alotropico mostruario luminoso iatista gourmet luneta luneta",
+            language: "pt",
+            seed_type: "mymonero",
+            hex_seed: "a18dfaed3b625f6a6c393df6ea8d9829",
+            priv_spend: "81ca9f65ab5a7464d46a685a4b3139cc3f239d6bf7f8e3cdcbd47e690a016501",
+            priv_view: "23f74a6303646d7556b1c540be22bafaf2905eaf60754a61b8c5b39b5fc46608",
+            pub_spend: "3bb151e96fcc033b6a4a1d8feee1634681cc19a924ef4476d896c10278ab8399",
+            pub_view: "8b685cace8d6f50154b062e35dcdfefa3ec38f098a14c92547a2ec1c655feb36",
+            address: "43tJuHoTyWaAwQJfWUCxKCCo1NbfZxBxFLsxRWNJXBqUSgaKthUc1qn1DupDMnhCVBirh7exKniKi7EfLmEXzqpW77Torjr",
+        },
+        Vector {
+            mnemonic: "пыль снижение пещера центр тряпка муха мужчина лист артист бюджет маяк сюжет тряпка",
+            language: "ru",
+            seed_type: "mymonero",
+            hex_seed: "c0c95ede4c8cf7aae1e6aca1f43f7755",
+            priv_spend: "7672007ea4c6b3232dc5f607a0ce4ba79c38f9f9e061aa575f281353f6d36e02",
+            priv_view: "54d3c9f67b05a50c64726edf897cc9973e28c9bc1a0140697b300c7c90e97f04",
+            pub_spend: "3563ca87c7d6a9a07bfb2c4c69e36e56398159a8878344f10be07ca896d74aa9",
+            pub_view: "87d99c81c60391fdaa0f6e4c7088293e7a729ce79d143cc6f6a543ef98f8e117",
+            address: "43eTQrf1LFJTqu8QfWeckmFRVEzxsfHuDhKTDRpGAK5bVMfGb2z1cikjRrxts6CB6QBT7qZWqVxzPaHCJGBNW2sJ3hhbZSH",
+        },
+    ];
+
+    // Known-answer vectors of polyseed seeds for every language of the polyseed wordsets, without features. The English
+    // phrase and its secret are the test vector of the polyseed reference implementation (tests/tests.c of
+    // tevador/polyseed), the other phrases encode secrets hashed from the language code. The phrases, polyseed keys,
+    // keys and addresses were computed by tests/vectors/generate.py, which implements polyseed from the reference
+    // sources (src/gf.c, src/storage.c and src/polyseed.c) and checks that it decodes the reference phrase to its
+    // secret.
+    struct PolyseedVector {
+        mnemonic: &'static str,
+        language: &'static str,
+        secret: &'static str,
+        hex_seed: &'static str,
+        priv_spend: &'static str,
+        priv_view: &'static str,
+        pub_spend: &'static str,
+        pub_view: &'static str,
+        address: &'static str,
+    }
+
+    const POLYSEED_VECTORS: [PolyseedVector; 10] = [
+        PolyseedVector {
+            mnemonic: "raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language",
+            language: "en",
+            secret: "dd76e7359a0ded37cd0ff0f3c829a5ae016733",
+            hex_seed: "21268a76048a3b25a4a9ac179d86b12fab5800b8d858da9facf4b0a778dc2840",
+            priv_spend: "6dd6b2029bfdf1c44a36ce8b229f35dcaa5800b8d858da9facf4b0a778dc2800",
+            priv_view: "3c56a3cc3e7f94dc428ffe3b856adb6054552dfa14360d4cdec3f7730b999107",
+            pub_spend: "92544fe21cbd03a8f98f3571d6e2c0b10528deb9fb56fe45f0612132ff4b9275",
+            pub_view: "f4cc71c8e018594db6e0b2b4e18b74a5b7ef315273d24f6caec878c3c33ffa99",
+            address: "47AjPj7DVPQVGGXJXbbTMZWcKQDejGHYZChVkeujy8qPLjKkgdsxge4DzvkRMgU4sDUigGLuBN9stKBMowhuXH2HJHWAuRf",
+        },
+        PolyseedVector {
+            mnemonic: "dvojice zubr kousek pejsek velitel kulka zapsat metoda dolina vydat srdce bohatost bodlina okrasa suchar invalida",
+            language: "cs",
+            secret: "ff14a9478857fd268480eab1f0ac2a8832e337",
+            hex_seed: "2788f6eb3424527bc0a2bafb6e95b6cf27bea06970ea8a334e4335934b279252",
+            priv_spend: "8664291bb134f6c29092e4cc15b45b6727bea06970ea8a334e4335934b279202",
+            priv_view: "4fbbfc64d4c2c1caf79eb2008f371dd0debc24342d2ef0b43702fbeec4aae001",
+            pub_spend: "5185ebf7541b648a8149d734c5ffc6efcdaf79008c4650d781fcdd00178ced9e",
+            pub_view: "57fb04bcf485a465ad6343fa67be6835f84686de1ed6cd6aa19b46f32e567b47",
+            address: "44iHrzngr8bQAfzgMopMuwh7PrDQFvpp7d3hKu6skFggTV8f1FjWPMVJ1Q2y7jG1voA2aM2c4ymekJqTWATF2u6297T3xkm",
+        },
+        PolyseedVector {
+            mnemonic: "evitar mejor cuchara fiable surgir tarde fiel horno espejo jarabe plomo lino menú respeto poseer cría",
+            language: "es",
+            secret: "8c0f057f7ae19636e140756d980a33c82e4329",
+            hex_seed: "99bf7fe988f3bbf43337ea89957d1818fe1c618a4b5d44e87af73cf1ea6b3d5c",
+            priv_spend: "f89bb2180504603c0427145b3c9cbdaffd1c618a4b5d44e87af73cf1ea6b3d0c",
+            priv_view: "958c50f5b2a8748e8c047726c0e1685f5252e64172684c9968bae9caabcf1107",
+            pub_spend: "d015423178ed27f64e2f02cf877315699588ad729c5002cda2a62fa1552b4182",
+            pub_view: "0c79b02e7fcb7fbed22f8cd53ad0082820f2783c988b89315bef3226f191b80d",
+            address: "49WTnZKmgsQiCUKKFR9myvJfJJ815HymjbPvPTK6yPdrNkdsB26u1DxYvCnEW4PRd17iJKgDwXnDn9Fr2XHVAvEj2XZ8J63",
+        },
+        PolyseedVector {
+            mnemonic: "véhicule charbon arbuste épicerie breuvage prairie diamant question déesse bermuda cristal atelier étoile fortune nuire papier",
+            language: "fr",
+            secret: "2e4425788cc0117c59021c0ec13d736ae9eb07",
+            hex_seed: "b43cc51e7775ae15be58b50ce518afe6d9975f4bfb39099e013e58df0cb82147",
+            priv_spend: "00ededaa0de964b564e5d6806a313393d9975f4bfb39099e013e58df0cb82107",
+            priv_view: "9549470749e20384fbc9f0da7d43b7fa29877942899068aa8919afd120aa6f0a",
+            pub_spend: "46b098cd86649a10ca6dbfa0d74121740879e89f63a766fb4ea91aec65e767e8",
+            pub_view: "a9e86e313a3b4ce213a40d6c8642a41a98dfa15d2f6c71f4fd6e028193a977e0",
+            address: "44JUpynqS7j3otgwWuCKeYLQffAc3pTU9j2zjBeVd7ocfv8WBVRUKRVepEByBWg3rT5T2VmEsQD52hyi9kVh3zVCSMQSCm7",
+        },
+        PolyseedVector {
+            mnemonic: "sfaldare triturato dedicato saluto rotondo tariffa lampo cosmico dinnanzi sobbalzo sportivo egemonia smemorato acqua dottore stizzoso",
+            language: "it",
+            secret: "ecce2bfef3e2dbb32cf1d37674334801d01d34",
+            hex_seed: "397f912505805df87c77b90f6af077781b5239ada413841ce382ea27ee933115",
+            priv_spend: "4cab9bc8ea1c4ba0a6dac16c8bf698631b5239ada413841ce382ea27ee933105",
+            priv_view: "c7c96dd7d75432908d3ff5786d0a91ffea0e4aa337dbbabb0ad52b59bf42c406",
+            pub_spend: "fe812aed05e08cac4ef5b4de1568f68c7cb06f03b89546834bc50ce56a3cc95d",
+            pub_view: "761c4b30daa4498c4d9a843954af640f038e60d97abd338a236c43431270993d",
+            address: "4BGVdFuS5ABVpcFEJNBRg1QVuEPk9eS3fNxjezHsL6UYGdhA5xepZUcQU7jtpZkkGT3WeuYw9KwSaQ77gejhQ5z87zhRL7R",
+        },
+        PolyseedVector {
+            mnemonic: "えきたい すべて ひたる にんめい いわば よてい さがる ざるそば たいよう ほったん そよかぜ すめし さかいし しんせいじ せきにん せいげん",
+            language: "ja",
+            secret: "71b2fb5446f51685f61edee08721666c9d170e",
+            hex_seed: "251c0a9e0e840bf0da6034c221a862fc9b712394f2c0fa38a17d7d0c2b771844",
+            priv_spend: "71cc322aa5f7c18f81ed5536a7c0e6a89b712394f2c0fa38a17d7d0c2b771804",
+            priv_view: "1f9c80c851a97c6aebe33b55f91998b55c75a9acc44fe5ffc98b5a2857c87906",
+            pub_spend: "16a52b22c77b2367648e31814e4ea4479a5707dcf43af6b03df73dcaecdbc5b3",
+            pub_view: "582ebcc7111170a5ba8b37d9d111dedc601b6956a8f7d15f63988505c40a9711",
+            address: "42Ut58EFYDpJJ3JJ3wAVkoCydzKzbHDY1WUmat4ec6rGWzsE3y5cS6KUin111xyM4qdrvDqrCCspUGxPoqy9fSRt2vx5jkF",
+        },
+        PolyseedVector {
+            mnemonic: "열쇠 영웅 잡지 군대 데이트 손님 가운데 나들이 순간 넷째 악기 나침반 시스템 약국 기원 말투",
+            language: "ko",
+            secret: "92ecc19cc96f00523dd127a0f245e587485324",
+            hex_seed: "dd4dc375ada2aa0334362a9536d4cfc33583b00e6d3c095d1e68f4fe44351a8a",
+            priv_spend: "75ae148eda891743814f6d7d4105d81c3583b00e6d3c095d1e68f4fe44351a0a",
+            priv_view: "b2d097f63a1418724e60f80f72727b4e2989d7441d79b819b3f46eeb8d294a0a",
+            pub_spend: "08465b78fcff3c0871049a6ae2a9339c737b3c760a22d1f96ce1a491b925343d",
+            pub_view: "ed983756dd24c9b1d393e09beaf829d7ccd0191e27db4a5d8bb972a601db550c",
+            address: "41wJ8aJejab2Qtnhkuw39CTAmkoMvNVbNiijoj5gga9yBMnFXsBfvELWk96VZUEEYQd6XneTRgm7fGeWfSDCk86G2PpHJCj",
+        },
+        PolyseedVector {
+            mnemonic: "fragata enfeite empinado motriz inativo pedra quimono oriundo vizinho gaiola ficar almofada jovem autuar selvagem papagaio",
+            language: "pt",
+            secret: "57156a3a03b7f1cb07f46fda00d2251b76fb14",
+            hex_seed: "a52b57126e872f19598b9f2e2d5e29d4a7e0d20ceae1b3a955d9f53540de9c27",
+            priv_spend: "cb836b5839c10a69ac51b0e86f6a6baaa7e0d20ceae1b3a955d9f53540de9c07",
+            priv_view: "611e1e9b98a1e89906ef9735f0b0777e28d46d8fbddca8baa37ab21bbf519a06",
+            pub_spend: "8ca177bb0636a9c0fa2f86e92bd51c0cb2ade1c02ffc5d6fc84e9ed1cf8f08ee",
+            pub_view: "f28ef087d16055d8ec01170c0b19315c2239b6aaaf37cc638aa30e4cd924a6ad",
+            address: "46xCvwMXz1vZH83UG8o3Eb38BoHiWu1u6KhRvoQf4RZqgy6DnAcFD2kdHR1xF5fnPrGQp7MkrfxKVHegJtTAqTQyLXaB3ht",
+        },
+        PolyseedVector {
+            mnemonic: "坦 咨 奔 梦 举 虑 诉 川 让 闻 劝 盛 船 测 行 静",
+            language: "zh_s",
+            secret: "f46dcb610881963799539075696d345841c63c",
+            hex_seed: "ba4f275267af1dc0f1f1a50536ec67385ae73bcdae41feca60d7ea606a58df29",
+            priv_spend: "e0a73b9832e9f80f45b8b6bf78f8a90e5ae73bcdae41feca60d7ea606a58df09",
+            priv_view: "076f541eae62625741509454d3c2e5b378f5613b59798b1c8671229c2534c005",
+            pub_spend: "4a30c056e0919570916cfc7fee4a0648b8aa34960de68365838367d3271a5589",
+            pub_view: "fee07dd9d8506a4d9c462e784a21e9c6ad399e05c95c7cb9c652bb631ea926a7",
+            address: "44SB5zBb76CKq3yWPK1kDbDAVKBBsnzjLHyoznqhyvDiQ5jMkVVAyzyDyvGyw94p5naEQvaX5ZhPZY5FLv9vXa4MKvZ1BqJ",
+        },
+        PolyseedVector {
+            mnemonic: "獄 駕 葡 玄 會 級 冠 括 燈 授 悄 恨 嗎 貨 鞏 階",
+            language: "zh_t",
+            secret: "f4bc1fe4111b73d5ddd9a7b75c9d8c6070920f",
+            hex_seed: "0b38b792c0ebec35c7845d795e1a9b2ded531ac6cb066adf2b66068247c7e7f6",
+            priv_spend: "28cd4f20351dd90c3854dbec52768af4eb531ac6cb066adf2b66068247c7e706",
+            priv_view: "1b584e37722d996fdbccb4d27fd65ac35f6a51f25ba1b51e1d1a4025bae0120c",
+            pub_spend: "4c257a461809a19c8b103888d09f067acf57f00b8e41bb3ea2e787b28ed784e5",
+            pub_view: "7cbfe57cb30eca1e0c06919523eb480477cf3ddab7f8db25b6b25b996a474cf9",
+            address: "44WUS7kJGS4TBfb3Q9cz69MYQnxV6dY42BUem1AancbmfPKELBsnX5362VcTP1MmUT1kM2SR612p27JsSnewDBN3VAxw6vZ",
+        },
+    ];
+
+    #[test]
+    fn full_pipeline() {
+        for vector in VECTORS.iter() {
+            let context = format!("{} {}", vector.language, vector.seed_type);
+            let mnemonic: Vec<String> = vector.mnemonic.split(' ').map(|s| s.to_string()).collect();
+            let hex_seed = derive_hex_seed(mnemonic).unwrap();
+            assert_eq!(hex_seed, vector.hex_seed, "{}", context);
+            let priv_keys = derive_priv_keys(hex_seed).unwrap();
+            assert_eq!(priv_keys[0], vector.priv_spend, "{}", context);
+            assert_eq!(priv_keys[1], vector.priv_view, "{}", context);
+            let pub_spend = derive_pub_key(priv_keys[0].clone()).unwrap();
+            let pub_view = derive_pub_key(priv_keys[1].clone()).unwrap();
+            assert_eq!(pub_spend, vector.pub_spend, "{}", context);
+            assert_eq!(pub_view, vector.pub_view, "{}", context);
+            assert_eq!(derive_address(pub_spend, pub_view, 0).unwrap(), vector.address, "{}", context);
+        }
+    }
+
+    #[test]
+    fn polyseed_pipeline() {
+        for vector in POLYSEED_VECTORS.iter() {
+            let mnemonic: Vec<String> = vector.mnemonic.split(' ').map(|s| s.to_string()).collect();
+            assert_eq!(hex::encode(polyseed_entropy(&mnemonic).unwrap()), vector.secret, "{}", vector.language);
+            let hex_seed = PolyseedSeed.to_hex_seed(&mnemonic).unwrap();
+            assert_eq!(hex_seed, vector.hex_seed, "{}", vector.language);
+            let (priv_spend, priv_view) = PolyseedSeed.derive_keys(&hex_seed).unwrap();
+            assert_eq!(priv_spend, vector.priv_spend, "{}", vector.language);
+            assert_eq!(priv_view, vector.priv_view, "{}", vector.language);
+            let pub_spend = derive_pub_key(priv_spend).unwrap();
+            let pub_view = derive_pub_key(priv_view).unwrap();
+            assert_eq!(pub_spend, vector.pub_spend, "{}", vector.language);
+            assert_eq!(pub_view, vector.pub_view, "{}", vector.language);
+            assert_eq!(derive_address(pub_spend, pub_view, 0).unwrap(), vector.address, "{}", vector.language);
+        }
+    }
+
+    #[test]
+    fn mymonero_addresses() {
+        for vector in VECTORS.iter().filter(|vector| vector.seed_type == "mymonero") {
+            assert_eq!(mymonero_hex_to_address(vector.hex_seed, 0).unwrap(), vector.address, "{}", vector.language);
+        }
+        assert!(mymonero_hex_to_address("fddb58a226ba0a61cc48e2a393739378", 1).unwrap().starts_with('9'));
+        assert_eq!(mymonero_hex_to_address("fddb58a226ba0a61cc48e2a39373937", 0), Err(KeyError::InvalidHexLength { got: 31, expected: 32 }));
+        assert_eq!(mymonero_hex_to_address("fddb58a226ba0a61cc48e2a39373937g", 0), Err(KeyError::InvalidHex));
+        assert_eq!(mymonero_hex_to_address("fddb58a226ba0a61cc48e2a393739378", 3), Err(KeyError::InvalidNetwork));
+    }
+
+    #[test]
+    fn checksum_words() {
+        for vector in VECTORS.iter() {
+            let context = format!("{} {}", vector.language, vector.seed_type);
+            let mut mnemonic: Vec<String> = vector.mnemonic.split(' ').map(|s| s.to_string()).collect();
+            assert_eq!(validate_mnemonic(&mnemonic), Ok(()), "{}", context);
+            let checksum_word = mnemonic.pop().unwrap();
+            let full_mnemonic = append_checksum_word(mnemonic, vector.language).unwrap();
+            assert_eq!(full_mnemonic.last(), Some(&checksum_word), "{}", context);
+        }
+    }
+
+    #[test]
+    fn checksum_round_trip() {
+        for vector in VECTORS.iter() {
+            let context = format!("{} {}", vector.language, vector.seed_type);
+            let mnemonic: Vec<String> = vector.mnemonic.split(' ').map(|s| s.to_string()).collect();
+            let stripped = without_checksum(&mnemonic).unwrap();
+            assert_eq!(stripped.len(), mnemonic.len() - 1, "{}", context);
+            assert_eq!(with_checksum(&stripped), Ok(mnemonic.clone()), "{}", context);
+            assert_eq!(without_checksum(&stripped), Ok(stripped.clone()), "{}", context);
+            assert_eq!(with_checksum(&mnemonic), Ok(mnemonic), "{}", context);
+        }
+        // Randomly generated seeds of every language
+        for language in ["en", "eo", "fr", "it", "ja", "lj", "pt", "ru"] {
+            for seed_type in ["original", "mymonero"] {
+                for _ in 0..5 {
+                    let mnemonic = generate_seed(language, seed_type);
+                    let context = format!("{} {} {:?}", language, seed_type, mnemonic);
+                    assert_eq!(with_checksum(&without_checksum(&mnemonic).unwrap()), Ok(mnemonic.clone()), "{}", context);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn spend_key_recovery() {
+        for vector in VECTORS.iter().filter(|vector| vector.seed_type == "original") {
+            let context = format!("{} {}", vector.language, vector.seed_type);
+            let priv_keys = derive_priv_keys(vector.hex_seed.to_string()).unwrap();
+            let mnemonic = spend_key_to_mnemonic(&priv_keys[0], vector.language).unwrap();
+            assert_eq!(validate_mnemonic(&mnemonic), Ok(()), "{}", context);
+            // A reduced hex seed is the private spend key itself, so the words are the same
+            if priv_keys[0] == vector.hex_seed {
+                assert_eq!(mnemonic.join(" "), vector.mnemonic, "{}", context);
+            }
+            // Otherwise the words differ but restore the same keys
+            let hex_seed = derive_hex_seed(mnemonic).unwrap();
+            assert_eq!(hex_seed, priv_keys[0], "{}", context);
+            assert_eq!(derive_priv_keys(hex_seed).unwrap(), priv_keys, "{}", context);
+        }
+    }
+
+    #[test]
+    fn esperanto_and_lojban() {
+        // Words no longer than the 4-character prefix and Lojban words with apostrophes in their prefix
+        let vectors = [
+            ("huzo muro algo tezo hubo bani eono daco gado heni puso devi gafo jado vono jupo tusi aglo napo dabi geto ecoj cent ulmo cent", "eo", "881a76663f0d7ea9f183e32fcbc2936dae73fc7c67692d18921c673428dc7fc4"),
+            ("obei eono boli ecoj rufa dubi dura adzo aglo gnuo gufo fali obei", "eo", "dd1d91d92473336999ea2b022d352dea"),
+            ("jbogu'e cmicu'a vokta'a famyma'o tersu'i latro'a depybu'i fu'ivla tinju'i fa'orma'o zdakemkulgu'a zi'evla selma'o jbofi'e cnima'o ci'izra snaxa'a zvafa'i jefyfa'o selja'e pampe'o crezenzu'e balcu'e tolmo'i famyma'o", "lj", "b5d884f881644df97f40ba01c42b68041b1d50007c629bff54c0a9fe49dd1af4"),
+            ("tolmo'i cnima'o zi'evla vokta'a fu'ivla nunpe'i cmicu'a zdakemkulgu'a crezenzu'e tersu'i noltruti'u tinju'i fu'ivla", "lj", "42373ffd1ca1aa08390d7309f4c643f4"),
+        ];
+        for (mnemonic, language, hex_seed) in vectors {
+            let mnemonic: Vec<String> = mnemonic.split(' ').map(|s| s.to_string()).collect();
+            assert_eq!(validate_mnemonic(&mnemonic), Ok(()), "{}", hex_seed);
+            assert_eq!(derive_hex_seed(mnemonic.clone()), Ok(hex_seed.to_string()));
+            let words = mnemonic[..mnemonic.len() - 1].to_vec();
+            assert_eq!(derive_hex_seed(words.clone()), Ok(hex_seed.to_string()));
+            assert_eq!(append_checksum_word(words, language), Ok(mnemonic.clone()), "{}", hex_seed);
+            if mnemonic.len() == 25 {
+                assert_eq!(spend_key_to_mnemonic(hex_seed, language), Ok(mnemonic));
+            }
+        }
+        // Encoding and decoding pseudorandom seeds gives them back
+        for language in ["eo", "lj"] {
+            for i in 0..10u8 {
+                let hex_seed = hex::encode(cn_fast_hash(&[i]));
+                let mnemonic = spend_key_to_mnemonic(&hex_seed, language).unwrap();
+                assert_eq!(derive_hex_seed(mnemonic), Ok(hex_seed));
+            }
+        }
+    }
+}
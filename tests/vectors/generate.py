@@ -0,0 +1,420 @@
+# This script generates the known-answer vectors of tests/vectors.rs, independently of the crate
+#
+# It implements Keccak-256, Ed25519 point arithmetic, Monero's word encoding (src/mnemonics/electrum-words.cpp), key
+# derivation and base58 addresses from Monero's sources, and polyseed (src/gf.c, src/storage.c and src/polyseed.c of
+# tevador/polyseed) from the reference sources. Only the wordlists are read from the crate, they are copies of the
+# Monero and polyseed ones. Nothing here calls the crate, so a bug in the crate does not carry over to the vectors.
+#
+# Usage: `python3 tests/vectors/generate.py`, prints the Rust tables to paste into tests/vectors.rs, and the output keys
+# and message signatures of the tests of tests/integration_test.rs
+
+import glob
+import hashlib
+import os
+import re
+import unicodedata
+import zlib
+
+ROOT = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..", "..")
+
+# Keccak-256 with the original padding (0x01), as Monero's cn_fast_hash
+
+KECCAK_RC = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808A, 0x8000000080008000, 0x000000000000808B,
+    0x0000000080000001, 0x8000000080008081, 0x8000000000008009, 0x000000000000008A, 0x0000000000000088,
+    0x0000000080008009, 0x000000008000000A, 0x000000008000808B, 0x800000000000008B, 0x8000000000008089,
+    0x8000000000008003, 0x8000000000008002, 0x8000000000000080, 0x000000000000800A, 0x800000008000000A,
+    0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+]
+KECCAK_ROT = [[0, 36, 3, 41, 18], [1, 44, 10, 45, 2], [62, 6, 43, 15, 61], [28, 55, 25, 21, 56], [27, 20, 39, 8, 14]]
+MASK64 = (1 << 64) - 1
+
+
+def rol(x, n):
+    return ((x << n) | (x >> (64 - n))) & MASK64 if n else x
+
+
+def keccak_f(a):
+    for rc in KECCAK_RC:
+        c = [a[x][0] ^ a[x][1] ^ a[x][2] ^ a[x][3] ^ a[x][4] for x in range(5)]
+        d = [c[(x - 1) % 5] ^ rol(c[(x + 1) % 5], 1) for x in range(5)]
+        a = [[a[x][y] ^ d[x] for y in range(5)] for x in range(5)]
+        b = [[0] * 5 for _ in range(5)]
+        for x in range(5):
+            for y in range(5):
+                b[y][(2 * x + 3 * y) % 5] = rol(a[x][y], KECCAK_ROT[x][y])
+        a = [[b[x][y] ^ ((~b[(x + 1) % 5][y]) & b[(x + 2) % 5][y]) for y in range(5)] for x in range(5)]
+        a[0][0] ^= rc
+    return a
+
+
+def keccak256(data):
+    rate = 136
+    padded = bytearray(data) + b"\x01"
+    while len(padded) % rate:
+        padded += b"\x00"
+    padded[-1] |= 0x80
+    a = [[0] * 5 for _ in range(5)]
+    for offset in range(0, len(padded), rate):
+        block = padded[offset:offset + rate]
+        for i in range(rate // 8):
+            a[i % 5][i // 5] ^= int.from_bytes(block[8 * i:8 * i + 8], "little")
+        a = keccak_f(a)
+    return b"".join(a[i % 5][i // 5].to_bytes(8, "little") for i in range(4))
+
+
+# Ed25519, affine coordinates
+
+Q = 2**255 - 19
+L = 2**252 + 27742317777372353535851937790883648493
+D = -121665 * pow(121666, Q - 2, Q) % Q
+SQRT_M1 = pow(2, (Q - 1) // 4, Q)
+
+
+def inv(x):
+    return pow(x, Q - 2, Q)
+
+
+def x_recover(y):
+    xx = (y * y - 1) * inv(D * y * y + 1)
+    x = pow(xx, (Q + 3) // 8, Q)
+    if (x * x - xx) % Q != 0:
+        x = x * SQRT_M1 % Q
+    if x % 2 != 0:
+        x = Q - x
+    return x
+
+
+BASE = (x_recover(4 * inv(5) % Q), 4 * inv(5) % Q)
+
+
+def point_add(p, q):
+    x1, y1 = p
+    x2, y2 = q
+    x3 = (x1 * y2 + x2 * y1) * inv(1 + D * x1 * x2 * y1 * y2)
+    y3 = (y1 * y2 + x1 * x2) * inv(1 - D * x1 * x2 * y1 * y2)
+    return (x3 % Q, y3 % Q)
+
+
+def point_mul(p, e):
+    r = (0, 1)
+    while e:
+        if e & 1:
+            r = point_add(r, p)
+        p = point_add(p, p)
+        e >>= 1
+    return r
+
+
+def point_encode(p):
+    x, y = p
+    return (y | ((x & 1) << 255)).to_bytes(32, "little")
+
+
+def point_decode(s):
+    y = int.from_bytes(s, "little") & ((1 << 255) - 1)
+    x = x_recover(y)
+    if x & 1 != s[31] >> 7:
+        x = Q - x
+    return (x, y)
+
+
+def scalar(b):
+    return int.from_bytes(b, "little") % L
+
+
+def scalar_bytes(n):
+    return (n % L).to_bytes(32, "little")
+
+
+def hash_to_scalar(data):
+    return scalar_bytes(scalar(keccak256(data)))
+
+
+def public_key(secret):
+    return point_encode(point_mul(BASE, scalar(secret)))
+
+
+def varint(n):
+    out = b""
+    while n >= 0x80:
+        out += bytes([(n & 0x7F) | 0x80])
+        n >>= 7
+    return out + bytes([n])
+
+
+# Monero base58 and addresses
+
+BASE58_ALPHABET = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"
+BASE58_BLOCK_SIZES = [0, 2, 3, 5, 6, 7, 9, 10, 11]
+
+
+def base58(data):
+    out = ""
+    for i in range(0, len(data), 8):
+        block = data[i:i + 8]
+        num = int.from_bytes(block, "big")
+        encoded = ""
+        while num:
+            num, rem = divmod(num, 58)
+            encoded = BASE58_ALPHABET[rem] + encoded
+        out += encoded.rjust(BASE58_BLOCK_SIZES[len(block)], "1")
+    return out
+
+
+def address(pub_spend, pub_view, prefix=0x12):
+    data = varint(prefix) + pub_spend + pub_view
+    return base58(data + keccak256(data)[:4])
+
+
+# Wordlists of the crate
+
+def load_wordset(path):
+    source = open(path, encoding="utf-8").read()
+    fields = {
+        "name": re.search(r'name:\s*"([^"]+)"', source).group(1),
+        "prefix_len": int(re.search(r"prefix_len:\s*(\d+)", source).group(1)),
+        "compose": re.search(r"compose:\s*(true|false)", source),
+    }
+    fields["compose"] = fields["compose"] is not None and fields["compose"].group(1) == "true"
+    fields["words"] = re.findall(r'"((?:[^"\\]|\\.)*)"', source[source.index("words: ["):])
+    return fields
+
+
+def load_wordsets(kind):
+    paths = glob.glob(os.path.join(ROOT, "src", "mnemonics", kind, "languages", "*.rs"))
+    return {wordset["name"]: wordset for wordset in map(load_wordset, paths)}
+
+
+ORIGINAL_WORDSETS = load_wordsets("original")
+POLYSEED_WORDSETS = load_wordsets("polyseed")
+
+
+# Original (25-word) and MyMonero (13-word) seeds
+
+def mnemonic_encode(seed, language):
+    wordset = ORIGINAL_WORDSETS[language]
+    words, n = wordset["words"], len(wordset["words"])
+    out = []
+    for i in range(0, len(seed), 4):
+        val = int.from_bytes(seed[i:i + 4], "little")
+        w1 = val % n
+        w2 = (val // n + w1) % n
+        w3 = (val // n // n + w2) % n
+        out += [words[w1], words[w2], words[w3]]
+    prefixes = "".join(word[:wordset["prefix_len"]] if wordset["prefix_len"] else word for word in out)
+    out.append(out[zlib.crc32(prefixes.encode()) % len(out)])
+    return out
+
+
+def original_keys(seed):
+    priv_spend = scalar_bytes(scalar(seed))
+    return priv_spend, hash_to_scalar(priv_spend)
+
+
+def mymonero_keys(seed):
+    hashed = keccak256(seed)
+    return scalar_bytes(scalar(hashed)), hash_to_scalar(hashed)
+
+
+# Random seeds of the 1626-word vectors, one original and one MyMonero seed per language
+ORIGINAL_SEEDS = [
+    ("en", "original", "52a89335878254a36c54c2259191d9d8e0be57782a3e3c85550f07ea98bc5852"),
+    ("eo", "original", "95d04cef69bf3a2e6947fa6b36296c2dc4788f3828bfa374c05dba56f4263208"),
+    ("fr", "original", "62cc44b5c758217b9519bcef48145567171eb57f74d6129024992a11727a9639"),
+    ("it", "original", "ee971c7ca7da65d7a9ce160b6fdc691e4eb8b648d337b95fe460d1d76bf1b342"),
+    ("ja", "original", "34891af86fb99f6883269e83c541e56585cb1bd08f03f9c780a88a2fca4f2152"),
+    ("lj", "original", "5a383b42b64522dc9979de9872d083df0075afffcbc332cef42d5b854c2df54e"),
+    ("pt", "original", "ce797a52cc0ac058c2563f8ec240c572acd0f4515bccce8dafeb5aab77ec3408"),
+    ("ru", "original", "aa13747f92af901acc5a1b6c683d79cc17942675a39ad4f8327cf05f512c9996"),
+    ("en", "mymonero", "fddb58a226ba0a61cc48e2a393739378"),
+    ("eo", "mymonero", "a62bc818c733d7575e3e607ed75a016a"),
+    ("fr", "mymonero", "7154cf97f9a56e4212bbf1d4bbfd5555"),
+    ("it", "mymonero", "8e39d42a8c2d1ef67384778526acd67e"),
+    ("ja", "mymonero", "00358c776086571f075e60b1a1bea889"),
+    ("lj", "mymonero", "9e4728eaeb641c3d32b30ee1cd33b947"),
+    ("pt", "mymonero", "a18dfaed3b625f6a6c393df6ea8d9829"),
+    ("ru", "mymonero", "c0c95ede4c8cf7aae1e6aca1f43f7755"),
+]
+
+
+# Polyseed (16-word) seeds
+
+def gf_mul2(x):
+    x <<= 1
+    return x ^ 0x805 if x & 0x800 else x
+
+
+def gf_poly_eval(coeff):
+    result = 0
+    for c in reversed(coeff):
+        result = gf_mul2(result) ^ c
+    return result
+
+
+def polyseed_poly(secret, birthday, features):
+    extra = (features << 10) | birthday
+    bits = "".join(format(b, "08b") for b in secret[:18]) + format(secret[18] & 0x3F, "06b")
+    coeff = [0] + [(int(bits[i * 10:(i + 1) * 10], 2) << 1) | ((extra >> (14 - i)) & 1) for i in range(15)]
+    coeff[0] = gf_poly_eval(coeff)
+    return coeff
+
+
+def polyseed_data(coeff):
+    assert gf_poly_eval(coeff) == 0, "bad checksum"
+    bits = "".join(format(c >> 1, "010b") for c in coeff[1:])
+    extra = int("".join(str(c & 1) for c in coeff[1:]), 2)
+    secret = bytes(int(bits[i * 8:(i + 1) * 8], 2) for i in range(18)) + bytes([int(bits[144:150], 2)])
+    return secret, extra & 0x3FF, extra >> 10
+
+
+def polyseed_encode(secret, birthday, features, language):
+    wordset = POLYSEED_WORDSETS[language]
+    words = [wordset["words"][c] for c in polyseed_poly(secret, birthday, features)]
+    return [unicodedata.normalize("NFC", word) if wordset["compose"] else word for word in words]
+
+
+def polyseed_decode(mnemonic, language):
+    words = [unicodedata.normalize("NFKD", word) for word in POLYSEED_WORDSETS[language]["words"]]
+    return polyseed_data([words.index(unicodedata.normalize("NFKD", word)) for word in mnemonic])
+
+
+def polyseed_keygen(secret, birthday, features):
+    salt = b"POLYSEED key" + b"\x00" + b"\xff" * 3 + (0).to_bytes(4, "little") + birthday.to_bytes(4, "little")
+    salt += features.to_bytes(4, "little") + bytes(4)
+    return hashlib.pbkdf2_hmac("sha256", secret + bytes(13), salt, 10000, 32)
+
+
+# The test vector of the polyseed reference implementation (tests/tests.c of tevador/polyseed)
+POLYSEED_REFERENCE = (
+    "raven tail swear infant grief assist regular lamp duck valid someone little harsh puppy airport language"
+)
+POLYSEED_LANGUAGES = ["en", "cs", "es", "fr", "it", "ja", "ko", "pt", "zh_s", "zh_t"]
+
+
+def polyseed_seeds():
+    """The English seed is the reference one, the others encode secrets made from the language code"""
+    secret, birthday, features = polyseed_decode(POLYSEED_REFERENCE.split(" "), "en")
+    assert secret.hex() == "dd76e7359a0ded37cd0ff0f3c829a5ae016733"
+    yield "en", secret, birthday, features
+    for index, language in enumerate(POLYSEED_LANGUAGES[1:], 1):
+        secret = bytearray(keccak256(b"libmonero polyseed vector " + language.encode())[:19])
+        secret[18] &= 0x3F
+        yield language, bytes(secret), 13 * index + 20, 0
+
+
+# One-time output keys (src/crypto/crypto.cpp)
+
+def generate_key_derivation(pub, sec):
+    return point_encode(point_mul(point_decode(pub), 8 * scalar(sec)))
+
+
+def derive_public_key(derivation, index, base):
+    output_scalar = scalar(keccak256(derivation + varint(index)))
+    return point_encode(point_add(point_mul(BASE, output_scalar), point_decode(base)))
+
+
+# Transaction keys, wallet keys and output indices of the output tests, the first one is the generate_key_derivation
+# vector of Monero's tests/crypto/tests.txt and the wallet is the `tissue raking haunted ...` one of the crate docs
+WALLET_PUB_SPEND = "e78d891dd2be407f24e6470caad956e1b746ae0b41cd8252f96684090bc05d95"
+OUTPUT_CASES = [
+    ("fdfd97d2ea9f1c25df773ff2c973d885653a3ee643157eb0ae2b6dd98f0b6984",
+     "eb2bd1cf0c5e074f9dbf38ebbc99c316f54e21803048c687a3bb359f7a713b02", [0, 1, 300]),
+    # A transaction secret key and the public view key
+    ("157d278aa3aee4e11c5a8243a43a78527a2691009562b8c18654975f1347cb47",
+     "729e239f0a1b4c74199be9dfa71e1fc2ef35b9bfd4f8013b9fa2795085496b04", [0, 1, 2]),
+    # A transaction public key and the private view key
+    ("10fccee43c0612968c2db809b140abbf1c799fe6158305be1c023509f3ae9a94",
+     "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908", [0, 1]),
+]
+
+
+# Message signatures (src/crypto/crypto.cpp and src/wallet/wallet2.cpp)
+
+def generate_signature(message_hash, secret, nonce):
+    pub = public_key(secret)
+    commitment = point_encode(point_mul(BASE, scalar(nonce)))
+    c = scalar(keccak256(message_hash + pub + commitment))
+    return scalar_bytes(c) + scalar_bytes(scalar(nonce) - c * scalar(secret))
+
+
+def message_hash_v2(message, pub_spend, pub_view, mode):
+    data = b"MoneroMessageSignature\x00" + pub_spend + pub_view + bytes([mode]) + varint(len(message)) + message
+    return keccak256(data)
+
+
+# The wallet of the crate docs, signing with its spend key (mode 0) and its view key (mode 1) with a fixed nonce
+WALLET_PRIV_SPEND = "c8982eada77ba2245183f2bff85dfaf993dc714178a09828775dba01b4df9a08"
+WALLET_PRIV_VIEW = "0d13a94c82d7a60abb54d2217d38935c3f715295e30378f8848a1ca1abc8d908"
+SIGNED_MESSAGE = b"Hello, world!"
+SIGNATURE_NONCE = keccak256(b"libmonero message signing test nonce")
+
+
+# Output
+
+def rust_struct(name, fields):
+    lines = ["        %s {" % name] + ['            %s: "%s",' % field for field in fields] + ["        },"]
+    return "\n".join(lines)
+
+
+def keys_fields(priv_spend, priv_view):
+    pub_spend, pub_view = public_key(priv_spend), public_key(priv_view)
+    return [
+        ("priv_spend", priv_spend.hex()),
+        ("priv_view", priv_view.hex()),
+        ("pub_spend", pub_spend.hex()),
+        ("pub_view", pub_view.hex()),
+        ("address", address(pub_spend, pub_view)),
+    ]
+
+
+def main():
+    print("    const VECTORS: [Vector; %d] = [" % len(ORIGINAL_SEEDS))
+    for language, seed_type, hex_seed in ORIGINAL_SEEDS:
+        seed = bytes.fromhex(hex_seed)
+        keys = original_keys(seed) if seed_type == "original" else mymonero_keys(seed)
+        fields = [
+            ("mnemonic", " ".join(mnemonic_encode(seed, language))),
+            ("language", language),
+            ("seed_type", seed_type),
+            ("hex_seed", hex_seed),
+        ]
+        print(rust_struct("Vector", fields + keys_fields(*keys)))
+    print("    ];")
+    print()
+    print("    const POLYSEED_VECTORS: [PolyseedVector; %d] = [" % len(POLYSEED_LANGUAGES))
+    for language, secret, birthday, features in polyseed_seeds():
+        mnemonic = polyseed_encode(secret, birthday, features, language)
+        assert polyseed_decode(mnemonic, language) == (secret, birthday, features)
+        key = polyseed_keygen(secret, birthday, features)
+        fields = [
+            ("mnemonic", " ".join(mnemonic)),
+            ("language", language),
+            ("secret", secret.hex()),
+            ("hex_seed", key.hex()),
+        ]
+        print(rust_struct("PolyseedVector", fields + keys_fields(*original_keys(key))))
+    print("    ];")
+    print()
+    print("    // Output keys, in the format of Monero's tests/crypto/tests.txt")
+    for pub, sec, indices in OUTPUT_CASES:
+        derivation = generate_key_derivation(bytes.fromhex(pub), bytes.fromhex(sec))
+        print("    // generate_key_derivation %s %s true %s" % (pub, sec, derivation.hex()))
+        for index in indices:
+            output_key = derive_public_key(derivation, index, bytes.fromhex(WALLET_PUB_SPEND))
+            line = (derivation.hex(), index, WALLET_PUB_SPEND, output_key.hex())
+            print("    // derive_public_key %s %d %s true %s" % line)
+    print()
+    priv_spend, priv_view = bytes.fromhex(WALLET_PRIV_SPEND), bytes.fromhex(WALLET_PRIV_VIEW)
+    pub_spend, pub_view = public_key(priv_spend), public_key(priv_view)
+    print("    // Signatures of %r by %s" % (SIGNED_MESSAGE.decode(), address(pub_spend, pub_view)))
+    signature = generate_signature(keccak256(SIGNED_MESSAGE), priv_spend, SIGNATURE_NONCE)
+    print("    // SigV1 with the spend key: SigV1%s" % base58(signature))
+    for mode, secret in enumerate([priv_spend, priv_view]):
+        message_hash = message_hash_v2(SIGNED_MESSAGE, pub_spend, pub_view, mode)
+        signature = generate_signature(message_hash, secret, SIGNATURE_NONCE)
+        print("    // SigV2 with the %s key: SigV2%s" % (["spend", "view"][mode], base58(signature)))
+
+
+if __name__ == "__main__":
+    main()